@@ -25,6 +25,11 @@
 //!             max: 1.0,
 //!             power: None,
 //!             format: None,
+//!             sanitize: false,
+//!             wrap: false,
+//!             logarithmic: false,
+//!             keyboard_step: None,
+//!             format_value: None,
 //!         };
 //!
 //!         __Example_Events {
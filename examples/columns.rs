@@ -0,0 +1,28 @@
+mod support;
+
+#[derive(imgui_ext::Gui, Default, Debug)]
+struct Material {
+    #[imgui(color(edit))]
+    albedo: [f32; 3],
+    #[imgui(color(edit))]
+    emissive: [f32; 3],
+    #[imgui(slider(min = 0.0, max = 1.0))]
+    roughness: f32,
+    #[imgui(slider(min = 0.0, max = 1.0))]
+    metalness: f32,
+}
+
+#[derive(imgui_ext::Gui, Default, Debug)]
+struct Example {
+    #[imgui(input)]
+    name: String,
+    // Two fields per row.
+    #[imgui(nested(columns = 2))]
+    material: Material,
+    #[imgui(checkbox(label = "Double sided"))]
+    double_sided: bool,
+}
+
+fn main() {
+    support::demo().run_debug::<Example, _>(|_, _| {});
+}
@@ -0,0 +1,34 @@
+mod support;
+
+#[derive(imgui_ext::Gui, Default, Debug)]
+struct Light {
+    #[imgui(color(edit))]
+    color: [f32; 3],
+    #[imgui(slider(min = 0.0, max = 10.0))]
+    intensity: f32,
+    #[imgui(drag(speed = 0.1))]
+    position: [f32; 3],
+}
+
+#[derive(imgui_ext::Gui, Default, Debug)]
+struct Lights {
+    #[imgui(text("Key light"), nested)]
+    key: Light,
+    #[imgui(text("Fill light"), nested)]
+    fill: Light,
+    #[imgui(text("Back light"), nested)]
+    back: Light,
+}
+
+#[derive(imgui_ext::Gui, Default, Debug)]
+struct Example {
+    #[imgui(checkbox(label = "Shadows"))]
+    shadows: bool,
+    // Bordered sub-panel. Its contents scroll when they don't fit.
+    #[imgui(group(border, size = "[0.0, 120.0]"))]
+    lights: Lights,
+}
+
+fn main() {
+    support::demo().run_debug::<Example, _>(|_, _| {});
+}
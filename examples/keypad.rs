@@ -0,0 +1,46 @@
+mod support;
+
+use imgui::ImString;
+
+#[derive(imgui_ext::Gui, Debug)]
+struct Keypad {
+    #[imgui(display(label = "Code"))]
+    code: String,
+    // A 3x3 grid of buttons, from "1" to "9".
+    #[imgui(buttons(cols = 3, size = "[32.0, 32.0]", on_click = "Keypad::press"))]
+    keys: [ImString; 9],
+}
+
+impl Keypad {
+    fn press(index: usize, keypad: &mut Keypad) {
+        keypad.code.push_str(keypad.keys[index].to_str());
+    }
+}
+
+impl Default for Keypad {
+    fn default() -> Self {
+        let key = |n: usize| ImString::new(n.to_string());
+        Self {
+            code: String::new(),
+            keys: [
+                key(1),
+                key(2),
+                key(3),
+                key(4),
+                key(5),
+                key(6),
+                key(7),
+                key(8),
+                key(9),
+            ],
+        }
+    }
+}
+
+fn main() {
+    support::demo().run_debug::<Keypad, _>(|keypad, events| {
+        if events.keys() {
+            println!("code = {}", keypad.code);
+        }
+    });
+}
@@ -0,0 +1,28 @@
+mod support;
+
+#[derive(imgui_ext::Gui, Default, Debug)]
+struct Item {
+    #[imgui(input)]
+    name: String,
+    #[imgui(drag(min = 0))]
+    count: i32,
+    #[imgui(checkbox)]
+    equipped: bool,
+}
+
+#[derive(imgui_ext::Gui, Default, Debug)]
+struct Inventory {
+    #[imgui(input(label = "Owner"))]
+    owner: String,
+    // One tree node per item, with "Remove" buttons, and an "Add" button.
+    #[imgui(list(label = "Item", catch = "resized"))]
+    items: Vec<Item>,
+}
+
+fn main() {
+    support::demo().run_debug::<Inventory, _>(|inventory, events| {
+        if events.resized() {
+            println!("{} items", inventory.items.len());
+        }
+    });
+}
@@ -0,0 +1,43 @@
+mod support;
+
+// Texture `0` is the font atlas, which is good enough to show the buttons.
+#[derive(imgui_ext::Gui, Default, Debug)]
+struct Toolbar {
+    #[imgui(image_button(size = "[24.0, 24.0]", uv1 = "[0.1, 0.1]"))]
+    new: usize,
+    #[imgui(image_button(size = "[24.0, 24.0]", uv1 = "[0.1, 0.1]", same_line))]
+    open: usize,
+    #[imgui(image_button(
+        size = "[24.0, 24.0]",
+        uv1 = "[0.1, 0.1]",
+        bg = "[0.2, 0.2, 0.2, 1.0]",
+        image_padding = 4,
+        same_line
+    ))]
+    save: usize,
+    #[imgui(image_button(
+        size = "[24.0, 24.0]",
+        uv1 = "[0.1, 0.1]",
+        tint = "[1.0, 0.3, 0.3, 1.0]",
+        catch = "quit",
+        same_line
+    ))]
+    close: usize,
+}
+
+fn main() {
+    support::demo().run::<Toolbar, _>(|_, events| {
+        if events.new() {
+            println!("new");
+        }
+        if events.open() {
+            println!("open");
+        }
+        if events.save() {
+            println!("save");
+        }
+        if events.quit() {
+            println!("quit");
+        }
+    });
+}
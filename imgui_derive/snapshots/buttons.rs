@@ -0,0 +1,61 @@
+# [allow (non_camel_case_types)]
+pub struct __Keypad_Events {
+    pub keys : bool , pub keys_index : Option < usize > ,
+}
+impl __Keypad_Events {
+    # [must_use]
+    # [inline (always)]
+    pub fn keys (& self) -> bool {
+        self . keys
+    }
+    pub fn keys_index (& self) -> Option < usize > {
+        self . keys_index
+    }
+    # [doc = r" Names of the events that were triggered."]
+    pub fn widgets_changed (& self) -> Vec < & 'static str > {
+        let mut changed = Vec :: new () ;
+        if self . keys {
+            changed . push ("keys") ;
+        }
+        changed
+    }
+}
+impl imgui_ext :: Gui for Keypad {
+    type Events = __Keypad_Events ;
+    fn draw_gui (ui : & imgui :: Ui , ext : & mut Self) -> Self :: Events {
+        < Self as imgui_ext :: Gui > :: draw_gui_columns (ui , ext , false)
+    }
+    fn draw_gui_columns (ui : & imgui :: Ui , ext : & mut Self , _columns : bool) -> Self :: Events {
+        let mut events : Self :: Events = unsafe {
+            std :: mem :: zeroed ()
+        };
+        {
+            use imgui_ext :: buttons :: ButtonsParams as Params ;
+            let _click = imgui_ext :: buttons :: build (ui , & ext . keys [..] , Params {
+                cols : 3usize , size : imgui_ext :: scaled ([32.0 , 32.0])
+            }) ;
+            events . keys |= _click . is_some () ;
+            if _click . is_some () {
+                events . keys_index = _click ;
+            }
+            if let Some (_index) = _click {
+                Keypad :: press (_index , ext) ;
+            }
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        }
+        events
+    }
+}
+impl Keypad {
+    # [doc = r" Number of widgets drawn by the `imgui` annotations of the fields."]
+    pub const IMGUI_WIDGET_COUNT : usize = 1 ;
+    # [doc = r" Widgets drawn by the `imgui` annotations of the fields, in"]
+    # [doc = r" declaration order."]
+    pub const IMGUI_WIDGETS : & 'static [imgui_ext :: WidgetInfo] = & [imgui_ext :: WidgetInfo {
+        field : "keys" , kind : "buttons" , label : None , min : None , max : None ,
+    }] ;
+}
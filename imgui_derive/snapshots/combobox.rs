@@ -0,0 +1,100 @@
+# [allow (non_camel_case_types)]
+pub struct __Fruits_Events {
+    pub a : bool , pub b : bool , pub c : bool ,
+}
+impl __Fruits_Events {
+    # [inline (always)]
+    pub fn a (& self) -> bool {
+        self . a
+    }
+    # [inline (always)]
+    pub fn b (& self) -> bool {
+        self . b
+    }
+    # [inline (always)]
+    pub fn c (& self) -> bool {
+        self . c
+    }
+    # [doc = r" Names of the events that were triggered."]
+    pub fn widgets_changed (& self) -> Vec < & 'static str > {
+        let mut changed = Vec :: new () ;
+        if self . a {
+            changed . push ("a") ;
+        }
+        if self . b {
+            changed . push ("b") ;
+        }
+        if self . c {
+            changed . push ("c") ;
+        }
+        changed
+    }
+}
+impl imgui_ext :: Gui for Fruits {
+    type Events = __Fruits_Events ;
+    fn draw_gui (ui : & imgui :: Ui , ext : & mut Self) -> Self :: Events {
+        < Self as imgui_ext :: Gui > :: draw_gui_columns (ui , ext , false)
+    }
+    fn draw_gui_columns (ui : & imgui :: Ui , ext : & mut Self , _columns : bool) -> Self :: Events {
+        let mut events : Self :: Events = unsafe {
+            std :: mem :: zeroed ()
+        };
+        {
+            use imgui_ext :: combobox :: Combobox ;
+            use imgui_ext :: combobox :: ComboboxParams as Params ;
+            let _ev = Combobox :: build (ui , & mut ext . a , Params {
+                label : imgui :: im_str ! ("a") , selected : 0usize , typeahead : true , icons : None , sections : None
+            }) ;
+            events . a |= _ev ;
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        };
+        {
+            use imgui_ext :: combobox :: Combobox ;
+            use imgui_ext :: combobox :: ComboboxParams as Params ;
+            let _sel = Combobox :: select (ui , & mut ext . b , Params {
+                label : imgui :: im_str ! ("b") , selected : 1usize , typeahead : false , icons : None , sections : None
+            }) ;
+            events . b |= _sel . is_some () ;
+            if let Some (_index) = _sel {
+                Fruits :: select (_index , ext) ;
+            }
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        };
+        {
+            use imgui_ext :: combobox :: Combobox ;
+            use imgui_ext :: combobox :: ComboboxParams as Params ;
+            let _ev = Combobox :: build (ui , & mut ext . c , Params {
+                label : imgui :: im_str ! ("c") , selected : 0usize , typeahead : false , icons : Some (& ext . icons [..]) , sections : Some (& ext . sections [..])
+            }) ;
+            events . c |= _ev ;
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        };
+        ;
+        events
+    }
+}
+impl Fruits {
+    # [doc = r" Number of widgets drawn by the `imgui` annotations of the fields."]
+    pub const IMGUI_WIDGET_COUNT : usize = 3 ;
+    # [doc = r" Widgets drawn by the `imgui` annotations of the fields, in"]
+    # [doc = r" declaration order."]
+    pub const IMGUI_WIDGETS : & 'static [imgui_ext :: WidgetInfo] = & [imgui_ext :: WidgetInfo {
+        field : "a" , kind : "combobox" , label : Some ("a") , min : None , max : None ,
+    }, imgui_ext :: WidgetInfo {
+        field : "b" , kind : "combobox" , label : Some ("b") , min : None , max : None ,
+    }, imgui_ext :: WidgetInfo {
+        field : "c" , kind : "combobox" , label : Some ("c") , min : None , max : None ,
+    }] ;
+}
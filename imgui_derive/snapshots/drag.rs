@@ -0,0 +1,167 @@
+# [allow (non_camel_case_types)]
+pub struct __Drag_Events {
+    pub a : bool , pub b : bool , pub reset : bool , pub d : bool , pub grab : bool , pub release : bool ,
+}
+impl __Drag_Events {
+    # [inline (always)]
+    pub fn a (& self) -> bool {
+        self . a
+    }
+    # [inline (always)]
+    pub fn b (& self) -> bool {
+        self . b
+    }
+    pub fn reset (& self) -> bool {
+        self . reset
+    }
+    # [inline (always)]
+    pub fn d (& self) -> bool {
+        self . d
+    }
+    pub fn grab (& self) -> bool {
+        self . grab
+    }
+    pub fn release (& self) -> bool {
+        self . release
+    }
+    # [doc = r" Names of the events that were triggered."]
+    pub fn widgets_changed (& self) -> Vec < & 'static str > {
+        let mut changed = Vec :: new () ;
+        if self . a {
+            changed . push ("a") ;
+        }
+        if self . b {
+            changed . push ("b") ;
+        }
+        if self . reset {
+            changed . push ("reset") ;
+        }
+        if self . d {
+            changed . push ("d") ;
+        }
+        if self . grab {
+            changed . push ("grab") ;
+        }
+        if self . release {
+            changed . push ("release") ;
+        }
+        changed
+    }
+}
+impl imgui_ext :: Gui for Drag {
+    type Events = __Drag_Events ;
+    fn draw_gui (ui : & imgui :: Ui , ext : & mut Self) -> Self :: Events {
+        < Self as imgui_ext :: Gui > :: draw_gui_columns (ui , ext , false)
+    }
+    fn draw_gui_columns (ui : & imgui :: Ui , ext : & mut Self , _columns : bool) -> Self :: Events {
+        let mut events : Self :: Events = unsafe {
+            std :: mem :: zeroed ()
+        };
+        {
+            let _elem = & mut ext . a ;
+            # [allow (unused_mut)]
+            let mut _ev = {
+                use imgui_ext :: drag :: Drag ;
+                Drag :: build (ui , & mut * _elem , {
+                    use imgui_ext :: drag :: DragParams as Params ;
+                    let mut params = Params {
+                        label : imgui :: im_str ! ("a") , min : None , max : None , speed : None , power : None , format : None , sanitize : false , unit : None ,
+                    };
+                    params
+                })
+            };
+            events . a |= _ev ;
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        };
+        {
+            ui . same_line (0.0) ;
+            {
+                let _elem = & mut ext . b ;
+                # [allow (unused_mut)]
+                let mut _ev = {
+                    use imgui_ext :: drag :: Drag ;
+                    Drag :: build (ui , & mut * _elem , {
+                        use imgui_ext :: drag :: DragParams as Params ;
+                        let mut params = Params {
+                            label : imgui :: im_str ! ("b") , min : None , max : None , speed : None , power : None , format : None , sanitize : false , unit : None ,
+                        };
+                        params . min = Some (0.0) ;
+                        params . speed = Some (0.1) ;
+                        params
+                    })
+                };
+                events . b |= _ev ;
+            }
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        };
+        {
+            let _elem = & mut ext . c ;
+            # [allow (unused_mut)]
+            let mut _ev = {
+                use imgui_ext :: drag :: Drag ;
+                Drag :: build (ui , & mut * _elem , {
+                    use imgui_ext :: drag :: DragParams as Params ;
+                    let mut params = Params {
+                        label : imgui :: im_str ! ("c") , min : None , max : None , speed : None , power : None , format : None , sanitize : false , unit : None ,
+                    };
+                    params
+                })
+            };
+            let _dclick = ui . is_item_hovered () && ui . is_mouse_double_clicked (imgui :: MouseButton :: Left) ;
+            _ev |= imgui_ext :: drag :: reset_on_double_click (_dclick , _elem) ;
+            events . reset |= _ev ;
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        };
+        {
+            let _elem = & mut ext . d ;
+            # [allow (unused_mut)]
+            let mut _ev = {
+                use imgui_ext :: drag :: Drag ;
+                Drag :: build (ui , & mut * _elem , {
+                    use imgui_ext :: drag :: DragParams as Params ;
+                    let mut params = Params {
+                        label : imgui :: im_str ! ("d") , min : None , max : None , speed : None , power : None , format : None , sanitize : false , unit : None ,
+                    };
+                    params
+                })
+            };
+            events . d |= _ev ;
+            let (_started , _finished) = imgui_ext :: item_edit (ui) ;
+            events . grab |= _started ;
+            events . release |= _finished ;
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        }
+        events
+    }
+}
+impl Drag {
+    # [doc = r" Number of widgets drawn by the `imgui` annotations of the fields."]
+    pub const IMGUI_WIDGET_COUNT : usize = 4 ;
+    # [doc = r" Widgets drawn by the `imgui` annotations of the fields, in"]
+    # [doc = r" declaration order."]
+    pub const IMGUI_WIDGETS : & 'static [imgui_ext :: WidgetInfo] = & [imgui_ext :: WidgetInfo {
+        field : "a" , kind : "drag" , label : Some ("a") , min : None , max : None ,
+    }, imgui_ext :: WidgetInfo {
+        field : "b" , kind : "drag" , label : Some ("b") , min : Some (0.0) , max : None ,
+    }, imgui_ext :: WidgetInfo {
+        field : "c" , kind : "drag" , label : Some ("c") , min : None , max : None ,
+    }, imgui_ext :: WidgetInfo {
+        field : "d" , kind : "drag" , label : Some ("d") , min : None , max : None ,
+    }] ;
+}
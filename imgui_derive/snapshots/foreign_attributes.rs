@@ -0,0 +1,52 @@
+# [allow (non_camel_case_types)]
+pub struct __Settings_Events {
+    pub vsync : bool ,
+}
+impl __Settings_Events {
+    # [inline (always)]
+    pub fn vsync (& self) -> bool {
+        self . vsync
+    }
+    # [doc = r" Names of the events that were triggered."]
+    pub fn widgets_changed (& self) -> Vec < & 'static str > {
+        let mut changed = Vec :: new () ;
+        if self . vsync {
+            changed . push ("vsync") ;
+        }
+        changed
+    }
+}
+impl imgui_ext :: Gui for Settings {
+    type Events = __Settings_Events ;
+    fn draw_gui (ui : & imgui :: Ui , ext : & mut Self) -> Self :: Events {
+        < Self as imgui_ext :: Gui > :: draw_gui_columns (ui , ext , false)
+    }
+    fn draw_gui_columns (ui : & imgui :: Ui , ext : & mut Self , _columns : bool) -> Self :: Events {
+        let mut events : Self :: Events = unsafe {
+            std :: mem :: zeroed ()
+        };
+        {
+            use imgui_ext :: checkbox :: CheckboxParams as Params ;
+            let _ev = imgui_ext :: checkbox :: Checkbox :: build (ui , & mut ext . vsync , Params {
+                label : imgui :: im_str ! ("vsync")
+            }) ;
+            events . vsync |= _ev ;
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        };
+        ;
+        events
+    }
+}
+impl Settings {
+    # [doc = r" Number of widgets drawn by the `imgui` annotations of the fields."]
+    pub const IMGUI_WIDGET_COUNT : usize = 1 ;
+    # [doc = r" Widgets drawn by the `imgui` annotations of the fields, in"]
+    # [doc = r" declaration order."]
+    pub const IMGUI_WIDGETS : & 'static [imgui_ext :: WidgetInfo] = & [imgui_ext :: WidgetInfo {
+        field : "vsync" , kind : "checkbox" , label : Some ("vsync") , min : None , max : None ,
+    }] ;
+}
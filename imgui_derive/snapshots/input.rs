@@ -0,0 +1,81 @@
+# [allow (non_camel_case_types)]
+pub struct __Input_Events {
+    pub a : bool , pub b : bool ,
+}
+impl __Input_Events {
+    # [inline (always)]
+    pub fn a (& self) -> bool {
+        self . a
+    }
+    # [inline (always)]
+    pub fn b (& self) -> bool {
+        self . b
+    }
+    # [doc = r" Names of the events that were triggered."]
+    pub fn widgets_changed (& self) -> Vec < & 'static str > {
+        let mut changed = Vec :: new () ;
+        if self . a {
+            changed . push ("a") ;
+        }
+        if self . b {
+            changed . push ("b") ;
+        }
+        changed
+    }
+}
+impl imgui_ext :: Gui for Input {
+    type Events = __Input_Events ;
+    fn draw_gui (ui : & imgui :: Ui , ext : & mut Self) -> Self :: Events {
+        < Self as imgui_ext :: Gui > :: draw_gui_columns (ui , ext , false)
+    }
+    fn draw_gui_columns (ui : & imgui :: Ui , ext : & mut Self , _columns : bool) -> Self :: Events {
+        let mut events : Self :: Events = unsafe {
+            std :: mem :: zeroed ()
+        };
+        {
+            use imgui_ext :: input :: Input ;
+            let _ev = Input :: build (ui , & mut ext . a , {
+                use imgui_ext :: input :: InputParams as Params ;
+                let mut params = Params {
+                    label : imgui :: im_str ! ("Name") , step : None , step_fast : None , flags : None , size : None , buffer_size : None , max_len : None , unit : None , completion : None , sanitize : None , format : None ,
+                };
+                params
+            }) ;
+            events . a |= _ev ;
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        };
+        {
+            use imgui_ext :: input :: Input ;
+            let _ev = Input :: build (ui , & mut ext . b , {
+                use imgui_ext :: input :: InputParams as Params ;
+                let mut params = Params {
+                    label : imgui :: im_str ! ("b") , step : None , step_fast : None , flags : None , size : None , buffer_size : None , max_len : None , unit : None , completion : None , sanitize : None , format : None ,
+                };
+                params . step = Some (1.0) ;
+                params
+            }) ;
+            events . b |= _ev ;
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        }
+        events
+    }
+}
+impl Input {
+    # [doc = r" Number of widgets drawn by the `imgui` annotations of the fields."]
+    pub const IMGUI_WIDGET_COUNT : usize = 2 ;
+    # [doc = r" Widgets drawn by the `imgui` annotations of the fields, in"]
+    # [doc = r" declaration order."]
+    pub const IMGUI_WIDGETS : & 'static [imgui_ext :: WidgetInfo] = & [imgui_ext :: WidgetInfo {
+        field : "a" , kind : "input" , label : Some ("Name") , min : None , max : None ,
+    }, imgui_ext :: WidgetInfo {
+        field : "b" , kind : "input3" , label : Some ("b") , min : None , max : None ,
+    }] ;
+}
@@ -0,0 +1,132 @@
+# [allow (non_camel_case_types)]
+pub struct __Slider_Events {
+    pub a : bool , pub b_changed : bool , pub c : bool , pub d : bool ,
+}
+impl __Slider_Events {
+    # [inline (always)]
+    pub fn a (& self) -> bool {
+        self . a
+    }
+    pub fn b_changed (& self) -> bool {
+        self . b_changed
+    }
+    # [inline (always)]
+    pub fn c (& self) -> bool {
+        self . c
+    }
+    # [inline (always)]
+    pub fn d (& self) -> bool {
+        self . d
+    }
+    # [doc = r" Names of the events that were triggered."]
+    pub fn widgets_changed (& self) -> Vec < & 'static str > {
+        let mut changed = Vec :: new () ;
+        if self . a {
+            changed . push ("a") ;
+        }
+        if self . b_changed {
+            changed . push ("b_changed") ;
+        }
+        if self . c {
+            changed . push ("c") ;
+        }
+        if self . d {
+            changed . push ("d") ;
+        }
+        changed
+    }
+}
+impl imgui_ext :: Gui for Slider {
+    type Events = __Slider_Events ;
+    fn draw_gui (ui : & imgui :: Ui , ext : & mut Self) -> Self :: Events {
+        < Self as imgui_ext :: Gui > :: draw_gui_columns (ui , ext , false)
+    }
+    fn draw_gui_columns (ui : & imgui :: Ui , ext : & mut Self , _columns : bool) -> Self :: Events {
+        let mut events : Self :: Events = unsafe {
+            std :: mem :: zeroed ()
+        };
+        {
+            use imgui_ext :: slider :: Slider ;
+            let _ev = Slider :: build (ui , & mut ext . a , {
+                use imgui_ext :: slider :: SliderParams as Params ;
+                let mut params = Params {
+                    label : imgui :: im_str ! ("a") , format : None , min : 0.0 , max : 1.0 , power : None , sanitize : false , wrap : false , logarithmic : false , keyboard_step : None , format_value : None ,
+                };
+                params . keyboard_step = Some (0.1f32) ;
+                params
+            }) ;
+            events . a |= _ev ;
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        };
+        {
+            use imgui_ext :: slider :: Slider ;
+            let _ev = Slider :: build (ui , & mut ext . b , {
+                use imgui_ext :: slider :: SliderParams as Params ;
+                let mut params = Params {
+                    label : imgui :: im_str ! ("b") , format : None , min : 0 , max : 8 , power : None , sanitize : false , wrap : false , logarithmic : false , keyboard_step : None , format_value : None ,
+                };
+                params . format = Some (imgui :: im_str ! ("%d")) ;
+                params
+            }) ;
+            events . b_changed |= _ev ;
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        };
+        {
+            use imgui_ext :: slider :: Slider ;
+            let _ev = Slider :: build (ui , & mut ext . c , {
+                use imgui_ext :: slider :: SliderParams as Params ;
+                let mut params = Params {
+                    label : imgui :: im_str ! ("c") , format : None , min : 20.0 , max : 20000.0 , power : None , sanitize : false , wrap : false , logarithmic : true , keyboard_step : None , format_value : None ,
+                };
+                params
+            }) ;
+            events . c |= _ev ;
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        };
+        {
+            use imgui_ext :: slider :: Slider ;
+            let _ev = Slider :: build (ui , & mut ext . d , {
+                use imgui_ext :: slider :: SliderParams as Params ;
+                let mut params = Params {
+                    label : imgui :: im_str ! ("d") , format : None , min : 0.0 , max : 1024.0 , power : None , sanitize : false , wrap : false , logarithmic : false , keyboard_step : None , format_value : None ,
+                };
+                params . format_value = Some (bytes) ;
+                params
+            }) ;
+            events . d |= _ev ;
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        }
+        events
+    }
+}
+impl Slider {
+    # [doc = r" Number of widgets drawn by the `imgui` annotations of the fields."]
+    pub const IMGUI_WIDGET_COUNT : usize = 4 ;
+    # [doc = r" Widgets drawn by the `imgui` annotations of the fields, in"]
+    # [doc = r" declaration order."]
+    pub const IMGUI_WIDGETS : & 'static [imgui_ext :: WidgetInfo] = & [imgui_ext :: WidgetInfo {
+        field : "a" , kind : "slider" , label : Some ("a") , min : Some (0.0) , max : Some (1.0) ,
+    }, imgui_ext :: WidgetInfo {
+        field : "b" , kind : "slider" , label : Some ("b") , min : Some (0.0) , max : Some (8.0) ,
+    }, imgui_ext :: WidgetInfo {
+        field : "c" , kind : "slider" , label : Some ("c") , min : Some (20.0) , max : Some (20000.0) ,
+    }, imgui_ext :: WidgetInfo {
+        field : "d" , kind : "slider" , label : Some ("d") , min : Some (0.0) , max : Some (1024.0) ,
+    }] ;
+}
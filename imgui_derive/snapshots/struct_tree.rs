@@ -0,0 +1,59 @@
+# [allow (non_camel_case_types)]
+pub struct __Light_Events {
+    pub intensity : bool ,
+}
+impl __Light_Events {
+    # [inline (always)]
+    pub fn intensity (& self) -> bool {
+        self . intensity
+    }
+    # [doc = r" Names of the events that were triggered."]
+    pub fn widgets_changed (& self) -> Vec < & 'static str > {
+        let mut changed = Vec :: new () ;
+        if self . intensity {
+            changed . push ("intensity") ;
+        }
+        changed
+    }
+}
+impl imgui_ext :: Gui for Light {
+    type Events = __Light_Events ;
+    fn draw_gui (ui : & imgui :: Ui , ext : & mut Self) -> Self :: Events {
+        < Self as imgui_ext :: Gui > :: draw_gui_columns (ui , ext , false)
+    }
+    fn draw_gui_columns (ui : & imgui :: Ui , ext : & mut Self , _columns : bool) -> Self :: Events {
+        let mut events : Self :: Events = unsafe {
+            std :: mem :: zeroed ()
+        };
+        let _id = ui . push_id (ext as * const Self) ;
+        imgui :: TreeNode :: new (ui , imgui :: im_str ! ("Light")) . build (|| {
+            {
+                use imgui_ext :: slider :: Slider ;
+                let _ev = Slider :: build (ui , & mut ext . intensity , {
+                    use imgui_ext :: slider :: SliderParams as Params ;
+                    let mut params = Params {
+                        label : imgui :: im_str ! ("intensity") , format : None , min : 0.0 , max : 10.0 , power : None , sanitize : false , wrap : false , logarithmic : false , keyboard_step : None , format_value : None ,
+                    };
+                    params
+                }) ;
+                events . intensity |= _ev ;
+            };
+            {
+                if _columns {
+                    ui . next_column () ;
+                }
+            }
+        }) ;
+        _id . pop (ui) ;
+        events
+    }
+}
+impl Light {
+    # [doc = r" Number of widgets drawn by the `imgui` annotations of the fields."]
+    pub const IMGUI_WIDGET_COUNT : usize = 1 ;
+    # [doc = r" Widgets drawn by the `imgui` annotations of the fields, in"]
+    # [doc = r" declaration order."]
+    pub const IMGUI_WIDGETS : & 'static [imgui_ext :: WidgetInfo] = & [imgui_ext :: WidgetInfo {
+        field : "intensity" , kind : "slider" , label : Some ("intensity") , min : Some (0.0) , max : Some (10.0) ,
+    }] ;
+}
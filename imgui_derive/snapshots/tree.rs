@@ -0,0 +1,89 @@
+# [allow (non_camel_case_types)]
+pub struct __Tree_Events {
+    pub inner : < Inner as imgui_ext :: Gui > :: Events , pub value : bool ,
+}
+impl __Tree_Events {
+    pub fn inner (& self) -> & < Inner as imgui_ext :: Gui > :: Events {
+        & self . inner
+    }
+    # [inline (always)]
+    pub fn value (& self) -> bool {
+        self . value
+    }
+    # [doc = r" Names of the events that were triggered."]
+    pub fn widgets_changed (& self) -> Vec < & 'static str > {
+        let mut changed = Vec :: new () ;
+        if self . value {
+            changed . push ("value") ;
+        }
+        changed
+    }
+}
+impl imgui_ext :: Gui for Tree {
+    type Events = __Tree_Events ;
+    fn draw_gui (ui : & imgui :: Ui , ext : & mut Self) -> Self :: Events {
+        < Self as imgui_ext :: Gui > :: draw_gui_columns (ui , ext , false)
+    }
+    fn draw_gui_columns (ui : & imgui :: Ui , ext : & mut Self , _columns : bool) -> Self :: Events {
+        let mut events : Self :: Events = unsafe {
+            std :: mem :: zeroed ()
+        };
+        {
+            let mut tree = imgui :: TreeNode :: new (ui , imgui :: im_str ! ("Inner")) ;
+            {
+            }
+            tree . build (|| {
+                {
+                    use imgui_ext :: Gui ;
+                    let _ev = Gui :: draw_gui (ui , & mut ext . inner) ;
+                    events . inner = _ev ;
+                }
+            })
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        };
+        {
+            let mut tree = imgui :: TreeNode :: new (ui , imgui :: im_str ! ("value")) ;
+            {
+                tree = tree . opened (true , imgui :: Condition :: FirstUseEver) ;
+            }
+            tree . build (|| {
+                {
+                    let _elem = as_f32 (& mut ext . value) ;
+                    # [allow (unused_mut)]
+                    let mut _ev = {
+                        use imgui_ext :: drag :: Drag ;
+                        Drag :: build (ui , & mut * _elem , {
+                            use imgui_ext :: drag :: DragParams as Params ;
+                            let mut params = Params {
+                                label : imgui :: im_str ! ("value") , min : None , max : None , speed : None , power : None , format : None , sanitize : false , unit : None ,
+                            };
+                            params
+                        })
+                    };
+                    events . value |= _ev ;
+                }
+            })
+        };
+        {
+            if _columns {
+                ui . next_column () ;
+            }
+        }
+        events
+    }
+}
+impl Tree {
+    # [doc = r" Number of widgets drawn by the `imgui` annotations of the fields."]
+    pub const IMGUI_WIDGET_COUNT : usize = 2 ;
+    # [doc = r" Widgets drawn by the `imgui` annotations of the fields, in"]
+    # [doc = r" declaration order."]
+    pub const IMGUI_WIDGETS : & 'static [imgui_ext :: WidgetInfo] = & [imgui_ext :: WidgetInfo {
+        field : "inner" , kind : "tree" , label : None , min : None , max : None ,
+    }, imgui_ext :: WidgetInfo {
+        field : "value" , kind : "tree" , label : None , min : None , max : None ,
+    }] ;
+}
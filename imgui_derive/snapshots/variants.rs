@@ -0,0 +1,28 @@
+impl imgui_ext :: variants :: Variants for Quality {
+    const VARIANTS : & 'static [(& 'static str , i32)] = & [("Low" , Quality :: Low as i32) , ("Medium" , Quality :: Medium as i32) , ("High" , Quality :: High as i32)] ;
+    fn index (& self) -> usize {
+        match * self {
+            Quality :: Low => 0 , Quality :: Medium => 1 , Quality :: High => 2 ,
+        }
+    }
+    fn from_index (index : usize) -> Self {
+        match index {
+            0 => Quality :: Low , 1 => Quality :: Medium , 2 => Quality :: High , _ => Quality :: High ,
+        }
+    }
+}
+impl imgui_ext :: Bounded < i32 > for Quality {
+    fn imgui_bounds () -> (i32 , i32) {
+        imgui_ext :: variants :: bounds :: < Self > ()
+    }
+}
+impl imgui_ext :: slider :: Slider < i32 > for Quality {
+    fn build (ui : & imgui :: Ui , elem : & mut Self , params : imgui_ext :: slider :: SliderParams < i32 >) -> bool {
+        imgui_ext :: variants :: build (ui , elem , params)
+    }
+}
+impl imgui_ext :: Gui for Quality {
+    type Events = () ;
+    fn draw_gui (_ : & imgui :: Ui , _ : & mut Self) {
+    }
+}
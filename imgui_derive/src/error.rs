@@ -7,12 +7,36 @@ pub enum ErrorKind {
     InvalidFormat,
     Multiple,
     NonStruct,
+    /// `Gui` derived on a union.
+    Union,
     UnexpectedMode,
     UnexpectedParam,
+    /// Unexpected parameter with a close match among the expected ones.
+    Suggestion(&'static str),
     Bullet,
     AlreadyDefined,
     ParseError,
     MissingParam(&'static str),
+    NoAlpha,
+    /// Format string that would be unsafe to pass to `printf`.
+    Printf(&'static str),
+    /// `inputN` annotation on a field that isn't a `[f32; N]`.
+    Arity(usize),
+    /// Number of `labels` doesn't match the number of components.
+    Labels(usize),
+    /// Widget that edits the field, used on a `PhantomData` field.
+    Phantom,
+    /// Option that only applies to float fields, used on an integer field.
+    FloatOnly(&'static str),
+    /// Numeric literal of the wrong kind (`"an integer"` or `"a float"`
+    /// expected).
+    Literal(&'static str),
+    /// Exclusive range (`a..b`) with float bounds.
+    ExclusiveRange,
+    /// Struct-level option on a field-less enum.
+    VariantsOption,
+    /// Field-less enum without `#[repr(i32)]`.
+    VariantsRepr,
 }
 
 impl fmt::Display for ErrorKind {
@@ -24,8 +48,15 @@ impl fmt::Display for ErrorKind {
                 fmt,
                 "ImGuiExt macro is only supported for structs with named fields."
             ),
+            ErrorKind::Union => write!(
+                fmt,
+                "ImGuiExt macro doesn't support unions. Use a struct with named fields instead."
+            ),
             ErrorKind::UnexpectedMode => write!(fmt, "Unexpected annotation."),
             ErrorKind::UnexpectedParam => write!(fmt, "Unexpected parameter."),
+            ErrorKind::Suggestion(s) => {
+                write!(fmt, "Unexpected parameter. Did you mean `{}`?", s)
+            }
             ErrorKind::Bullet => write!(
                 fmt,
                 "Multiple nested annotations inside of a bullet list element."
@@ -33,6 +64,32 @@ impl fmt::Display for ErrorKind {
             ErrorKind::AlreadyDefined => write!(fmt, "Field is defined already."),
             ErrorKind::ParseError => write!(fmt, "String parsing error."),
             ErrorKind::MissingParam(p) => write!(fmt, "Parameter `{}` missing.", p),
+            ErrorKind::NoAlpha => write!(fmt, "Alpha flags are not supported on RGB colors."),
+            ErrorKind::Printf(reason) => write!(fmt, "Invalid format string: {}", reason),
+            ErrorKind::Arity(n) => write!(fmt, "Expected a field of type `[f32; {}]`.", n),
+            ErrorKind::Labels(n) => write!(fmt, "Expected {} labels (one per component).", n),
+            ErrorKind::Phantom => write!(
+                fmt,
+                "Only layout annotations (separator, new_line, button, text(\"...\"), bullet(text = \"...\")) are allowed on `PhantomData` fields."
+            ),
+            ErrorKind::FloatOnly(option) => write!(
+                fmt,
+                "`{}` only applies to float fields (`f32` and `f64`).",
+                option
+            ),
+            ErrorKind::Literal(kind) => write!(fmt, "Expected {} literal for this field.", kind),
+            ErrorKind::ExclusiveRange => write!(
+                fmt,
+                "Exclusive ranges need integer bounds. Use `min..=max` on floats."
+            ),
+            ErrorKind::VariantsOption => write!(
+                fmt,
+                "Options (`tree`, `patch`, `doc_tooltips`) are not supported on field-less enums."
+            ),
+            ErrorKind::VariantsRepr => write!(
+                fmt,
+                "Field-less enums need `#[repr(i32)]`, so their discriminants fit in an `i32`."
+            ),
         }
     }
 }
@@ -54,6 +111,10 @@ impl Error {
         Self { kind, span }
     }
 
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
     pub fn to_compile_error(&self) -> TokenStream {
         syn::Error::new(self.span.clone(), self.kind).to_compile_error()
     }
@@ -88,6 +149,14 @@ impl Error {
         }
     }
 
+    /// `Gui` derived on a union (`span` points at the `union` keyword).
+    pub fn union(span: Span) -> Self {
+        Self {
+            kind: ErrorKind::Union,
+            span,
+        }
+    }
+
     /// Unexpected annotation mode.
     pub fn unexpected_mode(span: Span) -> Self {
         Self {
@@ -103,6 +172,18 @@ impl Error {
         }
     }
 
+    /// Unexpected parameter `name`. Suggests the closest of the `expected`
+    /// params in the error message, if there is one.
+    pub fn unknown_param(span: Span, name: &str, expected: &[&'static str]) -> Self {
+        match suggest(name, expected) {
+            Some(s) => Self {
+                kind: ErrorKind::Suggestion(s),
+                span,
+            },
+            None => Self::unexpected_param(span),
+        }
+    }
+
     pub fn bullet(span: Span) -> Self {
         Self {
             kind: ErrorKind::Bullet,
@@ -124,4 +205,172 @@ impl Error {
             span,
         }
     }
+
+    /// Alpha flag used on a color without an alpha channel.
+    pub fn no_alpha(span: Span) -> Self {
+        Self {
+            kind: ErrorKind::NoAlpha,
+            span,
+        }
+    }
+
+    /// `inputN` annotation on a field of the wrong type.
+    pub fn arity(span: Span, n: usize) -> Self {
+        Self {
+            kind: ErrorKind::Arity(n),
+            span,
+        }
+    }
+
+    /// Wrong number of labels for the components of a field.
+    pub fn labels(span: Span, n: usize) -> Self {
+        Self {
+            kind: ErrorKind::Labels(n),
+            span,
+        }
+    }
+
+    /// Annotation that reads or writes a `PhantomData` field.
+    pub fn phantom(span: Span) -> Self {
+        Self {
+            kind: ErrorKind::Phantom,
+            span,
+        }
+    }
+
+    /// Float-only `option` used on an integer field.
+    pub fn float_only(span: Span, option: &'static str) -> Self {
+        Self {
+            kind: ErrorKind::FloatOnly(option),
+            span,
+        }
+    }
+
+    /// Numeric literal that doesn't match the type of the field.
+    pub fn literal(span: Span, kind: &'static str) -> Self {
+        Self {
+            kind: ErrorKind::Literal(kind),
+            span,
+        }
+    }
+
+    /// Exclusive range with float bounds.
+    pub fn exclusive_range(span: Span) -> Self {
+        Self {
+            kind: ErrorKind::ExclusiveRange,
+            span,
+        }
+    }
+
+    /// `#[imgui(...)]` option on a field-less enum.
+    pub fn variants_option(span: Span) -> Self {
+        Self {
+            kind: ErrorKind::VariantsOption,
+            span,
+        }
+    }
+
+    /// Field-less enum whose discriminants may not fit in an `i32`.
+    pub fn variants_repr(span: Span) -> Self {
+        Self {
+            kind: ErrorKind::VariantsRepr,
+            span,
+        }
+    }
+
+    /// Format string rejected by `validate_format`.
+    pub fn printf(span: Span, reason: &'static str) -> Self {
+        Self {
+            kind: ErrorKind::Printf(reason),
+            span,
+        }
+    }
+}
+
+/// Returns the closest candidate to `name`, as long as they are close enough
+/// (to avoid suggesting nonsense).
+fn suggest(name: &str, candidates: &[&'static str]) -> Option<&'static str> {
+    let max = std::cmp::max(1, name.len() / 3);
+    candidates
+        .iter()
+        .map(|c| (levenshtein(name, c), *c))
+        .filter(|(d, _)| *d <= max)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, c)| c)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { prev } else { prev + 1 };
+            prev = row[j + 1];
+            row[j + 1] = std::cmp::min(cost, std::cmp::min(row[j], row[j + 1]) + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggestion() {
+        let err = Error::unknown_param(Span::call_site(), "mx", &["label", "min", "max"]);
+        assert_eq!(ErrorKind::Suggestion("max"), err.kind);
+        assert_eq!(
+            "Unexpected parameter. Did you mean `max`?",
+            err.kind.to_string()
+        );
+
+        let err = Error::unknown_param(Span::call_site(), "foo", &["label", "min", "max"]);
+        assert_eq!(ErrorKind::UnexpectedParam, err.kind);
+    }
+
+    #[test]
+    fn union() {
+        let input = syn::parse_quote! {
+            union Bits {
+                int: u32,
+                float: f32,
+            }
+        };
+        let err = crate::impl_derive(&input).err().unwrap();
+        assert_eq!(ErrorKind::Union, err.kind);
+    }
+
+    #[test]
+    fn variants() {
+        let input = syn::parse_quote! {
+            enum Quality {
+                Low,
+                High = 0x1_0000_0000,
+            }
+        };
+        let err = crate::impl_derive(&input).err().unwrap();
+        assert_eq!(ErrorKind::VariantsRepr, err.kind);
+
+        let input = syn::parse_quote! {
+            #[repr(i32)]
+            #[imgui(tree)]
+            enum Quality {
+                Low,
+                High,
+            }
+        };
+        let err = crate::impl_derive(&input).err().unwrap();
+        assert_eq!(ErrorKind::VariantsOption, err.kind);
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(0, levenshtein("max", "max"));
+        assert_eq!(1, levenshtein("mx", "max"));
+        assert_eq!(3, levenshtein("kitten", "sitting"));
+        assert_eq!(3, levenshtein("", "min"));
+    }
 }
@@ -4,16 +4,19 @@ extern crate proc_macro;
 
 use std::collections::HashSet;
 
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Data, DeriveInput, Fields, Ident, Variant};
+use proc_macro2::{Literal, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{
+    parse_macro_input, punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Data,
+    DeriveInput, Field, Fields, Generics, Ident, Variant, WherePredicate,
+};
 
 use error::Error;
 
-use crate::error::ErrorKind;
-
 mod error;
 mod parser;
+#[cfg(test)]
+mod snapshot;
 
 #[proc_macro_derive(Gui, attributes(imgui))]
 pub fn ui_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -24,40 +27,277 @@ pub fn ui_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     }
 }
 
+/// Name of the `Gui` derive in previous versions. Expands to the same impl.
+#[deprecated(since = "0.4.0", note = "Use `#[derive(Gui)]` instead.")]
+#[proc_macro_derive(ImGuiExt, attributes(imgui))]
+pub fn ui_derive_deprecated(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    ui_derive(input)
+}
+
 fn impl_derive(input: &DeriveInput) -> Result<TokenStream, Error> {
     let name = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-    let (body, catch_fields, catch_methods) = match input.data {
-        Data::Struct(ref body) => struct_body(body.fields.clone()),
+    // Field-less enums are edited with a slider over their discriminants.
+    if let Data::Enum(ref body) = input.data {
+        if !body.variants.is_empty() && body.variants.iter().all(|v| v.fields.is_empty()) {
+            // the enum draws nothing, so the options would be ignored
+            if let Some(attr) = input.attrs.iter().find(|attr| attr.path.is_ident("imgui")) {
+                return Err(Error::variants_option(attr.span()));
+            }
+            // discriminants are read with `as i32`, which would truncate them
+            if !parser::is_repr_i32(&input.attrs) {
+                return Err(Error::variants_repr(name.span()));
+            }
+            return Ok(variants_impl(name, &body.variants));
+        }
+    }
+
+    // Nested types that depend on a type parameter must implement `Gui`.
+    let mut generics = input.generics.clone();
+    let bounds = gui_bounds(input);
+    if !bounds.is_empty() {
+        generics.make_where_clause().predicates.extend(bounds);
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let options = parser::Options::from_attrs(&input.attrs)?;
+    let mut widgets = None;
+    let (body, catch_fields, catch_methods, catch_changed) = match input.data {
+        Data::Struct(ref body) => {
+            let mut info = Vec::new();
+            let body = struct_body(body.fields.clone(), &options, &mut info);
+            widgets = Some(info);
+            body
+        }
         Data::Enum(ref body) => enum_body(body.variants.clone()),
-        _ => Err(Error::non_struct(input.span())),
+        Data::Union(ref body) => Err(Error::union(body.union_token.span)),
     }?;
 
+    // Only structs, the widgets of enums depend on the variant.
+    let widget_consts = match widgets {
+        Some(widgets) => {
+            let count = Literal::usize_unsuffixed(widgets.len());
+            quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Number of widgets drawn by the `imgui` annotations of the fields.
+                    pub const IMGUI_WIDGET_COUNT: usize = #count;
+                    /// Widgets drawn by the `imgui` annotations of the fields, in
+                    /// declaration order.
+                    pub const IMGUI_WIDGETS: &'static [imgui_ext::WidgetInfo] = &[#(#widgets),*];
+                }
+            }
+        }
+        None => TokenStream::new(),
+    };
+
+    // #[imgui(tree)]
+    // The ID of the node is pushed along with the address of the value, so two
+    // fields of the same type get their own node.
+    let body = if options.tree {
+        let label = Literal::string(&name.to_string());
+        quote! {
+            let _id = ui.push_id(ext as *const Self);
+            imgui::TreeNode::new(ui, imgui::im_str!(#label)).build(|| { #body });
+            _id.pop(ui);
+        }
+    } else {
+        body
+    };
+
     // crate a new type.
     // It should never generate a collision
     let event_type = Ident::new(&format!("__{}_Events", name.to_string()), input.span());
 
-    Ok(quote! {
-        #[allow(non_camel_case_types)]
-        pub struct #event_type {
-            #catch_fields
-        }
-        impl #event_type {
-            #catch_methods
+    // The events type has the same generics as the annotated type, so nested
+    // events can depend on them (`<T as Gui>::Events`).
+    let marker = events_marker(&generics);
+
+    // #[imgui(patch)]
+    let patch = if options.patch {
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Draws the UI on a clone of `ext`, leaving `ext` untouched.
+                /// Returns the edited clone along with the events.
+                pub fn draw_gui_patch(ui: &imgui::Ui, ext: &Self) -> (Self, <Self as imgui_ext::Gui>::Events) {
+                    let mut patch = std::clone::Clone::clone(ext);
+                    let events = <Self as imgui_ext::Gui>::draw_gui(ui, &mut patch);
+                    (patch, events)
+                }
+            }
         }
+    } else {
+        TokenStream::new()
+    };
+
+    // Types without events (only text, separators...) don't get an events
+    // type, they return `()` instead.
+    let (events_def, events_ty) = if catch_fields.is_empty() {
+        (TokenStream::new(), quote!(()))
+    } else {
+        let events_def = quote! {
+            #[allow(non_camel_case_types)]
+            pub struct #event_type #impl_generics #where_clause {
+                #catch_fields
+                #marker
+            }
+            impl #impl_generics #event_type #ty_generics #where_clause {
+                #catch_methods
+
+                /// Names of the events that were triggered.
+                pub fn widgets_changed(&self) -> Vec<&'static str> {
+                    let mut changed = Vec::new();
+                    #catch_changed
+                    changed
+                }
+            }
+        };
+        (events_def, quote!(#event_type #ty_generics))
+    };
+
+    Ok(quote! {
+        #events_def
         impl #impl_generics imgui_ext::Gui for #name #ty_generics #where_clause {
-            type Events = #event_type;
+            type Events = #events_ty;
             fn draw_gui(ui: &imgui::Ui, ext: &mut Self) -> Self::Events {
+                <Self as imgui_ext::Gui>::draw_gui_columns(ui, ext, false)
+            }
+            // `_columns` is read by the fields of `nested(columns = N)` UIs.
+            fn draw_gui_columns(ui: &imgui::Ui, ext: &mut Self, _columns: bool) -> Self::Events {
                 // Because all fields are bool, it should be OK to zero the memory (right...?)
                 let mut events: Self::Events = unsafe { std::mem::zeroed() };
                 #body
                 events
             }
         }
+        #patch
+        #widget_consts
     })
 }
 
+/// `Variants` (and the traits of the slider) of a field-less enum. Nothing is
+/// drawn by the enum itself.
+fn variants_impl(name: &Ident, variants: &Punctuated<Variant, Comma>) -> TokenStream {
+    let idents: Vec<&Ident> = variants.iter().map(|variant| &variant.ident).collect();
+    let names = idents
+        .iter()
+        .map(|ident| Literal::string(&ident.to_string()));
+    let indices: Vec<Literal> = (0..idents.len()).map(Literal::usize_unsuffixed).collect();
+    let last = idents.last();
+
+    quote! {
+        impl imgui_ext::variants::Variants for #name {
+            const VARIANTS: &'static [(&'static str, i32)] = &[#( (#names, #name::#idents as i32) ),*];
+
+            fn index(&self) -> usize {
+                match *self {
+                    #( #name::#idents => #indices, )*
+                }
+            }
+
+            fn from_index(index: usize) -> Self {
+                match index {
+                    #( #indices => #name::#idents, )*
+                    _ => #name::#last,
+                }
+            }
+        }
+        impl imgui_ext::Bounded<i32> for #name {
+            fn imgui_bounds() -> (i32, i32) {
+                imgui_ext::variants::bounds::<Self>()
+            }
+        }
+        impl imgui_ext::slider::Slider<i32> for #name {
+            fn build(ui: &imgui::Ui, elem: &mut Self, params: imgui_ext::slider::SliderParams<i32>) -> bool {
+                imgui_ext::variants::build(ui, elem, params)
+            }
+        }
+        impl imgui_ext::Gui for #name {
+            type Events = ();
+            fn draw_gui(_: &imgui::Ui, _: &mut Self) {}
+        }
+    }
+}
+
+/// `Ty: imgui_ext::Gui` bounds for the nested fields (`nested`, `tab_bar`...)
+/// whose type depends on a type parameter.
+fn gui_bounds(input: &DeriveInput) -> Vec<WherePredicate> {
+    let params: HashSet<String> = input
+        .generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .collect();
+    if params.is_empty() {
+        return Vec::new();
+    }
+
+    let fields: Vec<&Field> = match input.data {
+        Data::Struct(ref body) => body.fields.iter().collect(),
+        Data::Enum(ref body) => body
+            .variants
+            .iter()
+            .filter_map(|variant| variant.fields.iter().next())
+            .collect(),
+        _ => Vec::new(),
+    };
+    let attrs = |field: &Field| -> Vec<Attribute> {
+        match input.data {
+            // attributes of enums are placed on the variants
+            Data::Enum(ref body) => body
+                .variants
+                .iter()
+                .find(|variant| variant.fields.iter().next() == Some(field))
+                .map(|variant| variant.attrs.clone())
+                .unwrap_or_default(),
+            _ => field.attrs.clone(),
+        }
+    };
+
+    let mut types = Vec::new();
+    for field in fields {
+        for attr in attrs(field)
+            .iter()
+            .filter(|attr| attr.path.is_ident("imgui"))
+        {
+            // errors are reported when the body is generated
+            let tags = match parser::parse_attr(attr) {
+                Ok(tags) => tags,
+                Err(_) => continue,
+            };
+            if let Some(ty) = parser::nested_type(&field.ty, &tags) {
+                if mentions(ty.to_token_stream(), &params) && !types.contains(&ty) {
+                    types.push(ty);
+                }
+            }
+        }
+    }
+    types
+        .into_iter()
+        .map(|ty| syn::parse_quote!(#ty: imgui_ext::Gui))
+        .collect()
+}
+
+/// Whether `tokens` contain any of the identifiers in `idents`.
+fn mentions(tokens: TokenStream, idents: &HashSet<String>) -> bool {
+    tokens.into_iter().any(|tree| match tree {
+        TokenTree::Ident(ident) => idents.contains(&ident.to_string()),
+        TokenTree::Group(group) => mentions(group.stream(), idents),
+        _ => false,
+    })
+}
+
+/// Field that uses all the generic parameters of the events type. Empty if
+/// there are none.
+fn events_marker(generics: &Generics) -> TokenStream {
+    let lifetimes = generics.lifetimes().map(|param| &param.lifetime);
+    let types = generics.type_params().map(|param| &param.ident);
+    if generics.lifetimes().count() + generics.type_params().count() == 0 {
+        return TokenStream::new();
+    }
+    quote! {
+        __marker: std::marker::PhantomData<(#( &#lifetimes (), )* #( fn() -> #types, )*)>,
+    }
+}
+
 // Adds support to allow multiple imgui tags in a single field:
 // ```
 // struct Demo {
@@ -70,13 +310,18 @@ fn impl_derive(input: &DeriveInput) -> Result<TokenStream, Error> {
 //     #[imgui(input(...))]
 //     y: f32,
 // }
-fn struct_body(fields: Fields) -> Result<(TokenStream, TokenStream, TokenStream), Error> {
+fn struct_body(
+    fields: Fields,
+    options: &parser::Options,
+    widgets: &mut Vec<TokenStream>,
+) -> Result<(TokenStream, TokenStream, TokenStream, TokenStream), Error> {
     let mut input_methods: TokenStream = TokenStream::new();
 
     let mut input_fields: TokenStream = TokenStream::new();
     let mut input_fields_set = HashSet::new();
+    let mut input_changed: TokenStream = TokenStream::new();
 
-    let field_body = fields
+    let mut field_body = fields
         .iter()
         .enumerate()
         .flat_map(|(_, field)| {
@@ -106,7 +351,7 @@ fn struct_body(fields: Fields) -> Result<(TokenStream, TokenStream, TokenStream)
             match (first, second) {
                 // No annotations were found.
                 // Emmit no sourcecode.
-                (None, None) => vec![Ok(TokenStream::new())],
+                (None, None) => vec![Ok((None, TokenStream::new()))],
 
                 // There is more than one imgui annotation.
                 // Raise a descriptive error pointing to the extra annotation.
@@ -115,27 +360,72 @@ fn struct_body(fields: Fields) -> Result<(TokenStream, TokenStream, TokenStream)
                 // There is a single annotation, as it should.
                 // Parse the annotation and emmit the source code for this field
                 (Some(attr), None) => {
-                    let tags = attr
-                        .parse_meta() // -> Meta
-                        .map_err(|_| Error::new(ErrorKind::ParseError, attr.span()))
-                        .and_then(parser::parse_meta); // -> Result<Vec<Tag>>
+                    let tags = parser::parse_attr(&attr); // -> Result<Vec<Tag>>
 
-                    match tags {
+                    match tags.and_then(|tags| {
+                        let order = parser::field_order(&attr, &tags)?;
+                        Ok((order, tags))
+                    }) {
                         Err(error) => vec![Err(error)],
-                        Ok(tags) => tags
-                            .into_iter()
-                            .map(|tag| {
-                                parser::emmit_tag_tokens(
-                                    &ident,
-                                    &ty,
-                                    &attr,
-                                    &tag,
-                                    &mut input_fields,
-                                    &mut input_methods,
-                                    &mut input_fields_set,
-                                )
-                            })
-                            .collect(),
+                        Ok((order, tags)) => {
+                            widgets.extend(parser::widget_info(&ident, &tags));
+                            let access = parser::field_access(&ident, &ty, &tags);
+                            let cell = parser::cell_access(&ident, &ty, &tags);
+                            let atomic = parser::atomic_access(&ident, &ty, &tags);
+                            let mut tokens: Vec<_> = tags
+                                .iter()
+                                .map(|tag| {
+                                    parser::emmit_tag_tokens(
+                                        &ident,
+                                        &access,
+                                        &ty,
+                                        &attr,
+                                        tag,
+                                        &mut input_fields,
+                                        &mut input_methods,
+                                        &mut input_fields_set,
+                                        &mut input_changed,
+                                    )
+                                    .map(|tokens| match cell {
+                                        // #[imgui(cell, ...)]
+                                        Some(ref cell) if parser::uses_field(tag) => {
+                                            parser::emmit_cell(cell, tokens)
+                                        }
+                                        _ => tokens,
+                                    })
+                                    .map(|tokens| match atomic {
+                                        // #[imgui(atomic, ...)]
+                                        Some(ref atomic) if parser::uses_field(tag) => {
+                                            parser::emmit_atomic(atomic, tokens)
+                                        }
+                                        _ => tokens,
+                                    })
+                                    .map(|tokens| (order, tokens))
+                                })
+                                .collect();
+
+                            // #[imgui(doc_tooltips)]
+                            // The tooltip belongs to the last widget of the field.
+                            if options.doc_tooltips {
+                                if let Some(doc) = parser::doc_text(&field.attrs) {
+                                    tokens.push(Ok((order, parser::emmit_doc_tooltip(&doc))));
+                                }
+                            }
+
+                            // nested(columns = N)
+                            // Each field that draws something takes a column.
+                            if parser::widget_count(&tags) > 0 {
+                                tokens.push(Ok((
+                                    order,
+                                    quote! {{
+                                        if _columns {
+                                            ui.next_column();
+                                        }
+                                    }},
+                                )));
+                            }
+                            tokens
+                        }
                     }
                 }
 
@@ -144,14 +434,30 @@ fn struct_body(fields: Fields) -> Result<(TokenStream, TokenStream, TokenStream)
         })
         .collect::<Result<Vec<_>, Error>>()?;
 
-    Ok((quote! { #( #field_body );*}, input_fields, input_methods))
+    // #[imgui(order = N)]
+    // Ordered fields go first. The sort is stable, so the rest keep the
+    // declaration order.
+    field_body.sort_by_key(|&(order, _)| match order {
+        Some(order) => (false, order),
+        None => (true, 0),
+    });
+    let field_body = field_body.into_iter().map(|(_, tokens)| tokens);
+
+    Ok((
+        quote! { #( #field_body );*},
+        input_fields,
+        input_methods,
+        input_changed,
+    ))
 }
 
-fn enum_body(variants: Punctuated<Variant, Comma>) -> Result<(TokenStream, TokenStream, TokenStream), Error> {
+fn enum_body(
+    variants: Punctuated<Variant, Comma>,
+) -> Result<(TokenStream, TokenStream, TokenStream, TokenStream), Error> {
     let mut input_fields: TokenStream = TokenStream::new();
     let mut input_methods: TokenStream = TokenStream::new();
     let mut input_fields_set = HashSet::new();
-
+    let mut input_changed: TokenStream = TokenStream::new();
 
     let field_body = variants
         .iter()
@@ -193,10 +499,7 @@ fn enum_body(variants: Punctuated<Variant, Comma>) -> Result<(TokenStream, Token
                 // There is a single annotation, as it should.
                 // Parse the annotation and emmit the source code for this field
                 (Some(attr), None) => {
-                    let tags = attr
-                        .parse_meta() // -> Meta
-                        .map_err(|_| Error::new(ErrorKind::ParseError, attr.span()))
-                        .and_then(parser::parse_meta); // -> Result<Vec<Tag>>
+                    let tags = parser::parse_attr(&attr); // -> Result<Vec<Tag>>
 
                     match tags {
                         Err(error) => vec![Err(error)],
@@ -205,12 +508,14 @@ fn enum_body(variants: Punctuated<Variant, Comma>) -> Result<(TokenStream, Token
                             .map(|tag| {
                                 parser::emmit_tag_tokens(
                                     ident,
+                                    &quote!(ext.#ident),
                                     ty,
                                     &attr,
                                     &tag,
                                     &mut input_fields,
                                     &mut input_methods,
                                     &mut input_fields_set,
+                                    &mut input_changed,
                                 )
                             })
                             .collect(),
@@ -219,8 +524,13 @@ fn enum_body(variants: Punctuated<Variant, Comma>) -> Result<(TokenStream, Token
 
                 _ => unreachable!(),
             }
-    })
-    .collect::<Result<Vec<_>, Error>>()?;
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
 
-    Ok((quote! { #( #field_body );*}, input_fields, input_methods))
+    Ok((
+        quote! { #( #field_body );*},
+        input_fields,
+        input_methods,
+        input_changed,
+    ))
 }
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 use std::string::ToString;
 
-use proc_macro2::{Literal, TokenStream};
+use proc_macro2::{Delimiter, Literal, Punct, Spacing, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
 use syn::spanned::Spanned;
 use syn::{Attribute, Ident, Lit, Meta, MetaList, MetaNameValue, NestedMeta, Path, Type};
@@ -14,23 +14,79 @@ macro_rules! tag {
         pub struct $tag:ident {
             fields { $( $field:ident : Lit ,)* },
             optional { $( $opt_field:ident : Option<Lit> ,)* }
+            $(, flags { $( $flag:ident : bool ,)* } )?
         }
     ) => {
         $(#[$meta])*
         pub struct $tag {
             $( $field : Lit ,)*
             $( $opt_field : Option<Lit> ,)*
+            $($( $flag : bool ,)*)?
+            /// `same_line` modifier, shared by all tags.
+            same_line: bool,
+            /// `id_offset` option, shared by all tags.
+            id_offset: Option<Lit>,
+            /// `disabled_if` option, shared by all tags.
+            disabled_if: Option<Lit>,
+            /// `hidden_fn` option, shared by all tags.
+            hidden_fn: Option<Lit>,
+            /// `frame_padding` option, shared by all tags.
+            frame_padding: Option<Lit>,
+            /// `label = CONST` (a path to a `&'static str` constant), on the
+            /// tags that have a `label`.
+            #[allow(dead_code)]
+            label_path: Option<Path>,
         }
         impl $tag {
             fn from_meta_list(list: &MetaList) -> Result<Self, Error> {
                 $( let mut $field = None; )*
                 $( let mut $opt_field = None; )*
+                $($( let mut $flag = false; )*)?
+                let mut same_line = false;
+                let mut id_offset = None;
+                let mut disabled_if = None;
+                let mut hidden_fn = None;
+                let mut frame_padding = None;
+                let mut label_path = None;
+                // `label = "..."` was given, which `label = CONST` can't be combined with
+                let mut label_lit = false;
+                let has_label = [$( stringify!($opt_field), )*].contains(&"label");
                 for param in list.nested.iter() {
                     match param {
                         NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })) => {
                             let ident = path_to_ident(&path);
+                            if has_label && ident == "label" {
+                                if label_path.is_some() {
+                                    return Err(Error::already_defined(ident.span()))
+                                }
+                                label_lit = true;
+                            }
                             match ident.to_string().as_str() {
                                 //"label" => widget.label = Some(lit.clone()),
+                                "id_offset" => {
+                                    if id_offset.is_some() {
+                                        return Err(Error::already_defined(ident.span()))
+                                    }
+                                    id_offset = Some(lit.clone());
+                                },
+                                "disabled_if" => {
+                                    if disabled_if.is_some() {
+                                        return Err(Error::already_defined(ident.span()))
+                                    }
+                                    disabled_if = Some(lit.clone());
+                                },
+                                "hidden_fn" => {
+                                    if hidden_fn.is_some() {
+                                        return Err(Error::already_defined(ident.span()))
+                                    }
+                                    hidden_fn = Some(lit.clone());
+                                },
+                                "frame_padding" => {
+                                    if frame_padding.is_some() {
+                                        return Err(Error::already_defined(ident.span()))
+                                    }
+                                    frame_padding = Some(lit.clone());
+                                },
                                 $( stringify!($opt_field) => {
                                     if $opt_field.is_some() {
                                         return Err(Error::already_defined(ident.span()))
@@ -43,7 +99,53 @@ macro_rules! tag {
                                     }
                                     $field = Some(lit.clone());
                                 },)*
-                                _ => return Err(Error::unexpected_param(ident.span())),
+                                name => {
+                                    let expected = &[
+                                        $( stringify!($field), )*
+                                        $( stringify!($opt_field), )*
+                                        "id_offset",
+                                        "disabled_if",
+                                        "hidden_fn",
+                                        "frame_padding",
+                                    ];
+                                    return Err(Error::unknown_param(ident.span(), name, expected))
+                                }
+                            }
+                        }
+                        // flags (params without a value)
+                        NestedMeta::Meta(Meta::Path(path)) => {
+                            let ident = path_to_ident(&path);
+                            match ident.to_string().as_str() {
+                                "same_line" => {
+                                    if same_line {
+                                        return Err(Error::already_defined(ident.span()))
+                                    }
+                                    same_line = true;
+                                },
+                                $($( stringify!($flag) => {
+                                    if $flag {
+                                        return Err(Error::already_defined(ident.span()))
+                                    }
+                                    $flag = true;
+                                },)*)?
+                                name => {
+                                    let expected = &[ "same_line", $($( stringify!($flag), )*)? ];
+                                    return Err(Error::unknown_param(ident.span(), name, expected))
+                                }
+                            }
+                        }
+                        // `label = CONST`, rewritten as `label(CONST)` by `desugar`
+                        NestedMeta::Meta(Meta::List(MetaList { path, nested, .. }))
+                            if has_label && path.is_ident("label") =>
+                        {
+                            if label_path.is_some() || label_lit {
+                                return Err(Error::already_defined(path.span()))
+                            }
+                            match nested.iter().collect::<Vec<_>>().as_slice() {
+                                [NestedMeta::Meta(Meta::Path(const_path))] => {
+                                    label_path = Some(const_path.clone());
+                                }
+                                _ => return Err(Error::invalid_format(nested.span())),
                             }
                         }
                         // TODO use proper span
@@ -54,6 +156,13 @@ macro_rules! tag {
                     //$( $field : $field.ok_or(Error::new(list.span(), format!("Parameter `{}` missing.", stringify!($field) )))?,)*
                     $( $field : $field.ok_or(Error::missing_param(list.span(), stringify!($field)))?,)*
                     $( $opt_field,)*
+                    $($( $flag,)*)?
+                    same_line,
+                    id_offset,
+                    disabled_if,
+                    hidden_fn,
+                    frame_padding,
+                    label_path,
                 })
             }
         }
@@ -141,6 +250,13 @@ tag! {
             catch: Option<Lit>,
             map: Option<Lit>,
             selected: Option<Lit>,
+            on_select: Option<Lit>,
+            icons: Option<Lit>,
+            sections: Option<Lit>,
+        },
+        flags {
+            label_inline: bool,
+            typeahead: bool,
         }
     }
 }
@@ -156,6 +272,10 @@ tag! {
             label: Option<Lit>,
             catch: Option<Lit>,
             map: Option<Lit>,
+            toggled: Option<Lit>,
+        },
+        flags {
+            label_inline: bool,
         }
     }
 }
@@ -175,23 +295,54 @@ tag! {
             catch: Option<Lit>,
             size: Option<Lit>,
             map: Option<Lit>,
+            buffer_size: Option<Lit>,
+            max_len: Option<Lit>,
+            unit: Option<Lit>,
+            completion: Option<Lit>,
+            sanitize: Option<Lit>,
+            format: Option<Lit>,
+            get: Option<Lit>,
+            set: Option<Lit>,
+        },
+        flags {
+            label_inline: bool,
+            auto_select_all: bool,
+            enter_returns_true: bool,
+            clear_button: bool,
         }
     }
 }
 
 tag! {
     /// `#[imgui(slider(label = "...", min = 0.0, max = 4.0, format = "..."))]`
+    #[derive(Default)]
     pub struct Slider {
         fields {
-            min: Lit,
-            max: Lit,
+            // none
         },
         optional {
+            min: Option<Lit>,
+            max: Option<Lit>,
             label: Option<Lit>,
             format: Option<Lit>,
             power: Option<Lit>,
+            keyboard_step: Option<Lit>,
+            format_value: Option<Lit>,
             catch: Option<Lit>,
             map: Option<Lit>,
+            started: Option<Lit>,
+            finished: Option<Lit>,
+            get: Option<Lit>,
+            set: Option<Lit>,
+            reset_to: Option<Lit>,
+        },
+        flags {
+            label_inline: bool,
+            sanitize: bool,
+            wrap: bool,
+            logarithmic: bool,
+            bounded: bool,
+            align: bool,
         }
     }
 }
@@ -207,10 +358,26 @@ tag! {
             min: Option<Lit>,
             max: Option<Lit>,
             speed: Option<Lit>,
+            speed_field: Option<Lit>,
+            speed_fast: Option<Lit>,
             power: Option<Lit>,
             format: Option<Lit>,
             catch: Option<Lit>,
             map: Option<Lit>,
+            unit: Option<Lit>,
+            labels: Option<Lit>,
+            started: Option<Lit>,
+            finished: Option<Lit>,
+            get: Option<Lit>,
+            set: Option<Lit>,
+            reset_to: Option<Lit>,
+        },
+        flags {
+            label_inline: bool,
+            sanitize: bool,
+            reset_on_dclick: bool,
+            bounded: bool,
+            align: bool,
         }
     }
 }
@@ -223,6 +390,38 @@ tag! {
         optional {
             size: Option<Lit>,
             catch: Option<Lit>,
+        },
+        flags {
+            fill: bool,
+        }
+    }
+}
+
+tag! {
+    /// `#[imgui(buttons(cols = 3))]` on a `[ImString; N]` or `Vec<ImString>`.
+    pub struct Buttons {
+        fields {
+            cols: Lit,
+        },
+        optional {
+            size: Option<Lit>,
+            catch: Option<Lit>,
+            on_click: Option<Lit>,
+        }
+    }
+}
+
+tag! {
+    /// `#[imgui(separator)]`
+    /// `#[imgui(separator(vertical))]`
+    #[derive(Default)]
+    pub struct Separator {
+        fields {
+        },
+        optional {
+        },
+        flags {
+            vertical: bool,
         }
     }
 }
@@ -248,6 +447,65 @@ tag! {
         optional {
             catch: Option<Lit>,
             map: Option<Lit>,
+            id: Option<Lit>,
+            columns: Option<Lit>,
+        }
+    }
+}
+
+tag! {
+    /// `#[imgui(group(border, size = "..."))]`
+    #[derive(Default)]
+    pub struct Group {
+        fields {
+        },
+        optional {
+            size: Option<Lit>,
+            catch: Option<Lit>,
+        },
+        flags {
+            border: bool,
+        }
+    }
+}
+
+tag! {
+    /// `#[imgui(tab_bar(catch = "..."))]`
+    #[derive(Default)]
+    pub struct TabBar {
+        fields {
+        },
+        optional {
+            catch: Option<Lit>,
+        }
+    }
+}
+
+tag! {
+    /// `#[imgui(tab_item(label = "...", open = "...", closable = "...", flags = "..."))]`
+    #[derive(Default)]
+    pub struct TabItem {
+        fields {
+        },
+        optional {
+            label: Option<Lit>,
+            flags: Option<Lit>,
+            open: Option<Lit>,
+            closable: Option<Lit>,
+            catch: Option<Lit>,
+        }
+    }
+}
+
+tag! {
+    /// `#[imgui(list)]` on a `Vec<T: Gui + Default>`.
+    #[derive(Default)]
+    pub struct List {
+        fields {
+        },
+        optional {
+            label: Option<Lit>,
+            catch: Option<Lit>,
         }
     }
 }
@@ -285,10 +543,12 @@ tag! {
         },
         optional {
             background: Option<Lit>,
-            frame_padding: Option<Lit>,
+            bg: Option<Lit>,
             tint: Option<Lit>,
             uv0: Option<Lit>,
             uv1: Option<Lit>,
+            image_padding: Option<Lit>,
+            catch: Option<Lit>,
         }
     }
 }
@@ -306,6 +566,11 @@ tag! {
             catch: Option<Lit>,
             input_mode: Option<Lit>,
             map: Option<Lit>,
+        },
+        flags {
+            label_inline: bool,
+            alpha_preview: bool,
+            alpha_preview_half: bool,
         }
     }
 }
@@ -324,6 +589,12 @@ tag! {
             format: Option<Lit>,
             catch: Option<Lit>,
             map: Option<Lit>,
+        },
+        flags {
+            label_inline: bool,
+            alpha_bar: bool,
+            alpha_preview: bool,
+            alpha_preview_half: bool,
         }
     }
 }
@@ -342,36 +613,120 @@ tag! {
             format: Option<Lit>,
             catch: Option<Lit>,
             map: Option<Lit>,
+        },
+        flags {
+            label_inline: bool,
+            alpha_bar: bool,
+            alpha_preview: bool,
+            alpha_preview_half: bool,
+            swatch_only: bool,
         }
     }
 }
 
 tag! {
-    pub struct Text {
+    /// `#[imgui(plot(lines(...)))]`
+    /// `#[imgui(plot(histogram(...)))]`
+    #[derive(Default)]
+    pub struct Plot {
         fields {
-            lit: Lit,
         },
         optional {
+            label: Option<Lit>,
+            overlay: Option<Lit>,
+            size: Option<Lit>,
+            min: Option<Lit>,
+            max: Option<Lit>,
+            offset: Option<Lit>,
+            stride: Option<Lit>,
+        },
+        flags {
+            label_inline: bool,
         }
     }
 }
 
+/// Text, with composable decorators:
+/// - `text("...")` or `text(lit = "...")`
+/// - `text(wrapped, disabled, bullet, colored(color = "..."))`
+///
+/// Without a literal, the value of the field is displayed.
+#[derive(Default)]
+pub struct Text {
+    lit: Option<Lit>,
+    wrapped: bool,
+    disabled: bool,
+    bullet: bool,
+    color: Option<Lit>,
+    same_line: bool,
+    id_offset: Option<Lit>,
+}
+
 impl Text {
-    /// allows parsing:
-    /// - text("...") -> literal form
-    /// - text(lit = "...") -> regular form
-    fn from_meta_list2(list: &MetaList) -> Result<Self, Error> {
-        let mut iter = list.nested.iter();
-        let first = iter.next();
-        let second = iter.next();
-
-        match (first, second) {
-            // text("...")
-            (Some(NestedMeta::Lit(Lit::Str(s))), None) => Ok(Self {
-                lit: Lit::Str(s.clone()),
-            }),
-            _ => Self::from_meta_list(list),
+    fn from_meta_list(list: &MetaList) -> Result<Self, Error> {
+        let mut text = Text::default();
+
+        for (i, meta) in list.nested.iter().enumerate() {
+            match meta {
+                // text("...")
+                NestedMeta::Lit(lit @ Lit::Str(_)) if i == 0 => text.lit = Some(lit.clone()),
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })) => {
+                    let ident = path_to_ident(&path);
+                    let value = match ident.to_string().as_str() {
+                        "lit" => &mut text.lit,
+                        "id_offset" => &mut text.id_offset,
+                        name => {
+                            let expected = &["lit", "id_offset"];
+                            return Err(Error::unknown_param(ident.span(), name, expected));
+                        }
+                    };
+                    if value.is_some() {
+                        return Err(Error::already_defined(ident.span()));
+                    }
+                    *value = Some(lit.clone());
+                }
+                NestedMeta::Meta(Meta::Path(path)) => {
+                    let ident = path_to_ident(&path);
+                    let flag = match ident.to_string().as_str() {
+                        "wrapped" => &mut text.wrapped,
+                        "disabled" => &mut text.disabled,
+                        "bullet" => &mut text.bullet,
+                        "same_line" => &mut text.same_line,
+                        "colored" => return Err(Error::missing_param(ident.span(), "color")),
+                        name => {
+                            let expected =
+                                &["wrapped", "disabled", "bullet", "same_line", "colored"];
+                            return Err(Error::unknown_param(ident.span(), name, expected));
+                        }
+                    };
+                    if *flag {
+                        return Err(Error::already_defined(ident.span()));
+                    }
+                    *flag = true;
+                }
+                // colored(color = "...")
+                NestedMeta::Meta(Meta::List(colored))
+                    if path_to_ident(&colored.path).to_string() == "colored" =>
+                {
+                    if text.color.is_some() {
+                        return Err(Error::already_defined(colored.span()));
+                    }
+                    match colored.nested.iter().next() {
+                        Some(NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                            path,
+                            lit,
+                            ..
+                        }))) if colored.nested.len() == 1 && path.is_ident("color") => {
+                            text.color = Some(lit.clone())
+                        }
+                        _ => return Err(Error::missing_param(colored.span(), "color")),
+                    }
+                }
+                _ => return Err(Error::invalid_format(list.span())),
+            }
         }
+
+        Ok(text)
     }
 }
 
@@ -417,7 +772,10 @@ impl Vars {
                             }
                         }
 
-                        _ => return Err(Error::unexpected_param(ident.span())),
+                        name => {
+                            let expected = &["color", "style"];
+                            return Err(Error::unknown_param(ident.span(), name, expected));
+                        }
                     }
                 }
 
@@ -493,7 +851,10 @@ impl Tree {
                             }
                         }
 
-                        _ => return Err(Error::unexpected_param(ident.span())),
+                        name => {
+                            let expected = &["label", "flags", "cond"];
+                            return Err(Error::unknown_param(ident.span(), name, expected));
+                        }
                     }
                 }
 
@@ -509,9 +870,7 @@ impl Tree {
                     }
                 }
 
-                // Nope
-                _ => panic!(),
-                //_ => return Err(Error::new(list.span(), "fuck")),
+                _ => return Err(Error::invalid_format(list.span())),
             }
         }
 
@@ -530,141 +889,757 @@ pub enum Tag {
     Combobox(Combobox),
     Checkbox(Checkbox),
     Input(Input),
+    /// `#[imgui(input2)]`, `input3` & `input4`. Same as `input`, on a field
+    /// that must be a `[f32; N]`.
+    InputN(usize, Input),
     Slider(Slider),
     Drag(Drag),
     Nested(Nested),
+    Group(Group),
+    TabBar(TabBar),
+    TabItem(TabItem),
+    List(List),
     Progress(Progress),
     Image(Image),
     ImageButton(ImageButton),
     Button(Button),
+    Buttons(Buttons),
 
     ColorButton(ColorButton),
     ColorPicker(ColorPicker),
     ColorEdit(ColorEdit),
 
+    PlotLines(Plot),
+    PlotHistogram(Plot),
+
     /// `#[imgui(separator)]`
-    Separator,
+    Separator(Separator),
     /// `#[imgui(new_line)]`
     NewLine,
     ///
     /// - Litaral`: #[text(literal = "...")]`
     /// - Annotated field (AsRef<str>): `#[text(literal)]`
     Text(Text),
-    TextWrap(Text),
 
     BulletParent,
     Bullet(Bullet),
 
     Tree(Tree),
     Vars(Vars),
+
+    /// `#[imgui(newtype)]`
+    Newtype,
+    /// `#[imgui(borrow)]`
+    Borrow,
+    /// `#[imgui(lock)]`
+    Lock,
+    /// `#[imgui(deref)]`
+    Deref,
+    /// `#[imgui(cell)]`
+    Cell,
+    /// `#[imgui(atomic)]` and `#[imgui(atomic = "Ordering")]`
+    Atomic(Option<syn::LitStr>),
+    /// `#[imgui(order = N)]`
+    Order(Lit),
 }
 
-/// meta is the whole (parsed) tag: `#[imgui]` or `#[imgui(...)]`
-pub fn parse_meta(meta: Meta) -> Result<Vec<Tag>, Error> {
-    match meta {
-        // #[imgui = ...] Nope
-        Meta::NameValue(named) => Err(Error::invalid_format(named.span())),
-        // #[imgui], treated as an empty label
-        Meta::Path(_) => Ok(vec![Tag::Display(Display::default())]),
-        // #[imgui(meta_list)] (general)
-        Meta::List(meta_list) => parse_meta_list(&meta_list),
+impl Tag {
+    /// Whether the tag has the `same_line` modifier.
+    fn same_line(&self) -> bool {
+        match self {
+            Tag::Combobox(t) => t.same_line,
+            Tag::Checkbox(t) => t.same_line,
+            Tag::Input(t) | Tag::InputN(_, t) => t.same_line,
+            Tag::Slider(t) => t.same_line,
+            Tag::Drag(t) => t.same_line,
+            Tag::Nested(t) => t.same_line,
+            Tag::Group(t) => t.same_line,
+            Tag::TabBar(t) => t.same_line,
+            Tag::TabItem(t) => t.same_line,
+            Tag::List(t) => t.same_line,
+            Tag::Progress(t) => t.same_line,
+            Tag::Image(t) => t.same_line,
+            Tag::ImageButton(t) => t.same_line,
+            Tag::Button(t) => t.same_line,
+            Tag::Buttons(t) => t.same_line,
+            Tag::ColorButton(t) => t.same_line,
+            Tag::ColorPicker(t) => t.same_line,
+            Tag::ColorEdit(t) => t.same_line,
+            Tag::PlotLines(t) | Tag::PlotHistogram(t) => t.same_line,
+            Tag::Text(t) => t.same_line,
+            Tag::Bullet(t) => t.same_line,
+            // vertical separators always go between two widgets of a row
+            Tag::Separator(t) => t.same_line || t.vertical,
+            _ => false,
+        }
     }
-}
 
-/// Parse the inside of `#[imgui(...)]`
-///                              ^^^
-/// Possible cases:
-///   - `#[imgui(foo(...), bar(...))]`
-///   - `#[imgui(foo(...),)]`
-///   - `#[imgui(foo(...))]`
-///   - `#[imgui(label = "...", display = "...", foo, bar)]`
-fn parse_meta_list(meta_list: &MetaList) -> Result<Vec<Tag>, Error> {
-    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-    enum State {
-        Init,
-        //Label,
-        Tags,
+    /// Value of the `id_offset` option, if the tag has one.
+    fn id_offset(&self) -> Option<&Lit> {
+        match self {
+            Tag::Combobox(t) => t.id_offset.as_ref(),
+            Tag::Checkbox(t) => t.id_offset.as_ref(),
+            Tag::Input(t) | Tag::InputN(_, t) => t.id_offset.as_ref(),
+            Tag::Slider(t) => t.id_offset.as_ref(),
+            Tag::Drag(t) => t.id_offset.as_ref(),
+            Tag::Nested(t) => t.id_offset.as_ref(),
+            Tag::Group(t) => t.id_offset.as_ref(),
+            Tag::TabBar(t) => t.id_offset.as_ref(),
+            Tag::TabItem(t) => t.id_offset.as_ref(),
+            Tag::List(t) => t.id_offset.as_ref(),
+            Tag::Progress(t) => t.id_offset.as_ref(),
+            Tag::Image(t) => t.id_offset.as_ref(),
+            Tag::ImageButton(t) => t.id_offset.as_ref(),
+            Tag::Button(t) => t.id_offset.as_ref(),
+            Tag::Buttons(t) => t.id_offset.as_ref(),
+            Tag::ColorButton(t) => t.id_offset.as_ref(),
+            Tag::ColorPicker(t) => t.id_offset.as_ref(),
+            Tag::ColorEdit(t) => t.id_offset.as_ref(),
+            Tag::PlotLines(t) | Tag::PlotHistogram(t) => t.id_offset.as_ref(),
+            Tag::Text(t) => t.id_offset.as_ref(),
+            Tag::Bullet(t) => t.id_offset.as_ref(),
+            Tag::Separator(t) => t.id_offset.as_ref(),
+            _ => None,
+        }
     }
 
-    let mut state = State::Init;
-    let mut tags = vec![];
+    /// Value of the `disabled_if` option, if the tag has one.
+    fn disabled_if(&self) -> Option<&Lit> {
+        match self {
+            Tag::Combobox(t) => t.disabled_if.as_ref(),
+            Tag::Checkbox(t) => t.disabled_if.as_ref(),
+            Tag::Input(t) | Tag::InputN(_, t) => t.disabled_if.as_ref(),
+            Tag::Slider(t) => t.disabled_if.as_ref(),
+            Tag::Drag(t) => t.disabled_if.as_ref(),
+            Tag::Nested(t) => t.disabled_if.as_ref(),
+            Tag::Group(t) => t.disabled_if.as_ref(),
+            Tag::TabBar(t) => t.disabled_if.as_ref(),
+            Tag::TabItem(t) => t.disabled_if.as_ref(),
+            Tag::List(t) => t.disabled_if.as_ref(),
+            Tag::Progress(t) => t.disabled_if.as_ref(),
+            Tag::Image(t) => t.disabled_if.as_ref(),
+            Tag::ImageButton(t) => t.disabled_if.as_ref(),
+            Tag::Button(t) => t.disabled_if.as_ref(),
+            Tag::Buttons(t) => t.disabled_if.as_ref(),
+            Tag::ColorButton(t) => t.disabled_if.as_ref(),
+            Tag::ColorPicker(t) => t.disabled_if.as_ref(),
+            Tag::ColorEdit(t) => t.disabled_if.as_ref(),
+            Tag::PlotLines(t) | Tag::PlotHistogram(t) => t.disabled_if.as_ref(),
+            Tag::Bullet(t) => t.disabled_if.as_ref(),
+            Tag::Separator(t) => t.disabled_if.as_ref(),
+            _ => None,
+        }
+    }
 
-    for nested in meta_list.nested.iter() {
-        match (state, nested) {
-            (_, NestedMeta::Lit(_)) => return Err(Error::invalid_format(meta_list.span())),
-            // Parse as a label(...)
-            (State::Init, NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, .. })))
-                if path_to_ident(&path).to_string() == "label"
-                    || path_to_ident(&path).to_string() == "display" =>
-            {
-                tags.push(Tag::Display(Display::from_meta_list(&meta_list)?));
-                // any errors will have been reported by the previous call to `parse_label`.
-                // At this point I can break out of the loop.
-                break;
-            }
+    /// Value of the `hidden_fn` option, if the tag has one.
+    fn hidden_fn(&self) -> Option<&Lit> {
+        match self {
+            Tag::Combobox(t) => t.hidden_fn.as_ref(),
+            Tag::Checkbox(t) => t.hidden_fn.as_ref(),
+            Tag::Input(t) | Tag::InputN(_, t) => t.hidden_fn.as_ref(),
+            Tag::Slider(t) => t.hidden_fn.as_ref(),
+            Tag::Drag(t) => t.hidden_fn.as_ref(),
+            Tag::Nested(t) => t.hidden_fn.as_ref(),
+            Tag::Group(t) => t.hidden_fn.as_ref(),
+            Tag::TabBar(t) => t.hidden_fn.as_ref(),
+            Tag::TabItem(t) => t.hidden_fn.as_ref(),
+            Tag::List(t) => t.hidden_fn.as_ref(),
+            Tag::Progress(t) => t.hidden_fn.as_ref(),
+            Tag::Image(t) => t.hidden_fn.as_ref(),
+            Tag::ImageButton(t) => t.hidden_fn.as_ref(),
+            Tag::Button(t) => t.hidden_fn.as_ref(),
+            Tag::Buttons(t) => t.hidden_fn.as_ref(),
+            Tag::ColorButton(t) => t.hidden_fn.as_ref(),
+            Tag::ColorPicker(t) => t.hidden_fn.as_ref(),
+            Tag::ColorEdit(t) => t.hidden_fn.as_ref(),
+            Tag::PlotLines(t) | Tag::PlotHistogram(t) => t.hidden_fn.as_ref(),
+            Tag::Bullet(t) => t.hidden_fn.as_ref(),
+            Tag::Separator(t) => t.hidden_fn.as_ref(),
+            _ => None,
+        }
+    }
 
-            // widgets that can take no parameters
-            (s, NestedMeta::Meta(Meta::Path(path))) if s == State::Init || s == State::Tags => {
-                let ident = path_to_ident(&path);
-                match ident.to_string().as_str() {
-                    "separator" => tags.push(Tag::Separator),
-                    "new_line" => tags.push(Tag::NewLine),
+    /// Value of the `frame_padding` option, if the tag has one.
+    fn frame_padding(&self) -> Option<&Lit> {
+        match self {
+            Tag::Combobox(t) => t.frame_padding.as_ref(),
+            Tag::Checkbox(t) => t.frame_padding.as_ref(),
+            Tag::Input(t) | Tag::InputN(_, t) => t.frame_padding.as_ref(),
+            Tag::Slider(t) => t.frame_padding.as_ref(),
+            Tag::Drag(t) => t.frame_padding.as_ref(),
+            Tag::Nested(t) => t.frame_padding.as_ref(),
+            Tag::Group(t) => t.frame_padding.as_ref(),
+            Tag::TabBar(t) => t.frame_padding.as_ref(),
+            Tag::TabItem(t) => t.frame_padding.as_ref(),
+            Tag::List(t) => t.frame_padding.as_ref(),
+            Tag::Progress(t) => t.frame_padding.as_ref(),
+            Tag::Image(t) => t.frame_padding.as_ref(),
+            Tag::ImageButton(t) => t.frame_padding.as_ref(),
+            Tag::Button(t) => t.frame_padding.as_ref(),
+            Tag::Buttons(t) => t.frame_padding.as_ref(),
+            Tag::ColorButton(t) => t.frame_padding.as_ref(),
+            Tag::ColorPicker(t) => t.frame_padding.as_ref(),
+            Tag::ColorEdit(t) => t.frame_padding.as_ref(),
+            Tag::PlotLines(t) | Tag::PlotHistogram(t) => t.frame_padding.as_ref(),
+            Tag::Bullet(t) => t.frame_padding.as_ref(),
+            Tag::Separator(t) => t.frame_padding.as_ref(),
+            // the rest of the tags don't parse the option
+            _ => None,
+        }
+    }
 
-                    "nested" => tags.push(Tag::Nested(Default::default())),
-                    "display" => tags.push(Tag::Display(Default::default())),
-                    "combobox" => tags.push(Tag::Combobox(Default::default())),
-                    "checkbox" => tags.push(Tag::Checkbox(Default::default())),
-                    "input" => tags.push(Tag::Input(Default::default())),
-                    "drag" => tags.push(Tag::Drag(Default::default())),
-                    "bullet" => tags.push(Tag::Bullet(Default::default())),
-                    "progress" => tags.push(Tag::Progress(Default::default())),
-                    //"text" => tags.push(Tag::Text(Default::default())),
-                    //"text_wrap" => tags.push(Tag::TextWrap(Default::default())),
-                    "tree" => tags.push(Tag::Tree(Default::default())),
-                    "vars" => tags.push(Tag::Vars(Default::default())),
+    /// Whether the tag has the `align` flag (`drag` and `slider`).
+    fn align(&self) -> bool {
+        match self {
+            Tag::Slider(t) => t.align,
+            Tag::Drag(t) => t.align,
+            _ => false,
+        }
+    }
 
-                    // errors
-                    "color" => return Err(Error::invalid_format(meta_list.span())),
-                    "text" => return Err(Error::invalid_format(meta_list.span())),
-                    "text_wrap" => return Err(Error::invalid_format(meta_list.span())),
-                    "slider" => {
-                        Tag::Slider(Slider::from_meta_list(&meta_list)?);
-                    }
-                    "button" => {
-                        Tag::Button(Button::from_meta_list(&meta_list)?);
-                    }
-                    "image" => {
-                        Tag::Image(Image::from_meta_list(&meta_list)?);
-                    }
+    /// Values of the `get` and `set` options of the value widgets.
+    fn accessors(&self) -> Option<(&Lit, &Lit, Option<&Lit>)> {
+        let (get, set, catch) = match self {
+            Tag::Slider(t) => (&t.get, &t.set, &t.catch),
+            Tag::Drag(t) => (&t.get, &t.set, &t.catch),
+            Tag::Input(t) | Tag::InputN(_, t) => (&t.get, &t.set, &t.catch),
+            _ => return None,
+        };
+        Some((get.as_ref()?, set.as_ref()?, catch.as_ref()))
+    }
 
-                    _ => return Err(Error::unexpected_mode(meta_list.span())),
-                }
-                state = State::Tags;
-            }
-            (s, NestedMeta::Meta(Meta::List(meta_list)))
-                if s == State::Init || s == State::Tags =>
-            {
-                let tag = match path_to_ident(&meta_list.path).to_string().as_str() {
-                    "separator" => Tag::Separator,
-                    "new_line" => Tag::NewLine,
+    /// Whether the tag has only one of the `get` and `set` options.
+    fn missing_accessor(&self) -> Option<&'static str> {
+        let (get, set) = match self {
+            Tag::Slider(t) => (&t.get, &t.set),
+            Tag::Drag(t) => (&t.get, &t.set),
+            Tag::Input(t) | Tag::InputN(_, t) => (&t.get, &t.set),
+            _ => return None,
+        };
+        match (get, set) {
+            (Some(_), None) => Some("set"),
+            (None, Some(_)) => Some("get"),
+            _ => None,
+        }
+    }
+}
 
-                    "display" => Tag::Display(Display::from_meta_list(&meta_list)?),
-                    "nested" => Tag::Nested(Nested::from_meta_list(meta_list)?),
-                    "combobox" => Tag::Combobox(Combobox::from_meta_list(meta_list)?),
-                    "checkbox" => Tag::Checkbox(Checkbox::from_meta_list(meta_list)?),
-                    "input" => Tag::Input(Input::from_meta_list(meta_list)?),
-                    "drag" => Tag::Drag(Drag::from_meta_list(meta_list)?),
-                    "slider" => Tag::Slider(Slider::from_meta_list(meta_list)?),
-                    "button" => Tag::Button(Button::from_meta_list(meta_list)?),
-                    "progress" => Tag::Progress(Progress::from_meta_list(meta_list)?),
-                    "image" => Tag::Image(Image::from_meta_list(meta_list)?),
-                    "image_button" => Tag::ImageButton(ImageButton::from_meta_list(meta_list)?),
-                    "text" => Tag::Text(Text::from_meta_list2(meta_list)?),
-                    "text_wrap" => Tag::TextWrap(Text::from_meta_list2(meta_list)?),
-                    "tree" => Tag::Tree(Tree::from_meta_list(meta_list)?),
-                    "vars" => Tag::Vars(Vars::from_meta_list(meta_list)?),
+/// Expression used by the generated code to access the annotated field.
+///
+/// With `#[imgui(newtype, ...)]` the widgets target the inner value of a
+/// single-field tuple struct (`ext.field.0`) instead of the field itself.
+///
+/// With `#[imgui(borrow, ...)]` and `#[imgui(lock, ...)]` the widgets target
+/// the value inside of a `RefCell` or a `Mutex` (`*ext.field.borrow_mut()` and
+/// `*ext.field.lock().unwrap()`). The guard is dropped at the end of each
+/// widget call.
+///
+/// With `#[imgui(deref, ...)]` the widgets target the `DerefMut` target of the
+/// field (`*ext.field`).
+///
+/// Fields of type `&mut T` are dereferenced, so the widgets target the borrowed
+/// value.
+pub fn field_access(ident: &Ident, ty: &Type, tags: &[Tag]) -> TokenStream {
+    let mut access = modifier_access(ident, ty, tags);
+    if tags.iter().any(|tag| matches!(tag, Tag::Newtype)) {
+        access = quote!(#access.0);
+    }
+    access
+}
 
-                    "color" => {
+/// Access to the field after the `borrow`, `lock`, `deref` and `cell`
+/// modifiers of `tags`.
+fn modifier_access(ident: &Ident, ty: &Type, tags: &[Tag]) -> TokenStream {
+    let mut access = match ty {
+        Type::Reference(reference) if reference.mutability.is_some() => quote!((*ext.#ident)),
+        _ => quote!(ext.#ident),
+    };
+    for tag in tags.iter() {
+        match tag {
+            Tag::Borrow => access = quote!((*#access.borrow_mut())),
+            Tag::Lock => access = quote!((*#access.lock().unwrap())),
+            Tag::Deref => access = quote!((*#access)),
+            // local copy of the value (see `cell_access`)
+            Tag::Cell => access = quote!(_cell),
+            // local copy of the value (see `atomic_access`)
+            Tag::Atomic(_) => access = quote!(_atomic),
+            _ => {}
+        }
+    }
+    access
+}
+
+/// Expression of the `Cell` targeted by the `cell` modifier, if any.
+///
+/// Widgets edit a copy of the value inside of the cell (the `_cell` local),
+/// which is written back once the widget is drawn.
+pub fn cell_access(ident: &Ident, ty: &Type, tags: &[Tag]) -> Option<TokenStream> {
+    let position = tags.iter().position(|tag| matches!(tag, Tag::Cell))?;
+    Some(modifier_access(ident, ty, &tags[..position]))
+}
+
+/// Wraps the `tokens` of a widget so they edit the `_cell` local of
+/// `cell_access`.
+pub fn emmit_cell(cell: &TokenStream, tokens: TokenStream) -> TokenStream {
+    quote!({
+        #[allow(unused_mut)]
+        let mut _cell = #cell.get();
+        #tokens;
+        #cell.set(_cell);
+    })
+}
+
+/// Atomic targeted by the `atomic` modifier.
+pub struct AtomicAccess {
+    atomic: TokenStream,
+    load: Ident,
+    store: Ident,
+}
+
+/// Orderings accepted by `atomic = "..."`.
+const ORDERINGS: &[&str] = &["Relaxed", "AcqRel", "SeqCst"];
+
+/// Expression of the atomic targeted by the `atomic` modifier, if any, along
+/// with the orderings of its loads and stores (`Relaxed` by default).
+///
+/// Widgets edit a copy of the loaded value (the `_atomic` local), which is
+/// stored back if it changed.
+pub fn atomic_access(ident: &Ident, ty: &Type, tags: &[Tag]) -> Option<AtomicAccess> {
+    let position = tags.iter().position(|tag| matches!(tag, Tag::Atomic(_)))?;
+    let (load, store) = match &tags[position] {
+        Tag::Atomic(Some(ordering)) => match ordering.value().as_str() {
+            "AcqRel" => ("Acquire", "Release"),
+            "SeqCst" => ("SeqCst", "SeqCst"),
+            _ => ("Relaxed", "Relaxed"),
+        },
+        _ => ("Relaxed", "Relaxed"),
+    };
+    Some(AtomicAccess {
+        atomic: modifier_access(ident, ty, &tags[..position]),
+        load: Ident::new(load, ident.span()),
+        store: Ident::new(store, ident.span()),
+    })
+}
+
+/// Wraps the `tokens` of a widget so they edit the `_atomic` local of
+/// `atomic_access`.
+pub fn emmit_atomic(atomic: &AtomicAccess, tokens: TokenStream) -> TokenStream {
+    let AtomicAccess {
+        atomic,
+        load,
+        store,
+    } = atomic;
+    quote!({
+        let _loaded = #atomic.load(std::sync::atomic::Ordering::#load);
+        #[allow(unused_mut)]
+        let mut _atomic = _loaded;
+        #tokens;
+        if _atomic != _loaded {
+            #atomic.store(_atomic, std::sync::atomic::Ordering::#store);
+        }
+    })
+}
+
+/// Position of a field in the UI, given by `#[imgui(order = N)]`.
+pub fn field_order(attr: &Attribute, tags: &[Tag]) -> Result<Option<i64>, Error> {
+    let mut order = None;
+    for tag in tags {
+        if let Tag::Order(lit) = tag {
+            if order.is_some() {
+                return Err(Error::already_defined(lit.span()));
+            }
+            order = match lit {
+                Lit::Int(n) => Some(
+                    n.base10_parse()
+                        .map_err(|_| Error::invalid_format(n.span()))?,
+                ),
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+        }
+    }
+    Ok(order)
+}
+
+/// Struct-level annotation: `#[imgui(patch)]`, `#[imgui(doc_tooltips)]`,
+/// `#[imgui(tree)]`
+#[derive(Default)]
+pub struct Options {
+    /// Generate `draw_gui_patch`.
+    pub patch: bool,
+    /// Show the doc comment of each field as a tooltip.
+    pub doc_tooltips: bool,
+    /// Draw the UI inside of a tree node labeled with the name of the type.
+    pub tree: bool,
+}
+
+impl Options {
+    /// Parse the `#[imgui(...)]` attributes placed on the struct itself.
+    pub fn from_attrs(attrs: &[Attribute]) -> Result<Self, Error> {
+        let mut options = Options::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("imgui")) {
+            let list = match attr.parse_meta() {
+                Ok(Meta::List(list)) => list,
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+            for nested in list.nested.iter() {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path)) => {
+                        let ident = path_to_ident(&path);
+                        match ident.to_string().as_str() {
+                            "patch" => {
+                                if options.patch {
+                                    return Err(Error::already_defined(ident.span()));
+                                }
+                                options.patch = true;
+                            }
+                            "doc_tooltips" => {
+                                if options.doc_tooltips {
+                                    return Err(Error::already_defined(ident.span()));
+                                }
+                                options.doc_tooltips = true;
+                            }
+                            "tree" => {
+                                if options.tree {
+                                    return Err(Error::already_defined(ident.span()));
+                                }
+                                options.tree = true;
+                            }
+                            name => {
+                                let expected = &["patch", "doc_tooltips", "tree"];
+                                return Err(Error::unknown_param(ident.span(), name, expected));
+                            }
+                        }
+                    }
+                    _ => return Err(Error::invalid_format(nested.span())),
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Whether `attrs` contain a `#[repr(i32)]` attribute.
+pub fn is_repr_i32(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .any(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => list.nested.iter().any(|nested| match nested {
+                NestedMeta::Meta(Meta::Path(path)) => path.is_ident("i32"),
+                _ => false,
+            }),
+            _ => false,
+        })
+}
+
+/// Text of the `///` doc comments of a field, one line per attribute.
+/// Returns `None` if the field is undocumented.
+pub fn doc_text(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(doc), ..
+            })) => Some(doc.value()),
+            _ => None,
+        })
+        .map(|line| match line.chars().next() {
+            // `/// foo` is stored as `" foo"`
+            Some(' ') => line[1..].trim_end().to_string(),
+            _ => line.trim_end().to_string(),
+        })
+        .collect();
+
+    let text = lines.join("\n");
+    let text = text.trim();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text.to_string())
+    }
+}
+
+/// Emmits a tooltip with `doc` for the last item drawn.
+pub fn emmit_doc_tooltip(doc: &str) -> TokenStream {
+    quote! {
+        if ui.is_item_hovered() {
+            ui.tooltip_text(#doc);
+        }
+    }
+}
+
+/// Parses the tags of an `#[imgui(...)]` attribute.
+pub fn parse_attr(attr: &Attribute) -> Result<Vec<Tag>, Error> {
+    desugar(attr)?
+        .parse_meta()
+        .map_err(|_| Error::parsing_error(attr.span()))
+        .and_then(parse_meta)
+}
+
+/// Rewrites the shorthands that can't be parsed as a `Meta`:
+///
+/// - The range of sliders (`slider(0.0..=1.0, ...)`) into the `min` & `max`
+///   params (`slider(min = 0.0, max = 1.0, ...)`). Exclusive ranges (`0..10`)
+///   need integer bounds, and `max` becomes the last value of the range
+///   (`9`). Negative bounds are written as strings (`min = "-1.0"`), the same
+///   way they are written in the long form.
+/// - Labels taken from constants (`label = LABEL`) into `label(LABEL)`.
+fn desugar(attr: &Attribute) -> Result<Attribute, Error> {
+    let mut attr = attr.clone();
+    attr.tokens = desugar_stream(attr.tokens)?;
+    Ok(attr)
+}
+
+fn desugar_stream(tokens: TokenStream) -> Result<TokenStream, Error> {
+    let mut out = Vec::new();
+    let mut slider = false;
+    for tree in tokens {
+        let tree = match tree {
+            TokenTree::Group(group) => {
+                let mut stream = desugar_stream(group.stream())?;
+                if group.delimiter() == Delimiter::Parenthesis {
+                    stream = desugar_label(stream);
+                    if slider {
+                        stream = desugar_range(stream)?;
+                    }
+                }
+                let mut new = proc_macro2::Group::new(group.delimiter(), stream);
+                new.set_span(group.span());
+                TokenTree::Group(new)
+            }
+            tree => tree,
+        };
+        slider = match &tree {
+            TokenTree::Ident(ident) => ident == "slider",
+            _ => false,
+        };
+        out.push(tree);
+    }
+    Ok(out.into_iter().collect())
+}
+
+/// Rewrites a range at the beginning of the params of a slider.
+fn desugar_range(stream: TokenStream) -> Result<TokenStream, Error> {
+    let is_punct = |tree: Option<&TokenTree>, c: char| match tree {
+        Some(TokenTree::Punct(punct)) => punct.as_char() == c,
+        _ => false,
+    };
+    let tokens: Vec<TokenTree> = stream.into_iter().collect();
+    let end = (0..tokens.len())
+        .find(|&i| is_punct(tokens.get(i), ','))
+        .unwrap_or(tokens.len());
+    let (head, rest) = tokens.split_at(end);
+
+    // `min..max` or `min..=max`
+    let dots = match (0..head.len()).find(|&i| is_punct(head.get(i), '.')) {
+        Some(dots) if is_punct(head.get(dots + 1), '.') => dots,
+        _ => return Ok(tokens.into_iter().collect()),
+    };
+    let inclusive = is_punct(head.get(dots + 2), '=');
+    let span = head[dots].span();
+    let min = range_bound(&head[..dots], span)?;
+    let mut max = range_bound(&head[dots + if inclusive { 3 } else { 2 }..], span)?;
+    if !inclusive {
+        let (value, span) = match &max {
+            Lit::Int(int) => (int.base10_parse::<i64>().ok(), int.span()),
+            Lit::Str(string) => (string.value().parse::<i64>().ok(), string.span()),
+            lit => (None, lit.span()),
+        };
+        max = match value.ok_or_else(|| Error::exclusive_range(span))? - 1 {
+            last if last < 0 => Lit::Str(syn::LitStr::new(&last.to_string(), span)),
+            last => Lit::Int(syn::LitInt::new(&last.to_string(), span)),
+        };
+    }
+
+    let comma = || TokenTree::Punct(Punct::new(',', Spacing::Alone));
+    let mut out = quote!(min = #min);
+    out.extend(Some(comma()));
+    out.extend(quote!(max = #max));
+    out.extend(rest.iter().cloned());
+    Ok(out)
+}
+
+/// Rewrites the `label = CONST` params of a list into `label(CONST)`. Labels
+/// given as a literal (`label = "..."`) are left as they are.
+fn desugar_label(stream: TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = stream.into_iter().collect();
+    let mut out = TokenStream::new();
+    for (i, param) in tokens
+        .split(|tree| match tree {
+            TokenTree::Punct(punct) => punct.as_char() == ',',
+            _ => false,
+        })
+        .enumerate()
+    {
+        if i > 0 {
+            out.extend(Some(TokenTree::Punct(Punct::new(',', Spacing::Alone))));
+        }
+        match param {
+            [TokenTree::Ident(name), TokenTree::Punct(eq), value @ ..]
+                if name == "label" && eq.as_char() == '=' =>
+            {
+                match value {
+                    [] | [TokenTree::Literal(_)] => out.extend(param.iter().cloned()),
+                    value => {
+                        let value: TokenStream = value.iter().cloned().collect();
+                        out.extend(quote!(#name(#value)));
+                    }
+                }
+            }
+            param => out.extend(param.iter().cloned()),
+        }
+    }
+    out
+}
+
+/// Bound of a range: a numeric literal, optionally negated.
+fn range_bound(tokens: &[TokenTree], span: proc_macro2::Span) -> Result<Lit, Error> {
+    let (negative, lit) = match tokens {
+        [TokenTree::Literal(lit)] => (false, lit),
+        [TokenTree::Punct(minus), TokenTree::Literal(lit)] if minus.as_char() == '-' => (true, lit),
+        _ => return Err(Error::parsing_error(span)),
+    };
+    match Lit::new(lit.clone()) {
+        lit @ Lit::Int(_) | lit @ Lit::Float(_) if !negative => Ok(lit),
+        Lit::Int(_) | Lit::Float(_) => {
+            Ok(Lit::Str(syn::LitStr::new(&format!("-{}", lit), lit.span())))
+        }
+        _ => Err(Error::parsing_error(lit.span())),
+    }
+}
+
+/// meta is the whole (parsed) tag: `#[imgui]` or `#[imgui(...)]`
+pub fn parse_meta(meta: Meta) -> Result<Vec<Tag>, Error> {
+    match meta {
+        // #[imgui = ...] Nope
+        Meta::NameValue(named) => Err(Error::invalid_format(named.span())),
+        // #[imgui], treated as an empty label
+        Meta::Path(_) => Ok(vec![Tag::Display(Display::default())]),
+        // #[imgui(meta_list)] (general)
+        Meta::List(meta_list) => parse_meta_list(&meta_list),
+    }
+}
+
+/// Parse the inside of `#[imgui(...)]`
+///                              ^^^
+/// Possible cases:
+///   - `#[imgui(foo(...), bar(...))]`
+///   - `#[imgui(foo(...),)]`
+///   - `#[imgui(foo(...))]`
+///   - `#[imgui(label = "...", display = "...", foo, bar)]`
+fn parse_meta_list(meta_list: &MetaList) -> Result<Vec<Tag>, Error> {
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    enum State {
+        Init,
+        //Label,
+        Tags,
+    }
+
+    let mut state = State::Init;
+    let mut tags = vec![];
+
+    for nested in meta_list.nested.iter() {
+        match (state, nested) {
+            (_, NestedMeta::Lit(_)) => return Err(Error::invalid_format(meta_list.span())),
+            // Parse as a label(...)
+            (State::Init, NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, .. })))
+                if path_to_ident(&path).to_string() == "label"
+                    || path_to_ident(&path).to_string() == "display" =>
+            {
+                tags.push(Tag::Display(Display::from_meta_list(&meta_list)?));
+                // any errors will have been reported by the previous call to `parse_label`.
+                // At this point I can break out of the loop.
+                break;
+            }
+
+            // widgets that can take no parameters
+            (s, NestedMeta::Meta(Meta::Path(path))) if s == State::Init || s == State::Tags => {
+                let ident = path_to_ident(&path);
+                match ident.to_string().as_str() {
+                    "separator" => tags.push(Tag::Separator(Default::default())),
+                    "new_line" => tags.push(Tag::NewLine),
+
+                    "nested" => tags.push(Tag::Nested(Default::default())),
+                    "group" => tags.push(Tag::Group(Default::default())),
+                    "tab_bar" => tags.push(Tag::TabBar(Default::default())),
+                    "tab_item" => tags.push(Tag::TabItem(Default::default())),
+                    "display" => tags.push(Tag::Display(Default::default())),
+                    "combobox" => tags.push(Tag::Combobox(Default::default())),
+                    "checkbox" => tags.push(Tag::Checkbox(Default::default())),
+                    "input" => tags.push(Tag::Input(Default::default())),
+                    "input2" => tags.push(Tag::InputN(2, Default::default())),
+                    "input3" => tags.push(Tag::InputN(3, Default::default())),
+                    "input4" => tags.push(Tag::InputN(4, Default::default())),
+                    "drag" => tags.push(Tag::Drag(Default::default())),
+                    "slider" => tags.push(Tag::Slider(Default::default())),
+                    "bullet" => tags.push(Tag::Bullet(Default::default())),
+                    "list" => tags.push(Tag::List(Default::default())),
+                    "progress" => tags.push(Tag::Progress(Default::default())),
+                    //"text" => tags.push(Tag::Text(Default::default())),
+                    //"text_wrap" => tags.push(Tag::TextWrap(Default::default())),
+                    "tree" => tags.push(Tag::Tree(Default::default())),
+                    "vars" => tags.push(Tag::Vars(Default::default())),
+                    "newtype" => tags.push(Tag::Newtype),
+                    "borrow" => tags.push(Tag::Borrow),
+                    "lock" => tags.push(Tag::Lock),
+                    "deref" => tags.push(Tag::Deref),
+                    "cell" => tags.push(Tag::Cell),
+                    "atomic" => tags.push(Tag::Atomic(None)),
+                    "skip" => tags.push(Tag::None),
+
+                    // errors
+                    "color" => return Err(Error::invalid_format(meta_list.span())),
+                    "plot" => return Err(Error::invalid_format(meta_list.span())),
+                    "text" => return Err(Error::invalid_format(meta_list.span())),
+                    "text_wrap" => return Err(Error::invalid_format(meta_list.span())),
+                    "button" => {
+                        Tag::Button(Button::from_meta_list(&meta_list)?);
+                    }
+                    "buttons" => return Err(Error::missing_param(meta_list.span(), "cols")),
+                    "image" => {
+                        Tag::Image(Image::from_meta_list(&meta_list)?);
+                    }
+
+                    _ => return Err(Error::unexpected_mode(meta_list.span())),
+                }
+                state = State::Tags;
+            }
+            (s, NestedMeta::Meta(Meta::List(meta_list)))
+                if s == State::Init || s == State::Tags =>
+            {
+                let tag = match path_to_ident(&meta_list.path).to_string().as_str() {
+                    "separator" => Tag::Separator(Separator::from_meta_list(meta_list)?),
+                    "new_line" => Tag::NewLine,
+
+                    "display" => Tag::Display(Display::from_meta_list(&meta_list)?),
+                    "nested" => Tag::Nested(Nested::from_meta_list(meta_list)?),
+                    "group" => Tag::Group(Group::from_meta_list(meta_list)?),
+                    "tab_bar" => Tag::TabBar(TabBar::from_meta_list(meta_list)?),
+                    "tab_item" => Tag::TabItem(TabItem::from_meta_list(meta_list)?),
+                    "combobox" => Tag::Combobox(Combobox::from_meta_list(meta_list)?),
+                    "checkbox" => Tag::Checkbox(Checkbox::from_meta_list(meta_list)?),
+                    "input" => Tag::Input(Input::from_meta_list(meta_list)?),
+                    "input2" => Tag::InputN(2, Input::from_meta_list(meta_list)?),
+                    "input3" => Tag::InputN(3, Input::from_meta_list(meta_list)?),
+                    "input4" => Tag::InputN(4, Input::from_meta_list(meta_list)?),
+                    "drag" => Tag::Drag(Drag::from_meta_list(meta_list)?),
+                    "slider" => Tag::Slider(Slider::from_meta_list(meta_list)?),
+                    "button" => Tag::Button(Button::from_meta_list(meta_list)?),
+                    "buttons" => Tag::Buttons(Buttons::from_meta_list(meta_list)?),
+                    "list" => Tag::List(List::from_meta_list(meta_list)?),
+                    "progress" => Tag::Progress(Progress::from_meta_list(meta_list)?),
+                    "image" => Tag::Image(Image::from_meta_list(meta_list)?),
+                    "image_button" => Tag::ImageButton(ImageButton::from_meta_list(meta_list)?),
+                    "text" => Tag::Text(Text::from_meta_list(meta_list)?),
+                    "text_wrap" => Tag::Text(Text {
+                        wrapped: true,
+                        ..Text::from_meta_list(meta_list)?
+                    }),
+                    "tree" => Tag::Tree(Tree::from_meta_list(meta_list)?),
+                    "vars" => Tag::Vars(Vars::from_meta_list(meta_list)?),
+
+                    "color" => {
                         for nested in meta_list.nested.iter() {
                             match nested {
                                 // One of:
@@ -716,6 +1691,54 @@ fn parse_meta_list(meta_list: &MetaList) -> Result<Vec<Tag>, Error> {
                         Tag::None
                     }
 
+                    "plot" => {
+                        for nested in meta_list.nested.iter() {
+                            match nested {
+                                // One of:
+                                //   - `plot(lines)`
+                                //   - `plot(histogram)`
+                                NestedMeta::Meta(Meta::Path(path)) => {
+                                    let ident = path_to_ident(&path);
+                                    match ident.to_string().as_str() {
+                                        "lines" => tags.push(Tag::PlotLines(Default::default())),
+                                        "histogram" => {
+                                            tags.push(Tag::PlotHistogram(Default::default()))
+                                        }
+
+                                        // Compiler error
+                                        _ => return Err(Error::unexpected_mode(ident.span())),
+                                    }
+                                }
+
+                                // One of:
+                                //   - `plot(lines(...))`
+                                //   - `plot(histogram(...))`
+                                NestedMeta::Meta(Meta::List(plot_meta_list)) => {
+                                    let ident = path_to_ident(&plot_meta_list.path);
+                                    match ident.to_string().as_str() {
+                                        "lines" => tags.push(Tag::PlotLines(Plot::from_meta_list(
+                                            plot_meta_list,
+                                        )?)),
+                                        "histogram" => tags.push(Tag::PlotHistogram(
+                                            Plot::from_meta_list(plot_meta_list)?,
+                                        )),
+
+                                        // Compiler error
+                                        _ => {
+                                            return Err(Error::unexpected_mode(
+                                                plot_meta_list.path.span(),
+                                            ))
+                                        }
+                                    }
+                                }
+
+                                _ => return Err(Error::invalid_format(meta_list.span())),
+                            }
+                        }
+
+                        Tag::None
+                    }
+
                     // TODO refactor
                     // FIXME errors handling not clear enough
                     // bullet(toxt = "..") raises the wrong error
@@ -743,6 +1766,30 @@ fn parse_meta_list(meta_list: &MetaList) -> Result<Vec<Tag>, Error> {
                 tags.push(tag);
                 state = State::Tags;
             }
+            // `order = N`
+            (_, NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })))
+                if path_to_ident(&path).to_string() == "order" =>
+            {
+                tags.push(Tag::Order(lit.clone()));
+            }
+            // `atomic = "Ordering"`
+            (_, NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. })))
+                if path_to_ident(&path).to_string() == "atomic" =>
+            {
+                match lit {
+                    Lit::Str(ordering) if ORDERINGS.contains(&ordering.value().as_str()) => {
+                        tags.push(Tag::Atomic(Some(ordering.clone())))
+                    }
+                    Lit::Str(ordering) => {
+                        return Err(Error::unknown_param(
+                            ordering.span(),
+                            &ordering.value(),
+                            ORDERINGS,
+                        ))
+                    }
+                    _ => return Err(Error::invalid_format(lit.span())),
+                }
+            }
             _ => panic!(),
         }
     }
@@ -758,16 +1805,57 @@ fn parse_meta_list(meta_list: &MetaList) -> Result<Vec<Tag>, Error> {
 /// This function needs to be called twice (once per Tag)
 pub fn emmit_tag_tokens(
     ident: &Ident,
+    access: &TokenStream,
     _ty: &Type,
     attr: &Attribute,
     tag: &Tag,
     fields: &mut TokenStream,
     methods: &mut TokenStream,
     input_fields: &mut HashSet<String>,
+    changed: &mut TokenStream,
 ) -> Result<TokenStream, Error> {
+    if let Some(name) = tag.missing_accessor() {
+        return Err(Error::missing_param(attr.span(), name));
+    }
+    // `get` and `set` replace the field with a local copy of the value they
+    // read and write, so the type of the field doesn't matter.
+    let accessors = tag.accessors();
+    let access = &match accessors {
+        Some(_) => quote!(_value),
+        None => access.clone(),
+    };
+    if let Tag::InputN(n, Input { map: None, .. }) = tag {
+        if accessors.is_none() {
+            check_arity(_ty, *n)?;
+        }
+    }
+    if is_phantom(_ty) && !is_layout(tag) && accessors.is_none() {
+        return Err(Error::phantom(attr.span()));
+    }
+    // type of the edited value, when it is known to be the type of the field
+    let field_ty = match accessors {
+        Some(_) => None,
+        None => Some(_ty),
+    };
+
     let tokens = match tag {
-        Tag::None => quote!(),
-        Tag::Separator => quote!({ ui.separator() }),
+        Tag::None
+        | Tag::Newtype
+        | Tag::Borrow
+        | Tag::Lock
+        | Tag::Deref
+        | Tag::Cell
+        | Tag::Atomic(_)
+        | Tag::Order(_) => {
+            quote!()
+        }
+        Tag::Separator(Separator { vertical, .. }) => {
+            if *vertical {
+                quote!({ imgui_ext::vertical_separator(ui) })
+            } else {
+                quote!({ ui.separator() })
+            }
+        }
         Tag::NewLine => quote!({ ui.new_line() }),
         Tag::Vars(Vars {
             color,
@@ -779,12 +1867,14 @@ pub fn emmit_tag_tokens(
                 for tag in tags.iter() {
                     tokens.extend(emmit_tag_tokens(
                         ident,
+                        access,
                         _ty,
                         attr,
                         tag,
                         fields,
                         methods,
                         input_fields,
+                        changed,
                     )?);
                 }
             }
@@ -836,12 +1926,14 @@ pub fn emmit_tag_tokens(
                 for tag in tags.iter() {
                     node_tokens.extend(emmit_tag_tokens(
                         ident,
+                        access,
                         _ty,
                         attr,
                         tag,
                         fields,
                         methods,
                         input_fields,
+                        changed,
                     )?);
                 }
             }
@@ -877,21 +1969,27 @@ pub fn emmit_tag_tokens(
         Tag::ImageButton(ImageButton {
             size,
             background,
-            frame_padding,
+            bg,
+            image_padding,
             uv0,
             uv1,
             tint,
+            catch,
+            ..
         }) => {
             let size = match size {
-                Lit::Str(size) => Ident::new(&size.value(), size.span()),
+                Lit::Str(size) => path_or_expr(size)?,
                 _ => return Err(Error::invalid_format(attr.span())),
             };
+            let background = match (background, bg) {
+                (Some(_), Some(bg)) => return Err(Error::already_defined(bg.span())),
+                (background, bg) => background.as_ref().or(bg.as_ref()),
+            };
 
             let mut params = quote! {
                 use imgui_ext::image_button::ImageButtonParams as Params;
-                use imgui::im_str;
                 let mut params = Params {
-                    size: #size().into(),
+                    size: imgui_ext::scaled(#size),
                     background: None,
                     frame_padding: None,
                     tint: None,
@@ -899,13 +1997,13 @@ pub fn emmit_tag_tokens(
                     uv1: None,
                 };
             };
-            match frame_padding {
+            match image_padding {
                 Some(Lit::Str(value_str)) => {
                     let value = value_str
                         .value()
                         .parse()
                         .map(Literal::i32_unsuffixed)
-                        .expect("frame_padding expected to be numeric (i32).");
+                        .map_err(|_| Error::parsing_error(value_str.span()))?;
                     params.extend(quote!(params.frame_padding = Some(#value);));
                 }
                 Some(Lit::Int(value)) => {
@@ -916,43 +2014,51 @@ pub fn emmit_tag_tokens(
             }
             match uv0 {
                 Some(Lit::Str(uv0)) => {
-                    let fn_ident: syn::Path =
-                        syn::parse_str(&uv0.value()).expect("Error parsing function path.");
-                    params.extend(quote! {{ params.uv0 = Some( #fn_ident().into() ); }});
+                    let value = path_or_expr(uv0)?;
+                    params.extend(quote! {{ params.uv0 = Some( #value ); }});
                 }
                 None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
             }
             match uv1 {
                 Some(Lit::Str(uv1)) => {
-                    let fn_ident: syn::Path =
-                        syn::parse_str(&uv1.value()).expect("Error parsing function path.");
-                    params.extend(quote! {{ params.uv1 = Some( #fn_ident().into() ); }});
+                    let value = path_or_expr(uv1)?;
+                    params.extend(quote! {{ params.uv1 = Some( #value ); }});
                 }
                 None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
             }
             match tint {
-                Some(Lit::Str(size)) => {
-                    let fn_ident: syn::Path =
-                        syn::parse_str(&size.value()).expect("Error parsing function path.");
-                    params.extend(quote! {{ params.tint = Some( #fn_ident().into() ); }});
+                Some(Lit::Str(tint)) => {
+                    let value = path_or_expr(tint)?;
+                    params.extend(quote! {{ params.tint = Some( #value ); }});
                 }
                 None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
             }
             match background {
-                Some(Lit::Str(size)) => {
-                    let fn_ident: syn::Path =
-                        syn::parse_str(&size.value()).expect("Error parsing function path.");
-                    params.extend(quote! {{ params.background = Some( #fn_ident().into() ); }});
+                Some(Lit::Str(background)) => {
+                    let value = path_or_expr(background)?;
+                    params.extend(quote! {{ params.background = Some( #value ); }});
                 }
                 None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
             }
+
+            let catch_ident = click_ident(
+                attr,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+                changed,
+            )?;
+
             quote! {{
-                use imgui_ext::image::Image;
-                Image::build(ui, ext.#ident, { #params ; params });
+                use imgui_ext::image_button::ImageButton;
+                let _ev = ImageButton::build(ui, #access, { #params ; params });
+                events.#catch_ident |= _ev;
             }}
         }
         Tag::Image(Image {
@@ -961,6 +2067,7 @@ pub fn emmit_tag_tokens(
             tint,
             uv0,
             uv1,
+            ..
         }) => {
             let size = match size {
                 Lit::Str(size) => Ident::new(&size.value(), size.span()),
@@ -969,9 +2076,8 @@ pub fn emmit_tag_tokens(
 
             let mut params = quote! {
                 use imgui_ext::image::ImageParams as Params;
-                use imgui::im_str;
                 let mut params = Params {
-                    size: #size().into(),
+                    size: imgui_ext::scaled(#size().into()),
                     border: None,
                     tint: None,
                     uv0: None,
@@ -1016,13 +2122,30 @@ pub fn emmit_tag_tokens(
             }
             quote! {{
                 use imgui_ext::image::Image;
-                Image::build(ui, ext.#ident, { #params ; params });
+                Image::build(ui, #access, { #params ; params });
+            }}
+        }
+        Tag::List(List { label, catch, .. }) => {
+            let label = emmit_label(attr, ident, label.as_ref(), None, false)?;
+            let catch_ident = catch_ident(
+                attr,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+                changed,
+            )?;
+
+            quote! {{
+                use imgui_ext::list::ListParams as Params;
+                let _ev = imgui_ext::list::build(ui, &mut #access, Params { label: #label });
+                events.#catch_ident |= _ev;
             }}
         }
-        Tag::Progress(Progress { overlay, size }) => {
+        Tag::Progress(Progress { overlay, size, .. }) => {
             let mut params = quote! {
                 use imgui_ext::progress::ProgressParams as Params;
-                use imgui::im_str;
                 let mut params = Params {
                     overlay: None,
                     size: None,
@@ -1030,14 +2153,15 @@ pub fn emmit_tag_tokens(
             };
 
             let ident_str = ident.to_string();
-            match (overlay, ident_str.as_bytes()[0]) {
+            match (overlay, ident_str.starts_with('_')) {
                 (Some(Lit::Str(stri)), _) => {
-                    params.extend(quote! {{ params.overlay = Some(im_str!(#stri)); }})
+                    validate_text(stri)?;
+                    params.extend(quote! {{ params.overlay = Some(imgui::im_str!(#stri)); }})
                 }
-                (None, b'_') => {}
-                (None, _) => {
+                (None, true) => {}
+                (None, false) => {
                     let overlay = Literal::string(&ident_str);
-                    params.extend(quote! {{ params.overlay = Some(im_str!(#overlay)); }});
+                    params.extend(quote! {{ params.overlay = Some(imgui::im_str!(#overlay)); }});
                 }
                 _ => return Err(Error::invalid_format(attr.span())),
             }
@@ -1046,7 +2170,9 @@ pub fn emmit_tag_tokens(
                 Some(Lit::Str(size)) => {
                     let fn_ident: syn::Path =
                         syn::parse_str(&size.value()).expect("Error parsing function path.");
-                    params.extend(quote! {{ params.size = Some( #fn_ident().into() ); }});
+                    params.extend(
+                        quote! {{ params.size = Some(imgui_ext::scaled(#fn_ident().into())); }},
+                    );
                 }
                 None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
@@ -1054,28 +2180,82 @@ pub fn emmit_tag_tokens(
 
             quote! {{
                 use imgui_ext::progress::Progress;
-                Progress::build(ui, &ext.#ident, { #params; params });
+                Progress::build(ui, &#access, { #params; params });
             }}
         }
-        Tag::Text(Text { lit }) => {
-            match lit {
-                //Some(Lit::Str(lit)) => quote! { ui.text_wrapped(imgui::im_str!(#lit)); },
-                Lit::Str(lit) => quote! { ui.text(#lit); },
+        Tag::PlotLines(plot) => emmit_plot_tokens(
+            quote!(imgui_ext::plot::PlotLines),
+            ident,
+            access,
+            attr,
+            plot,
+        )?,
+        Tag::PlotHistogram(plot) => emmit_plot_tokens(
+            quote!(imgui_ext::plot::PlotHistogram),
+            ident,
+            access,
+            attr,
+            plot,
+        )?,
+        Tag::Text(Text {
+            lit,
+            wrapped,
+            disabled,
+            bullet,
+            color,
+            ..
+        }) => {
+            // `text` takes a `&str`, `text_wrapped` an `&ImStr`
+            let text = match (lit, wrapped) {
+                (Some(Lit::Str(lit)), false) => quote!(#lit),
+                (Some(Lit::Str(lit)), true) => quote!(imgui::im_str!(#lit)),
+                (None, false) => quote!(format!("{}", #access)),
+                (None, true) => quote!(&imgui::im_str!("{}", #access)),
 
                 // Invalid format. Raise compiler error.
                 _ => return Err(Error::invalid_format(attr.span())),
-            }
-        }
-        Tag::TextWrap(Text { lit }) => {
-            match lit {
-                Lit::Str(lit) => quote! { ui.text_wrapped(imgui::im_str!(#lit)); },
+            };
 
-                // Invalid format. Raise compiler error.
+            // the decorators are applied one on top of the other
+            let mut tokens = TokenStream::new();
+            let mut pop = TokenStream::new();
+            match color {
+                Some(Lit::Str(color)) => {
+                    let color = color_expr(color)?;
+                    tokens.extend(quote! {
+                        let _color = ui.push_style_color(imgui::StyleColor::Text, #color);
+                    });
+                    pop.extend(quote!(_color.pop(ui);));
+                }
+                None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
             }
+            if *disabled {
+                tokens.extend(quote! {
+                    let _disabled = ui.push_style_color(
+                        imgui::StyleColor::Text,
+                        ui.style_color(imgui::StyleColor::TextDisabled),
+                    );
+                });
+                pop = quote!(_disabled.pop(ui); #pop);
+            }
+            if *bullet {
+                tokens.extend(quote! {
+                    ui.bullet();
+                    ui.same_line(0.0);
+                });
+            }
+            if *wrapped {
+                tokens.extend(quote!(ui.text_wrapped(#text);));
+            } else {
+                tokens.extend(quote!(ui.text(#text);));
+            }
+            quote!({ #tokens #pop })
         }
         Tag::ColorEdit(ColorEdit {
             label,
+            label_path,
+            label_inline,
             flags,
             preview,
             display_mode,
@@ -1083,19 +2263,23 @@ pub fn emmit_tag_tokens(
             format,
             catch,
             map,
+            alpha_bar,
+            alpha_preview,
+            alpha_preview_half,
+            swatch_only,
+            ..
         }) => {
-            let label = match label {
-                Some(Lit::Str(stri)) => stri.value(),
-                None => ident.to_string(),
-                // TODO proper error span
-                _ => return Err(Error::invalid_format(attr.span())),
-            };
-            let label = Literal::string(&label);
+            let label = emmit_label(
+                attr,
+                ident,
+                label.as_ref(),
+                label_path.as_ref(),
+                *label_inline,
+            )?;
             let mut params = quote! {
                 use imgui_ext::color::ColorEditParams as Params;
-                use imgui::im_str;
                 let mut params = Params {
-                    label: im_str!( #label ),
+                    label: #label,
                     flags: None,
                     preview: None,
                     input_mode: None,
@@ -1146,7 +2330,10 @@ pub fn emmit_tag_tokens(
                 _ => return Err(Error::invalid_format(attr.span())),
             }
 
+            // `"u32"` edits a packed color, the rest are `ColorFormat` variants.
+            let mut packed = false;
             match format {
+                Some(Lit::Str(c)) if c.value() == "u32" => packed = true,
                 Some(Lit::Str(c)) => {
                     let var = Ident::new(&c.value(), ident.span());
                     params.extend(quote! {{
@@ -1157,32 +2344,57 @@ pub fn emmit_tag_tokens(
                 _ => return Err(Error::invalid_format(attr.span())),
             }
 
-            let catch_ident =
-                catch_ident(attr, ident, catch.as_ref(), input_fields, fields, methods)?;
+            params.extend(emmit_alpha_flags(
+                _ty,
+                attr,
+                *alpha_bar,
+                *alpha_preview,
+                *alpha_preview_half,
+            )?);
+
+            // just the color swatch, which opens the picker when clicked.
+            if *swatch_only {
+                params.extend(quote! {{
+                    use imgui::ColorEditFlags as Flags;
+                    let flags = params.flags.unwrap_or(Flags::empty());
+                    params.flags = Some(flags | Flags::NO_INPUTS | Flags::NO_LABEL);
+                }});
+            }
+
+            let catch_ident = catch_ident(
+                attr,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+                changed,
+            )?;
 
-            match map {
-                None => {
-                    quote! {{
-                        use imgui_ext::color::ColorEdit;
-                        let _ev = ColorEdit::build(ui, &mut ext.#ident, { #params ; params });
-                        events.#catch_ident |= _ev;
-                    }}
-                }
+            let mut color = match map {
+                None => quote!(&mut #access),
                 Some(Lit::Str(map)) => {
                     // TODO error handling
                     let map_path: syn::Path =
                         syn::parse_str(&map.value()).expect("Error parsing parth to function.");
-                    quote! {{
-                        use imgui_ext::color::ColorEdit;
-                        let _ev = ColorEdit::build(ui, #map_path(&mut ext.#ident), { #params ; params });
-                        events.#catch_ident |= _ev;
-                    }}
+                    quote!(#map_path(&mut #access))
                 }
                 _ => return Err(Error::invalid_format(attr.span())),
+            };
+            if packed {
+                color = quote!(imgui_ext::color::Packed(#color));
             }
+
+            quote! {{
+                use imgui_ext::color::ColorEdit;
+                let _ev = ColorEdit::build(ui, #color, { #params ; params });
+                events.#catch_ident |= _ev;
+            }}
         }
         Tag::ColorPicker(ColorPicker {
             label,
+            label_path,
+            label_inline,
             flags,
             preview,
             mode,
@@ -1190,19 +2402,22 @@ pub fn emmit_tag_tokens(
             format,
             catch,
             map,
+            alpha_bar,
+            alpha_preview,
+            alpha_preview_half,
+            ..
         }) => {
-            let label = match label {
-                Some(Lit::Str(stri)) => stri.value(),
-                None => ident.to_string(),
-                // TODO proper error span
-                _ => return Err(Error::invalid_format(attr.span())),
-            };
-            let label = Literal::string(&label);
+            let label = emmit_label(
+                attr,
+                ident,
+                label.as_ref(),
+                label_path.as_ref(),
+                *label_inline,
+            )?;
             let mut params = quote! {
                 use imgui_ext::color::ColorPickerParams as Params;
-                use imgui::im_str;
                 let mut params = Params {
-                    label: im_str!( #label ),
+                    label: #label,
                     flags: None,
                     preview: None,
                     input_mode: None,
@@ -1265,14 +2480,29 @@ pub fn emmit_tag_tokens(
                 _ => return Err(Error::invalid_format(attr.span())),
             }
 
-            let catch_ident =
-                catch_ident(attr, ident, catch.as_ref(), input_fields, fields, methods)?;
+            params.extend(emmit_alpha_flags(
+                _ty,
+                attr,
+                *alpha_bar,
+                *alpha_preview,
+                *alpha_preview_half,
+            )?);
+
+            let catch_ident = catch_ident(
+                attr,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+                changed,
+            )?;
 
             match map {
                 None => {
                     quote! {{
                         use imgui_ext::color::ColorPicker;
-                        let _ev = ColorPicker::build(ui, &mut ext.#ident, { #params ; params });
+                        let _ev = ColorPicker::build(ui, &mut #access, { #params ; params });
                         events.#catch_ident |= _ev;
                     }}
                 }
@@ -1282,7 +2512,7 @@ pub fn emmit_tag_tokens(
                         syn::parse_str(&map.value()).expect("Error parsing parth to function.");
                     quote! {{
                         use imgui_ext::color::ColorPicker;
-                        let _ev = ColorPicker::build(ui, #map_path(&mut ext.#ident), { #params ; params });
+                        let _ev = ColorPicker::build(ui, #map_path(&mut #access), { #params ; params });
                         events.#catch_ident |= _ev;
                     }}
                 }
@@ -1291,25 +2521,29 @@ pub fn emmit_tag_tokens(
         }
         Tag::ColorButton(ColorButton {
             label,
+            label_path,
+            label_inline,
             flags,
             preview,
             size,
             catch,
             map,
             input_mode,
+            alpha_preview,
+            alpha_preview_half,
+            ..
         }) => {
-            let label = match label {
-                Some(Lit::Str(stri)) => stri.value(),
-                None => ident.to_string(),
-                // TODO proper error span
-                _ => return Err(Error::invalid_format(attr.span())),
-            };
-            let label = Literal::string(&label);
+            let label = emmit_label(
+                attr,
+                ident,
+                label.as_ref(),
+                label_path.as_ref(),
+                *label_inline,
+            )?;
             let mut params = quote! {
                 use imgui_ext::color::ColorButtonParams as Params;
-                use imgui::im_str;
                 let mut params = Params {
-                    label: im_str!( #label ),
+                    label: #label,
                     flags: None,
                     size: None,
                     preview: None,
@@ -1341,7 +2575,8 @@ pub fn emmit_tag_tokens(
             match size {
                 Some(Lit::Str(size)) => {
                     let ident = Ident::new(&size.value(), size.span());
-                    params.extend(quote! { params.size = Some( #ident().into() ); });
+                    params
+                        .extend(quote! { params.size = Some(imgui_ext::scaled(#ident().into())); });
                 }
                 None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
@@ -1359,14 +2594,29 @@ pub fn emmit_tag_tokens(
                 _ => return Err(Error::invalid_format(attr.span())),
             }
 
-            let catch_ident =
-                catch_ident(attr, ident, catch.as_ref(), input_fields, fields, methods)?;
+            params.extend(emmit_alpha_flags(
+                _ty,
+                attr,
+                false,
+                *alpha_preview,
+                *alpha_preview_half,
+            )?);
+
+            let catch_ident = click_ident(
+                attr,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+                changed,
+            )?;
 
             match map {
                 None => {
                     quote! {{
                         use imgui_ext::color::ColorButton;
-                        let _ev = ColorButton::build(ui, ext.#ident, { #params ; params });
+                        let _ev = ColorButton::build(ui, #access, { #params ; params });
                         events.#catch_ident |= _ev;
                     }}
                 }
@@ -1376,7 +2626,7 @@ pub fn emmit_tag_tokens(
                         syn::parse_str(&map.value()).expect("Error parsing parth to function.");
                     quote! {{
                         use imgui_ext::color::ColorButton;
-                        let _ev = ColorButton::build(ui, #map_path(ext.#ident), { #params ; params });
+                        let _ev = ColorButton::build(ui, #map_path(#access), { #params ; params });
                         events.#catch_ident |= _ev;
                     }}
                 }
@@ -1385,37 +2635,100 @@ pub fn emmit_tag_tokens(
         }
         Tag::Input(Input {
             label,
+            label_path,
+            label_inline,
             step,
             step_fast,
             flags,
             catch,
             size,
             map,
-        }) => {
-            let label = match label {
-                Some(Lit::Str(stri)) => stri.value(),
-                None => ident.to_string(),
-                // TODO proper error span
-                _ => return Err(Error::invalid_format(attr.span())),
-            };
-            let label = Literal::string(&label);
+            auto_select_all,
+            enter_returns_true,
+            clear_button,
+            buffer_size,
+            max_len,
+            unit,
+            completion,
+            sanitize,
+            format,
+            ..
+        })
+        | Tag::InputN(
+            _,
+            Input {
+                label,
+                label_path,
+                label_inline,
+                step,
+                step_fast,
+                flags,
+                catch,
+                size,
+                map,
+                auto_select_all,
+                enter_returns_true,
+                clear_button,
+                buffer_size,
+                max_len,
+                unit,
+                completion,
+                sanitize,
+                format,
+                ..
+            },
+        ) => {
+            let label = emmit_label(
+                attr,
+                ident,
+                label.as_ref(),
+                label_path.as_ref(),
+                *label_inline,
+            )?;
             let mut params = quote! {
                 use imgui_ext::input::InputParams as Params;
-                use imgui::im_str;
                 let mut params = Params {
-                    label: im_str!( #label ),
+                    label: #label,
                     step: None,
                     step_fast: None,
                     flags: None,
                     size: None,
+                    buffer_size: None,
+                    max_len: None,
+                    unit: None,
+                    completion: None,
+                    sanitize: None,
+                    format: None,
                 };
             };
 
+            if let Some(unit) = time_unit(unit.as_ref())? {
+                params.extend(quote! { params.unit = Some(#unit); });
+            }
+
+            match buffer_size {
+                Some(Lit::Int(buffer_size)) => {
+                    params.extend(quote! { params.buffer_size = Some(#buffer_size); })
+                }
+                None => {}
+                _ => return Err(Error::invalid_format(attr.span())),
+            }
+
+            match max_len {
+                Some(Lit::Int(max_len)) => {
+                    params.extend(quote! { params.max_len = Some(#max_len); })
+                }
+                None => {}
+                _ => return Err(Error::invalid_format(attr.span())),
+            }
+
             match size {
                 Some(Lit::Str(size)) => {
                     let fn_ident: syn::Path =
                         syn::parse_str(&size.value()).expect("Error parsing function path.");
-                    params.extend(quote! {{ params.size = Some( #fn_ident().into() ); }});
+                    params.extend(
+                        quote! {{ params.size = Some(imgui_ext::scaled(#fn_ident().into())); }},
+                    );
                 }
                 None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
@@ -1465,27 +2778,107 @@ pub fn emmit_tag_tokens(
                 _ => return Err(Error::invalid_format(attr.span())),
             }
 
+            match completion {
+                Some(Lit::Str(completion)) => {
+                    let path: syn::Path = syn::parse_str(&completion.value())
+                        .map_err(|_| Error::parsing_error(completion.span()))?;
+                    params.extend(quote! { params.completion = Some(#path); });
+                }
+                None => {}
+                _ => return Err(Error::invalid_format(attr.span())),
+            }
+
+            match sanitize {
+                Some(Lit::Str(sanitize)) => {
+                    let path: syn::Path = syn::parse_str(&sanitize.value())
+                        .map_err(|_| Error::parsing_error(sanitize.span()))?;
+                    params.extend(quote! { params.sanitize = Some(#path); });
+                }
+                None => {}
+                _ => return Err(Error::invalid_format(attr.span())),
+            }
+
+            match format {
+                Some(Lit::Str(value)) => {
+                    validate_format(value, if map.is_some() { None } else { field_ty })?;
+                    params.extend(quote!(params.format = Some(imgui::im_str!(#value));))
+                }
+                None => {}
+                _ => return Err(Error::invalid_format(attr.span())),
+            }
+
+            if *auto_select_all {
+                params.extend(quote! {{
+                    use imgui::ImGuiInputTextFlags as Flags;
+                    let flags = params.flags.unwrap_or(Flags::empty());
+                    params.flags = Some(flags | Flags::AutoSelectAll);
+                }});
+            }
+
+            if *enter_returns_true {
+                params.extend(quote! {{
+                    use imgui::ImGuiInputTextFlags as Flags;
+                    let flags = params.flags.unwrap_or(Flags::empty());
+                    params.flags = Some(flags | Flags::EnterReturnsTrue);
+                }});
+            }
+
             // TODO ????????
             params.extend(quote!(params));
 
-            let catch_ident =
-                catch_ident(attr, ident, catch.as_ref(), input_fields, fields, methods)?;
+            // with `enter_returns_true`, the event is a submit
+            let event_ident = if *enter_returns_true {
+                click_ident
+            } else {
+                catch_ident
+            };
+            let catch_ident = event_ident(
+                attr,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+                changed,
+            )?;
+
+            // small button that empties the text, on the same line.
+            let clear = |target: TokenStream| {
+                if *clear_button {
+                    let id = Literal::string(&format!("x##{}_clear", ident));
+                    quote! {
+                        ui.same_line(0.0);
+                        if ui.small_button(imgui::im_str!(#id)) {
+                            (#target).clear();
+                            events.#catch_ident = true;
+                        }
+                    }
+                } else {
+                    TokenStream::new()
+                }
+            };
 
             match map {
-                None => quote!({
-                    use imgui_ext::input::Input;
-                    let _ev = Input::build(ui, &mut ext.#ident, { #params });
-                    events.#catch_ident |= _ev;
-                }),
+                None => {
+                    let clear = clear(quote!(#access));
+                    quote!({
+                        use imgui_ext::input::Input;
+                        let _ev = Input::build(ui, &mut #access, { #params });
+                        events.#catch_ident |= _ev;
+                        #clear
+                    })
+                }
                 Some(Lit::Str(map)) => {
                     // TODO error handling
                     let map_path: syn::Path =
                         syn::parse_str(&map.value()).expect("Error parsing parth to function.");
+                    let clear = clear(quote!(#map_path (&mut #access)));
 
                     quote!({
                         use imgui_ext::input::Input;
-                        let _ev = Input::build(ui, #map_path (&mut ext.#ident), { #params });
+                        let _ev = Input::build(ui, #map_path (&mut #access), { #params });
                         events.#catch_ident |= _ev;
+                        #clear
                     })
                 }
                 _ => return Err(Error::invalid_format(attr.span())),
@@ -1493,33 +2886,67 @@ pub fn emmit_tag_tokens(
         }
         Tag::Drag(Drag {
             label,
+            label_path,
+            label_inline,
             min,
             max,
             speed,
+            speed_field,
+            speed_fast,
             power,
             format,
             catch,
             map,
+            sanitize,
+            unit,
+            labels,
+            reset_on_dclick,
+            reset_to,
+            bounded,
+            started,
+            finished,
+            ..
         }) => {
-            let label = match label {
-                Some(Lit::Str(stri)) => stri.value(),
-                None => ident.to_string(),
-                _ => return Err(Error::invalid_format(attr.span())),
-            };
-            let label = Literal::string(&label);
+            let label = emmit_label(
+                attr,
+                ident,
+                label.as_ref(),
+                label_path.as_ref(),
+                *label_inline,
+            )?;
+            check_numeric(
+                if map.is_some() { None } else { field_ty },
+                power.as_ref(),
+                &[min.as_ref(), max.as_ref()],
+            )?;
             let mut params = quote! {
                 use imgui_ext::drag::DragParams as Params;
-                use imgui::im_str;
                 let mut params = Params {
-                    label: im_str!( #label ),
+                    label: #label,
                     min: None,
                     max: None,
                     speed: None,
                     power: None,
                     format: None,
+                    sanitize: #sanitize,
+                    unit: None,
                 };
             };
 
+            if let Some(unit) = time_unit(unit.as_ref())? {
+                params.extend(quote! { params.unit = Some(#unit); });
+            }
+
+            // bounds of the type, overridden by the explicit ones below
+            if *bounded {
+                let ty = bounded_type(_ty);
+                params.extend(quote! {
+                    let _bounds = <#ty as imgui_ext::Bounded<_>>::imgui_bounds();
+                    params.min = Some(_bounds.0);
+                    params.max = Some(_bounds.1);
+                });
+            }
+
             match min {
                 Some(Lit::Float(min)) => params.extend(quote!(params.min = Some(#min);)),
                 Some(Lit::Int(min)) => params.extend(quote!(params.min = Some(#min);)),
@@ -1561,6 +2988,35 @@ pub fn emmit_tag_tokens(
                 None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
             }
+            // speed read from a sibling field at draw time.
+            match (speed, speed_field) {
+                (Some(_), Some(field)) => return Err(Error::already_defined(field.span())),
+                (None, Some(Lit::Str(field))) => {
+                    let field = Ident::new(&field.value(), field.span());
+                    params.extend(quote! { params.speed = Some(ext.#field); });
+                }
+                (_, None) => {}
+                _ => return Err(Error::invalid_format(attr.span())),
+            }
+            // speed used while the shift key is held down.
+            let speed_fast = match speed_fast {
+                Some(Lit::Float(value)) => Some(quote!(#value)),
+                Some(Lit::Str(value)) => match value.value().parse::<f32>() {
+                    Ok(value) => Some(quote!(#value)),
+                    Err(_) => return Err(Error::parsing_error(value.span())),
+                },
+                None => None,
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+            if let Some(fast) = speed_fast {
+                params.extend(quote! {
+                    params.speed = imgui_ext::drag::select_speed(
+                        ui.io().key_shift,
+                        params.speed,
+                        Some(#fast),
+                    );
+                });
+            }
             match power {
                 Some(Lit::Float(value)) => params.extend(quote! { params.power = Some(#value); }),
                 Some(Lit::Str(value)) => match value.value().parse::<f32>() {
@@ -1572,37 +3028,94 @@ pub fn emmit_tag_tokens(
             }
             match format {
                 Some(Lit::Str(value)) => {
-                    params.extend(quote!(params.format = Some(im_str!(#value));))
+                    validate_format(value, if map.is_some() { None } else { field_ty })?;
+                    params.extend(quote!(params.format = Some(imgui::im_str!(#value));))
                 }
                 None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
             }
 
-            let catch_ident =
-                catch_ident(attr, ident, catch.as_ref(), input_fields, fields, methods)?;
+            let catch_ident = catch_ident(
+                attr,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+                changed,
+            )?;
 
             params.extend(quote!(params));
-            match map {
-                None => quote!({
-                    use imgui_ext::drag::Drag;
-                    let _ev = Drag::build(ui, &mut ext.#ident, { #params });
-                    events.#catch_ident |= _ev;
-                }),
+            let elem = match map {
+                None => quote!(&mut #access),
                 Some(Lit::Str(map)) => {
                     // TODO error handling
                     let map_path: syn::Path =
                         syn::parse_str(&map.value()).expect("Error parsing parth to function.");
-
+                    quote!(#map_path(&mut #access))
+                }
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+            let build = match labels {
+                None => quote!({
+                    use imgui_ext::drag::Drag;
+                    Drag::build(ui, &mut *_elem, { #params })
+                }),
+                // one drag per component, each with its own label
+                Some(Lit::Str(labels)) => {
+                    let labels = parse_labels(labels, if map.is_some() { None } else { field_ty })?;
                     quote!({
-                        use imgui_ext::drag::Drag;
-                        let _ev = Drag::build(ui, #map_path(&mut ext.#ident), { #params });
-                        events.#catch_ident |= _ev;
+                        let _labels = [#( imgui::im_str!(#labels) ),*];
+                        imgui_ext::drag::build_axes(ui, &mut *_elem, &_labels, { #params })
                     })
                 }
                 _ => return Err(Error::invalid_format(attr.span())),
-            }
+            };
+            let reset_to = reset_value(attr, reset_to.as_ref())?;
+            let reset = match (*reset_on_dclick, &reset_to) {
+                (false, _) => quote!(),
+                (true, None) => quote! {
+                    let _dclick = ui.is_item_hovered()
+                        && ui.is_mouse_double_clicked(imgui::MouseButton::Left);
+                    _ev |= imgui_ext::drag::reset_on_double_click(_dclick, _elem);
+                },
+                (true, Some(value)) => quote! {
+                    let _dclick = ui.is_item_hovered()
+                        && ui.is_mouse_double_clicked(imgui::MouseButton::Left);
+                    _ev |= imgui_ext::reset_to(_dclick, _elem, || #value);
+                },
+            };
+            let sinks = EventSinks {
+                field_set: input_fields,
+                fields,
+                methods,
+                changed,
+            };
+            let edit = edit_events(attr, ident, started.as_ref(), finished.as_ref(), sinks)?;
+            // drawn after the edit events, which refer to the drag
+            let reset_button = match reset_to {
+                Some(value) => {
+                    reset_button(ident, tag.id_offset(), quote!(_elem), &value, &catch_ident)
+                }
+                None => quote!(),
+            };
+            quote!({
+                let _elem = #elem;
+                #[allow(unused_mut)]
+                let mut _ev = #build;
+                #reset
+                events.#catch_ident |= _ev;
+                #edit
+                #reset_button
+            })
         }
-        Tag::Button(Button { label, size, catch }) => {
+        Tag::Button(Button {
+            label,
+            size,
+            catch,
+            fill,
+            ..
+        }) => {
             let label = match label {
                 Lit::Str(stri) => Literal::string(&stri.value()),
                 _ => return Err(Error::invalid_format(attr.span())),
@@ -1612,19 +3125,24 @@ pub fn emmit_tag_tokens(
                 let id = Ident::new(&c.value(), ident.span());
                 let q = quote! { events.#id = _ev; };
                 fields.extend(quote! { pub #id: bool , });
-                methods.extend(quote! { pub fn #id(&self) -> bool { self.#id } });
+                methods.extend(quote! { #[must_use] pub fn #id(&self) -> bool { self.#id } });
+                changed.extend(catch_changed(&id));
                 q
             } else {
                 quote!()
             };
 
+            let size = match (size, fill) {
+                (None, false) => None,
+                (None, true) => Some(quote!([-1.0, 0.0])),
+                (Some(Lit::Str(size)), false) => Some(path_or_expr(size)?),
+                (Some(_), true) => return Err(Error::already_defined(attr.span())),
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+
             if let Some(size) = size {
-                let size_fn = match size {
-                    Lit::Str(size) => Ident::new(&size.value(), size.span()),
-                    _ => return Err(Error::invalid_format(attr.span())),
-                };
                 quote! {{
-                    let _ev = ui.button( imgui::im_str!( #label ), { #size_fn().into() } );
+                    let _ev = ui.button(imgui::im_str!(#label), imgui_ext::scaled(#size));
                     #catch
                 }}
             } else {
@@ -1634,108 +3152,161 @@ pub fn emmit_tag_tokens(
                 }}
             }
         }
-        Tag::BulletParent => {
-            quote! { ui.bullet(); }
-        }
-        Tag::Bullet(Bullet { text }) => {
-            let text = match text {
-                Some(Lit::Str(text)) => Some(text),
-                None => None,
+        Tag::Buttons(Buttons {
+            cols,
+            size,
+            catch,
+            on_click,
+            ..
+        }) => {
+            let cols = match cols {
+                Lit::Int(cols) => match cols.base10_parse::<usize>() {
+                    Ok(0) => return Err(Error::invalid_format(cols.span())),
+                    Ok(cols) => cols,
+                    Err(_) => return Err(Error::parsing_error(cols.span())),
+                },
                 _ => return Err(Error::invalid_format(attr.span())),
             };
-
-            if let Some(text) = text {
-                quote! {{
-                    use imgui::im_str;
-                    ui.bullet_text( im_str!( #text ));
-                }}
-            } else {
-                quote! { ui.bullet(); }
-            }
-        }
-        Tag::Slider(Slider {
+            let size = match size {
+                Some(Lit::Str(size)) => {
+                    let size = path_or_expr(size)?;
+                    quote!(imgui_ext::scaled(#size))
+                }
+                None => quote!([0.0, 0.0]),
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+            let catch_ident = click_ident(
+                attr,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+                changed,
+            )?;
+            let index_ident = index_ident(&catch_ident, input_fields, fields, methods);
+            let on_click = match on_click {
+                Some(Lit::Str(on_click)) => {
+                    let on_click: syn::Path = syn::parse_str(&on_click.value())
+                        .map_err(|_| Error::parsing_error(on_click.span()))?;
+                    enabled_only(
+                        tag,
+                        quote! {
+                            if let Some(_index) = _click {
+                                #on_click(_index, ext);
+                            }
+                        },
+                    )
+                }
+                None => TokenStream::new(),
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+
+            quote! {{
+                use imgui_ext::buttons::ButtonsParams as Params;
+                let _click = imgui_ext::buttons::build(ui, &#access[..], Params { cols: #cols, size: #size });
+                events.#catch_ident |= _click.is_some();
+                if _click.is_some() {
+                    events.#index_ident = _click;
+                }
+                #on_click
+            }}
+        }
+        Tag::BulletParent => {
+            quote! { ui.bullet(); }
+        }
+        Tag::Bullet(Bullet { text, .. }) => {
+            let text = match text {
+                Some(Lit::Str(text)) => Some(text),
+                None => None,
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+
+            if let Some(text) = text {
+                quote! {{
+                    ui.bullet_text(imgui::im_str!(#text));
+                }}
+            } else {
+                quote! { ui.bullet(); }
+            }
+        }
+        Tag::Slider(Slider {
             label,
+            label_path,
+            label_inline,
             min,
             max,
             format,
             power,
+            keyboard_step,
+            format_value,
             catch,
             map,
+            sanitize,
+            wrap,
+            logarithmic,
+            bounded,
+            started,
+            finished,
+            reset_to,
+            ..
         }) => {
-            let label = match label {
-                Some(Lit::Str(stri)) => stri.value(),
-                None => ident.to_string(),
-                _ => return Err(Error::invalid_format(attr.span())),
-            };
-            let label = Literal::string(&label);
-            let min_max = match (min, max) {
-                (Lit::Int(min), Lit::Int(max)) => quote! { min: #min, max: #max },
-                (Lit::Float(min), Lit::Float(max)) => quote! { min: #min, max: #max },
-                (Lit::Str(min), Lit::Int(max)) => {
-                    let min = min
-                        .value()
-                        .parse()
-                        .map(Literal::i64_unsuffixed)
-                        .map_err(|_| Error::parsing_error(min.span()))?;
-
-                    quote! { min: #min, max: #max }
-                }
-                (Lit::Str(min), Lit::Float(max)) => {
-                    let min = min
-                        .value()
-                        .parse()
-                        .map(Literal::f64_unsuffixed)
-                        .map_err(|_| Error::parsing_error(min.span()))?;
-
-                    quote! { min: #min, max: #max }
-                }
-                (Lit::Int(min), Lit::Str(max)) => {
-                    let max = max
-                        .value()
-                        .parse()
-                        .map(Literal::i64_unsuffixed)
-                        .map_err(|_| Error::parsing_error(max.span()))?;
-
-                    quote! { min: #min, max: #max }
-                }
-                (Lit::Float(min), Lit::Str(max)) => {
-                    let max = max
-                        .value()
-                        .parse()
-                        .map(Literal::f64_unsuffixed)
-                        .map_err(|_| Error::parsing_error(max.span()))?;
-
-                    quote! { min: #min, max: #max }
-                }
-                (Lit::Str(min), Lit::Str(max)) => {
-                    let min_f64 = min.value().parse().map(Literal::f64_unsuffixed);
-                    let max_f64 = max.value().parse().map(Literal::f64_unsuffixed);
-                    let min_i32 = min.value().parse().map(Literal::i64_unsuffixed);
-                    let max_i32 = max.value().parse().map(Literal::i64_unsuffixed);
-
-                    match (min_f64, max_f64, min_i32, max_i32) {
-                        (_, _, Ok(min), Ok(max)) => quote! { min: #min, max: #max },
-                        (Ok(min), Ok(max), _, _) => quote! { min: #min, max: #max },
-
-                        // Nope
-                        _ => return Err(Error::parsing_error(max.span())),
-                    }
+            let label = emmit_label(
+                attr,
+                ident,
+                label.as_ref(),
+                label_path.as_ref(),
+                *label_inline,
+            )?;
+            check_numeric(
+                if map.is_some() { None } else { field_ty },
+                power.as_ref(),
+                &[min.as_ref(), max.as_ref()],
+            )?;
+            let (bounds, min_max) = match (min, max) {
+                (Some(min), Some(max)) => (TokenStream::new(), slider_min_max(attr, min, max)?),
+                // missing bounds are read from the type
+                (min, max) if *bounded => {
+                    let ty = bounded_type(_ty);
+                    let min = match min {
+                        Some(min) => bound_literal(min)?,
+                        None => quote!(_bounds.0),
+                    };
+                    let max = match max {
+                        Some(max) => bound_literal(max)?,
+                        None => quote!(_bounds.1),
+                    };
+                    (
+                        quote! { let _bounds = <#ty as imgui_ext::Bounded<_>>::imgui_bounds(); },
+                        quote! { min: #min, max: #max },
+                    )
                 }
-                _ => return Err(Error::invalid_format(attr.span())),
+                (None, _) => return Err(Error::missing_param(attr.span(), "min")),
+                (_, None) => return Err(Error::missing_param(attr.span(), "max")),
             };
             let mut params = quote! {
                 use imgui_ext::slider::SliderParams as Params;
-                use imgui::im_str;
+                #bounds
                 let mut params = Params {
-                    label: im_str!( #label ),
+                    label: #label,
                     format: None,
                     #min_max,
                     power: None,
+                    sanitize: #sanitize,
+                    wrap: #wrap,
+                    logarithmic: #logarithmic,
+                    keyboard_step: None,
+                    format_value: None,
                 };
             };
+            // the power curve would be applied on top of the logarithmic scale
+            if *logarithmic && power.is_some() {
+                return Err(Error::already_defined(attr.span()));
+            }
             match format {
                 Some(Lit::Str(value)) => {
-                    params.extend(quote!(params.format = Some( im_str!(#value) );))
+                    validate_format(value, if map.is_some() { None } else { field_ty })?;
+                    params.extend(quote!(params.format = Some(imgui::im_str!(#value));))
                 }
                 None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
@@ -1749,40 +3320,113 @@ pub fn emmit_tag_tokens(
                 None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
             }
+            match (format, format_value) {
+                (Some(_), Some(format_value)) => {
+                    return Err(Error::already_defined(format_value.span()))
+                }
+                (None, Some(Lit::Str(path))) => {
+                    let path: syn::Path = syn::parse_str(&path.value())
+                        .map_err(|_| Error::parsing_error(path.span()))?;
+                    params.extend(quote! { params.format_value = Some(#path); });
+                }
+                (_, None) => {}
+                _ => return Err(Error::invalid_format(attr.span())),
+            }
+            // the step is `f32`, whatever the type of the field
+            let step = match keyboard_step {
+                Some(Lit::Float(value)) => Some(value.base10_parse::<f32>()),
+                Some(Lit::Int(value)) => Some(value.base10_parse::<f32>()),
+                None => None,
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+            match step {
+                Some(Ok(step)) => params.extend(quote!(params.keyboard_step = Some(#step);)),
+                Some(Err(err)) => return Err(Error::parsing_error(err.span())),
+                None => {}
+            }
+
+            let catch_ident = catch_ident(
+                attr,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+                changed,
+            )?;
+
+            let sinks = EventSinks {
+                field_set: input_fields,
+                fields,
+                methods,
+                changed,
+            };
+            let edit = edit_events(attr, ident, started.as_ref(), finished.as_ref(), sinks)?;
 
-            let catch_ident =
-                catch_ident(attr, ident, catch.as_ref(), input_fields, fields, methods)?;
+            let reset_to = reset_value(attr, reset_to.as_ref())?;
+            let reset_button = |elem| match &reset_to {
+                Some(value) => reset_button(ident, tag.id_offset(), elem, value, &catch_ident),
+                None => quote!(),
+            };
 
             params.extend(quote!(params));
             match map {
-                None => quote!({
-                    use imgui_ext::slider::Slider;
-                    let _ev = Slider::build(ui, &mut ext.#ident, { #params });
-                    events.#catch_ident |= _ev;
-                }),
+                None => {
+                    let reset_button = reset_button(quote!(&mut #access));
+                    quote!({
+                        use imgui_ext::slider::Slider;
+                        let _ev = Slider::build(ui, &mut #access, { #params });
+                        events.#catch_ident |= _ev;
+                        #edit
+                        #reset_button
+                    })
+                }
                 Some(Lit::Str(map)) => {
                     // TODO error handling
                     let map_path: syn::Path =
                         syn::parse_str(&map.value()).expect("Error parsing parth to function.");
+                    let reset_button = reset_button(quote!(#map_path(&mut #access)));
                     quote!({
                         use imgui_ext::slider::Slider;
-                        let _ev = Slider::build(ui, #map_path(&mut ext.#ident), { #params });
+                        let _ev = Slider::build(ui, #map_path(&mut #access), { #params });
                         events.#catch_ident |= _ev;
+                        #edit
+                        #reset_button
                     })
                 }
                 _ => return Err(Error::invalid_format(attr.span())),
             }
         }
-        Tag::Combobox(Combobox { label, catch, map, selected }) => {
-            let label = match label {
-                Some(Lit::Str(lab)) => lab.value(),
-                None => ident.to_string(),
-                _ => return Err(Error::invalid_format(attr.span())),
-            };
-            let label = Literal::string(&label);
+        Tag::Combobox(Combobox {
+            label,
+            label_path,
+            label_inline,
+            catch,
+            map,
+            selected,
+            typeahead,
+            on_select,
+            icons,
+            sections,
+            ..
+        }) => {
+            let label = emmit_label(
+                attr,
+                ident,
+                label.as_ref(),
+                label_path.as_ref(),
+                *label_inline,
+            )?;
 
-            let catch_ident =
-                catch_ident(attr, ident, catch.as_ref(), input_fields, fields, methods)?;
+            let catch_ident = catch_ident(
+                attr,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+                changed,
+            )?;
 
             let selected: usize = match selected {
                 //Some(Lit::Int(idx)) => (*idx).value(),
@@ -1791,64 +3435,153 @@ pub fn emmit_tag_tokens(
                 _ => return Err(Error::invalid_format(attr.span())),
             };
 
-            match map {
+            // sibling field with the icons of the items
+            let icons = match icons {
+                Some(Lit::Str(icons)) => {
+                    let icons = Ident::new(&icons.value(), icons.span());
+                    quote!(Some(&ext.#icons[..]))
+                }
+                None => quote!(None),
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+
+            // sibling field with the section of each item
+            let sections = match sections {
+                Some(Lit::Str(sections)) => {
+                    let sections = Ident::new(&sections.value(), sections.span());
+                    quote!(Some(&ext.#sections[..]))
+                }
+                None => quote!(None),
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+
+            let elem = match map {
+                None => quote!(&mut #access),
+                Some(Lit::Str(map)) => {
+                    // TODO error handling
+                    let map_path: syn::Path =
+                        syn::parse_str(&map.value()).expect("Error parsing parth to function.");
+                    quote!(#map_path(&mut #access))
+                }
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+
+            match on_select {
                 None => quote!({
                     use imgui_ext::combobox::Combobox;
                     use imgui_ext::combobox::ComboboxParams as Params;
-                    use imgui::im_str;
-                    let _ev = Combobox::build(ui, &mut ext.#ident, Params { label: im_str!(#label), selected: #selected });
+                    let _ev = Combobox::build(ui, #elem, Params { label: #label, selected: #selected, typeahead: #typeahead, icons: #icons, sections: #sections });
                     events.#catch_ident |= _ev;
                 }),
-                Some(Lit::Str(map)) => {
-                    // TODO error handling
-                    let map_path: syn::Path =
-                        syn::parse_str(&map.value()).expect("Error parsing parth to function.");
+                Some(Lit::Str(on_select)) => {
+                    let on_select: syn::Path = syn::parse_str(&on_select.value())
+                        .map_err(|_| Error::parsing_error(on_select.span()))?;
+                    let on_select = enabled_only(
+                        tag,
+                        quote! {
+                            if let Some(_index) = _sel {
+                                #on_select(_index, ext);
+                            }
+                        },
+                    );
                     quote!({
                         use imgui_ext::combobox::Combobox;
                         use imgui_ext::combobox::ComboboxParams as Params;
-                        use imgui::im_str;
-                        let _ev = Combobox::build(ui, #map_path(&mut ext.#ident), Params { label: im_str!(#label), selected: #selected });
-                        events.#catch_ident |= _ev;
+                        let _sel = Combobox::select(ui, #elem, Params { label: #label, selected: #selected, typeahead: #typeahead, icons: #icons, sections: #sections });
+                        events.#catch_ident |= _sel.is_some();
+                        #on_select
                     })
                 }
                 _ => return Err(Error::invalid_format(attr.span())),
             }
         }
-        Tag::Checkbox(Checkbox { label, catch, map }) => {
-            let label = match label {
-                Some(Lit::Str(lab)) => lab.value(),
-                None => ident.to_string(),
+        Tag::Checkbox(Checkbox {
+            label,
+            label_path,
+            label_inline,
+            catch,
+            map,
+            toggled,
+            ..
+        }) => {
+            let label = emmit_label(
+                attr,
+                ident,
+                label.as_ref(),
+                label_path.as_ref(),
+                *label_inline,
+            )?;
+
+            let toggled = match toggled {
+                None => None,
+                Some(toggled @ Lit::Str(_)) => Some(catch_ident(
+                    attr,
+                    ident,
+                    Some(toggled),
+                    input_fields,
+                    fields,
+                    methods,
+                    changed,
+                )?),
                 _ => return Err(Error::invalid_format(attr.span())),
             };
-            let label = Literal::string(&label);
 
-            let catch_ident =
-                catch_ident(attr, ident, catch.as_ref(), input_fields, fields, methods)?;
+            let catch_ident = catch_ident(
+                attr,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+                changed,
+            )?;
 
-            match map {
-                None => quote!({
-                    use imgui_ext::checkbox::Checkbox;
-                    use imgui_ext::checkbox::CheckboxParams as Params;
-                    use imgui::im_str;
-                    let _ev = Checkbox::build(ui, &mut ext.#ident, Params { label: im_str!(#label) });
-                    events.#catch_ident |= _ev;
-                }),
+            let elem = match map {
+                None => quote!(&mut #access),
                 Some(Lit::Str(map)) => {
                     // TODO error handling
                     let map_path: syn::Path =
                         syn::parse_str(&map.value()).expect("Error parsing parth to function.");
+                    quote!(#map_path(&mut #access))
+                }
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+
+            // `Option<bool>` fields get a tri-state checkbox (`None` is drawn
+            // as a mixed value) instead of the `Option<C>` impl.
+            let build = if map.is_none() && is_option_bool(_ty) {
+                quote!(imgui_ext::checkbox::build_tri_state)
+            } else {
+                quote!(imgui_ext::checkbox::Checkbox::build)
+            };
+
+            match toggled {
+                None => quote!({
+                    use imgui_ext::checkbox::CheckboxParams as Params;
+                    let _ev = #build(ui, #elem, Params { label: #label });
+                    events.#catch_ident |= _ev;
+                }),
+                // Extra event, only triggered when the value is different from the
+                // one before the widget was drawn.
+                Some(toggled_ident) => {
                     quote!({
-                        use imgui_ext::checkbox::Checkbox;
                         use imgui_ext::checkbox::CheckboxParams as Params;
-                        use imgui::im_str;
-                        let _ev = Checkbox::build(ui, #map_path(&mut ext.#ident), Params { label: im_str!(#label) });
+                        let _elem = #elem;
+                        let _old = std::clone::Clone::clone(&*_elem);
+                        let _ev = #build(ui, &mut *_elem, Params { label: #label });
                         events.#catch_ident |= _ev;
+                        events.#toggled_ident |= *_elem != _old;
                     })
                 }
-                _ => return Err(Error::invalid_format(attr.span())),
             }
         }
-        Tag::Nested(Nested { catch, map }) => {
+        Tag::Nested(Nested {
+            catch,
+            map,
+            id,
+            columns,
+            ..
+        }) => {
             let catch_ident = catch_ident_nested(
                 attr,
                 _ty,
@@ -1859,11 +3592,17 @@ pub fn emmit_tag_tokens(
                 methods,
             )?;
 
-            match map {
+            // the fields of the nested UI take a column each
+            let draw = |target: TokenStream| match columns {
+                Some(_) => quote!(Gui::draw_gui_columns(ui, #target, true)),
+                None => quote!(Gui::draw_gui(ui, #target)),
+            };
+            let tokens = match map {
                 None => {
+                    let draw = draw(quote!(&mut #access));
                     quote! {{
                         use imgui_ext::Gui;
-                        let _ev = Gui::draw_gui(ui, &mut ext.#ident);
+                        let _ev = #draw;
                         events.#catch_ident = _ev;
                     }}
                 }
@@ -1871,56 +3610,1293 @@ pub fn emmit_tag_tokens(
                     // TODO error handling
                     let map_path: syn::Path =
                         syn::parse_str(&map.value()).expect("Error parsing parth to function.");
+                    let draw = draw(quote!(#map_path(&mut #access)));
                     quote! {{
                         use imgui_ext::Gui;
-                        let _ev = Gui::draw_gui(ui, #map_path(&mut ext.#ident));
+                        let _ev = #draw;
                         events.#catch_ident = _ev;
                     }}
                 }
                 _ => return Err(Error::invalid_format(attr.span())),
+            };
+
+            // lay the fields of the nested UI into columns
+            let tokens = match columns {
+                Some(Lit::Int(count)) => {
+                    let id = Literal::string(&ident.to_string());
+                    quote! {{
+                        let _layout = imgui_ext::nested::begin_columns(
+                            ui,
+                            imgui::im_str!(#id),
+                            #count,
+                        );
+                        #tokens
+                        _layout.end(ui);
+                    }}
+                }
+                None => tokens,
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+
+            // explicit id scope around the nested UI
+            match id {
+                Some(Lit::Str(id)) => quote! {{
+                    let _id = ui.push_id(#id);
+                    #tokens
+                    _id.pop(ui);
+                }},
+                Some(Lit::Int(id)) => quote! {{
+                    let _id = ui.push_id(#id);
+                    #tokens
+                    _id.pop(ui);
+                }},
+                None => tokens,
+                _ => return Err(Error::invalid_format(attr.span())),
             }
         }
-        Tag::Display(Display {
+        Tag::TabBar(TabBar { catch, .. }) => {
+            let catch_ident = catch_ident_nested(
+                attr,
+                _ty,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+            )?;
+
+            let id = Literal::string(&ident.to_string());
+            quote! {{
+                use imgui_ext::Gui;
+                if let Some(_bar) = imgui_ext::tab::begin_tab_bar(ui, imgui::im_str!(#id)) {
+                    let _ev = Gui::draw_gui(ui, &mut #access);
+                    events.#catch_ident = _ev;
+                    _bar.end(ui);
+                }
+            }}
+        }
+        Tag::TabItem(TabItem {
             label,
-            display,
-            params,
+            flags,
+            open,
+            closable,
+            catch,
+            ..
         }) => {
+            let events_ident = catch_ident_nested(
+                attr,
+                _ty,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+            )?;
+
             let label = match label {
-                Some(Lit::Str(lab)) => lab.value(),
+                Some(Lit::Str(label)) => label.value(),
                 None => ident.to_string(),
                 _ => return Err(Error::invalid_format(attr.span())),
             };
             let label = Literal::string(&label);
 
-            let display = match display {
-                Some(Lit::Str(disp)) => Some(disp.value()),
-                None => None,
+            // `flags = "UnsavedDocument | SetSelected"`
+            let mut tab_flags = quote!(Flags::empty());
+            match flags {
+                Some(Lit::Str(flags)) => {
+                    for name in flags.value().split('|').map(str::trim) {
+                        let flag = match name {
+                            "SetSelected" => quote!(SET_SELECTED),
+                            "UnsavedDocument" => quote!(UNSAVED_DOCUMENT),
+                            "NoCloseWithMiddleMouseButton" => {
+                                quote!(NO_CLOSE_WITH_MIDDLE_MOUSE_BUTTON)
+                            }
+                            _ => {
+                                let expected = &[
+                                    "SetSelected",
+                                    "UnsavedDocument",
+                                    "NoCloseWithMiddleMouseButton",
+                                ];
+                                return Err(Error::unknown_param(flags.span(), name, expected));
+                            }
+                        };
+                        tab_flags.extend(quote!(| Flags::#flag));
+                    }
+                }
+                None => {}
                 _ => return Err(Error::invalid_format(attr.span())),
-            };
-
-            let display = if let Some(display) = display {
-                let literal = Literal::string(display.as_str());
-                let params: Vec<_> = params
-                    .into_iter()
-                    .map(|field| match field {
-                        DisplayParam::Literal(lit) => quote!( ext.#ident.#lit ),
-                        DisplayParam::Ident(ident) => quote!( ext.#ident.#ident ),
-                    })
-                    .collect();
-                quote!(#literal , #( #params ),*)
-            } else {
-                // display the variable using the Display trait
-                quote!("{}", ext.#ident)
-            };
+            }
 
-            quote!({
-                use imgui::im_str;
-                ui.label_text(im_str!(#label), &im_str!(#display));
+            // The close button is shown when the tab is bound to an `open` field
+            // (sibling `bool`), or when there is a `closable` event.
+            let (mut tab, closed) = match open {
+                Some(Lit::Str(open)) => {
+                    let open = Ident::new(&open.value(), open.span());
+                    let tab = quote! {
+                        let _was_open = ext.#open;
+                        let _tab = imgui_ext::tab::begin_tab_item(ui, params, Some(&mut ext.#open));
+                    };
+                    (tab, quote!(_was_open && !ext.#open))
+                }
+                None if closable.is_some() => {
+                    let tab = quote! {
+                        let mut _open = true;
+                        let _tab = imgui_ext::tab::begin_tab_item(ui, params, Some(&mut _open));
+                    };
+                    (tab, quote!(!_open))
+                }
+                None => {
+                    let tab = quote! {
+                        let _tab = imgui_ext::tab::begin_tab_item(ui, params, None);
+                    };
+                    (tab, quote!(false))
+                }
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+            tab.extend(quote! {
+                if let Some(_tab) = _tab {
+                    let _ev = Gui::draw_gui(ui, &mut #access);
+                    events.#events_ident = _ev;
+                    _tab.end(ui);
+                }
+            });
+            match closable {
+                Some(closable @ Lit::Str(_)) => {
+                    let closed_ident = catch_ident(
+                        attr,
+                        ident,
+                        Some(closable),
+                        input_fields,
+                        fields,
+                        methods,
+                        changed,
+                    )?;
+                    tab.extend(quote!(events.#closed_ident |= #closed;));
+                }
+                None => {}
+                _ => return Err(Error::invalid_format(attr.span())),
+            }
+
+            quote! {{
+                use imgui_ext::Gui;
+                use imgui_ext::tab::TabItemFlags as Flags;
+                use imgui_ext::tab::TabItemParams as Params;
+                let params = Params {
+                    label: imgui::im_str!(#label),
+                    flags: #tab_flags,
+                };
+                #tab
+            }}
+        }
+        Tag::Group(Group {
+            size,
+            catch,
+            border,
+            ..
+        }) => {
+            let catch_ident = catch_ident_nested(
+                attr,
+                _ty,
+                ident,
+                catch.as_ref(),
+                input_fields,
+                fields,
+                methods,
+            )?;
+
+            let id = Literal::string(&ident.to_string());
+            let mut child = quote!(imgui::ChildWindow::new(imgui::im_str!(#id)));
+            match size {
+                // either a path to a function (like the rest of the `size` params)
+                // or an expression (`"[0.0, 120.0]"`).
+                Some(Lit::Str(size)) => {
+                    let size = path_or_expr(size)?;
+                    child.extend(quote!(.size(imgui_ext::scaled(#size))));
+                }
+                None => {}
+                _ => return Err(Error::invalid_format(attr.span())),
+            }
+            if *border {
+                child.extend(quote!(.border(true)));
+            }
+
+            quote! {{
+                use imgui_ext::Gui;
+                #child.build(ui, || {
+                    let _ev = Gui::draw_gui(ui, &mut #access);
+                    events.#catch_ident = _ev;
+                });
+            }}
+        }
+        Tag::Display(Display {
+            label,
+            display,
+            params,
+        }) => {
+            let label = match label {
+                Some(Lit::Str(lab)) => lab.value(),
+                None => ident.to_string(),
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+            let label = Literal::string(&label);
+
+            let display = match display {
+                Some(Lit::Str(disp)) => Some(disp.value()),
+                None => None,
+                _ => return Err(Error::invalid_format(attr.span())),
+            };
+
+            let display = if let Some(display) = display {
+                let literal = Literal::string(display.as_str());
+                let params: Vec<_> = params
+                    .into_iter()
+                    .map(|field| match field {
+                        DisplayParam::Literal(lit) => quote!( #access.#lit ),
+                        DisplayParam::Ident(ident) => quote!( #access.#ident ),
+                    })
+                    .collect();
+                quote!(#literal , #( #params ),*)
+            } else {
+                // display the variable using the Display trait
+                quote!("{}", #access)
+            };
+
+            quote!({
+                ui.label_text(imgui::im_str!(#label), &imgui::im_str!(#display));
             })
         }
     };
 
-    Ok(tokens)
+    // aligns the text of the row (the `label_inline` label included) with the
+    // frame of the widget.
+    let tokens = if tag.align() {
+        quote!({
+            ui.align_text_to_frame_padding();
+            #tokens
+        })
+    } else {
+        tokens
+    };
+
+    // `frame_padding = "[x, y]"` overrides the style of the widget alone.
+    let tokens = match tag.frame_padding() {
+        Some(Lit::Str(padding)) => {
+            let [x, y] = frame_padding(padding)?;
+            quote!({
+                let _padding = ui.push_style_var(imgui::StyleVar::FramePadding([#x, #y]));
+                #tokens
+                _padding.pop(ui);
+            })
+        }
+        Some(_) => return Err(Error::invalid_format(attr.span())),
+        None => tokens,
+    };
+
+    // a disabled widget still edits the value it is drawn with, so it is drawn
+    // with a copy of the value, and the edit is discarded.
+    let tokens = if tag.disabled_if().is_some() && edits_field(tag) {
+        quote!({
+            let _restore = if _disabled.is_disabled() {
+                Some(std::clone::Clone::clone(&#access))
+            } else {
+                None
+            };
+            #tokens
+            if let Some(_restore) = _restore {
+                #access = _restore;
+            }
+        })
+    } else {
+        tokens
+    };
+
+    // `get(&Self)` reads the value before the widget is drawn, and
+    // `set(&mut Self, value)` writes it back if the widget reports a change.
+    let tokens = match accessors {
+        Some((Lit::Str(get), Lit::Str(set), catch)) => {
+            let get: syn::Path =
+                syn::parse_str(&get.value()).map_err(|_| Error::parsing_error(get.span()))?;
+            let set: syn::Path =
+                syn::parse_str(&set.value()).map_err(|_| Error::parsing_error(set.span()))?;
+            let catch_ident = match catch {
+                Some(Lit::Str(catch)) => Ident::new(&catch.value(), ident.span()),
+                _ => ident.clone(),
+            };
+            let set = enabled_only(
+                tag,
+                quote! {
+                    if events.#catch_ident {
+                        #set(&mut *ext, _value);
+                    }
+                },
+            );
+            quote!({
+                let mut _value = #get(&*ext);
+                let _changed = std::mem::replace(&mut events.#catch_ident, false);
+                #tokens
+                #set
+                events.#catch_ident |= _changed;
+            })
+        }
+        Some(_) => return Err(Error::invalid_format(attr.span())),
+        None => tokens,
+    };
+
+    // widget faded out while the predicate field is `true`. Its events go to a
+    // scratch `Events`, so they are not reported while it is disabled.
+    let tokens = match tag.disabled_if() {
+        Some(Lit::Str(predicate)) => {
+            // `disabled_if = "!field"` disables the widget while `field` is `false`
+            let value = predicate.value();
+            let value = value.trim();
+            let (negate, field) = match value.strip_prefix('!') {
+                Some(field) => (true, field.trim_start()),
+                None => (false, value),
+            };
+            if syn::parse_str::<Ident>(field).is_err() {
+                return Err(Error::parsing_error(predicate.span()));
+            }
+            let field = Ident::new(field, predicate.span());
+            let disabled = if negate {
+                quote!(!ext.#field)
+            } else {
+                quote!(ext.#field)
+            };
+            quote!({
+                let _disabled: bool = #disabled;
+                let _disabled = imgui_ext::begin_disabled(ui, _disabled);
+                let mut _disabled_events: Self::Events = unsafe { std::mem::zeroed() };
+                {
+                    let events = if _disabled.is_disabled() {
+                        &mut _disabled_events
+                    } else {
+                        &mut events
+                    };
+                    #tokens
+                }
+                _disabled.end(ui);
+            })
+        }
+        Some(_) => return Err(Error::invalid_format(attr.span())),
+        None => tokens,
+    };
+
+    // extra ID stack entry, on top of the ones pushed by the widgets.
+    let tokens = match tag.id_offset() {
+        Some(Lit::Int(offset)) => quote!({
+            let _id = ui.push_id(#offset);
+            #tokens
+            _id.pop(ui);
+        }),
+        Some(_) => return Err(Error::invalid_format(attr.span())),
+        None => tokens,
+    };
+
+    let tokens = if tag.same_line() {
+        quote!({
+            ui.same_line(0.0);
+            #tokens
+        })
+    } else {
+        tokens
+    };
+
+    // the whole widget (`same_line` included) is skipped while the predicate
+    // returns `true`.
+    match tag.hidden_fn() {
+        Some(Lit::Str(predicate)) => {
+            let path: syn::Path = syn::parse_str(&predicate.value())
+                .map_err(|_| Error::parsing_error(predicate.span()))?;
+            Ok(quote!({
+                let _hidden: bool = #path(&*ext);
+                if !_hidden {
+                    #tokens
+                }
+            }))
+        }
+        Some(_) => Err(Error::invalid_format(attr.span())),
+        None => Ok(tokens),
+    }
+}
+
+/// Adds the alpha flags of the `color(...)` annotation to the rest of the flags.
+///
+/// Alpha flags are rejected on `[f32; 3]` fields, which have no alpha channel.
+fn emmit_alpha_flags(
+    ty: &Type,
+    attr: &Attribute,
+    alpha_bar: bool,
+    alpha_preview: bool,
+    alpha_preview_half: bool,
+) -> Result<TokenStream, Error> {
+    if !(alpha_bar || alpha_preview || alpha_preview_half) {
+        return Ok(TokenStream::new());
+    }
+
+    if array_len(ty) == Some(3) {
+        return Err(Error::no_alpha(attr.span()));
+    }
+
+    let mut alpha = Vec::new();
+    if alpha_bar {
+        alpha.push(quote!(Flags::ALPHA_BAR));
+    }
+    if alpha_preview {
+        alpha.push(quote!(Flags::ALPHA_PREVIEW));
+    }
+    if alpha_preview_half {
+        alpha.push(quote!(Flags::ALPHA_PREVIEW_HALF));
+    }
+
+    Ok(quote! {{
+        use imgui::ColorEditFlags as Flags;
+        let flags = params.flags.unwrap_or(Flags::empty());
+        params.flags = Some(flags #( | #alpha )*);
+    }})
+}
+
+/// Length of a fixed-size array type (`[T; N]`), if the length is a literal.
+fn array_len(ty: &Type) -> Option<usize> {
+    match ty {
+        Type::Array(syn::TypeArray {
+            len: syn::Expr::Lit(syn::ExprLit {
+                lit: Lit::Int(len), ..
+            }),
+            ..
+        }) => len.base10_parse().ok(),
+        _ => None,
+    }
+}
+
+/// Expression for the label of a widget (evaluates to an `&ImStr`).
+///
+/// - `label = "..."` string literal.
+/// - `label = CONST` path to a `&'static str` constant (`label_path`).
+///
+/// When none are defined, the name of the field is used as the label.
+fn emmit_label(
+    attr: &Attribute,
+    ident: &Ident,
+    label: Option<&Lit>,
+    label_path: Option<&Path>,
+    label_inline: bool,
+) -> Result<TokenStream, Error> {
+    let label = match (label, label_path) {
+        (Some(_), Some(path)) => return Err(Error::already_defined(path.span())),
+        (Some(Lit::Str(label)), None) => quote!(imgui::im_str!(#label)),
+        (None, Some(path)) => quote!(imgui_ext::static_label!(#path)),
+        (None, None) => {
+            let label = Literal::string(&ident.to_string());
+            quote!(imgui::im_str!(#label))
+        }
+        _ => return Err(Error::invalid_format(attr.span())),
+    };
+    if label_inline {
+        // label is rendered as text before the widget, which gets a hidden label
+        // (but keeps the same id).
+        let hidden = Literal::string(&format!("##{}", ident));
+        Ok(quote!({
+            ui.text(#label);
+            ui.same_line(0.0);
+            imgui::im_str!(#hidden)
+        }))
+    } else {
+        Ok(label)
+    }
+}
+
+/// Shared by the `plot(lines(...))` and `plot(histogram(...))` variants, which
+/// only differ on the trait used to build the widget.
+fn emmit_plot_tokens(
+    plot_trait: TokenStream,
+    ident: &Ident,
+    access: &TokenStream,
+    attr: &Attribute,
+    plot: &Plot,
+) -> Result<TokenStream, Error> {
+    let Plot {
+        label,
+        label_path,
+        label_inline,
+        overlay,
+        size,
+        min,
+        max,
+        offset,
+        stride,
+        ..
+    } = plot;
+
+    let label = emmit_label(
+        attr,
+        ident,
+        label.as_ref(),
+        label_path.as_ref(),
+        *label_inline,
+    )?;
+    let mut params = quote! {
+        use imgui_ext::plot::PlotParams as Params;
+        let mut params = Params {
+            label: #label,
+            overlay: None,
+            size: None,
+            min: None,
+            max: None,
+            offset: None,
+            stride: None,
+        };
+    };
+
+    match overlay {
+        Some(Lit::Str(value)) => {
+            validate_text(value)?;
+            params.extend(quote!(params.overlay = Some(imgui::im_str!(#value));))
+        }
+        None => {}
+        _ => return Err(Error::invalid_format(attr.span())),
+    }
+
+    match size {
+        Some(Lit::Str(size)) => {
+            let fn_ident: syn::Path =
+                syn::parse_str(&size.value()).expect("Error parsing function path.");
+            params.extend(quote! {{ params.size = Some(imgui_ext::scaled(#fn_ident().into())); }});
+        }
+        None => {}
+        _ => return Err(Error::invalid_format(attr.span())),
+    }
+
+    match min {
+        Some(Lit::Float(min)) => params.extend(quote!(params.min = Some(#min);)),
+        Some(Lit::Str(min)) => match min.value().parse::<f32>() {
+            Ok(min) => params.extend(quote!(params.min = Some(#min);)),
+            Err(_) => return Err(Error::parsing_error(min.span())),
+        },
+        None => {}
+        _ => return Err(Error::invalid_format(attr.span())),
+    }
+
+    match max {
+        Some(Lit::Float(max)) => params.extend(quote!(params.max = Some(#max);)),
+        Some(Lit::Str(max)) => match max.value().parse::<f32>() {
+            Ok(max) => params.extend(quote!(params.max = Some(#max);)),
+            Err(_) => return Err(Error::parsing_error(max.span())),
+        },
+        None => {}
+        _ => return Err(Error::invalid_format(attr.span())),
+    }
+
+    // The offset is either a constant or the name of a sibling field holding the
+    // current offset (the head of a ring buffer, for example).
+    match offset {
+        Some(Lit::Int(offset)) => {
+            let offset = offset
+                .base10_parse::<usize>()
+                .map_err(|_| Error::parsing_error(offset.span()))?;
+            params.extend(quote!(params.offset = Some(#offset);));
+        }
+        Some(Lit::Str(field)) => {
+            let field = Ident::new(&field.value(), field.span());
+            params.extend(quote!(params.offset = Some(ext.#field as usize);));
+        }
+        None => {}
+        _ => return Err(Error::invalid_format(attr.span())),
+    }
+
+    // stride is measured in bytes, so it can't be smaller than one element,
+    // and it must keep the values aligned.
+    match stride {
+        Some(Lit::Int(lit)) => {
+            let stride = lit
+                .base10_parse::<usize>()
+                .map_err(|_| Error::parsing_error(lit.span()))?;
+            let size = std::mem::size_of::<f32>();
+            if stride < size || stride % size != 0 {
+                return Err(Error::invalid_format(lit.span()));
+            }
+            params.extend(quote!(params.stride = Some(#stride);));
+        }
+        None => {}
+        _ => return Err(Error::invalid_format(attr.span())),
+    }
+
+    Ok(quote! {{
+        use #plot_trait as Plot;
+        Plot::build(ui, &#access, { #params; params });
+    }})
+}
+
+/// Kind of value a `printf` conversion formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Conversion {
+    Float,
+    Int,
+    /// 64-bit integers, formatted with the `ll` length modifier (`%lld`).
+    Int64,
+}
+
+/// Kind of conversion expected for the values of a field of type `ty`. Returns
+/// `None` if it can't be known from the type alone (custom types, generics).
+fn conversion(ty: &Type) -> Option<Conversion> {
+    match ty {
+        Type::Array(array) => conversion(&array.elem),
+        Type::Reference(reference) => conversion(&reference.elem),
+        Type::Paren(paren) => conversion(&paren.elem),
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+            match segment.ident.to_string().as_str() {
+                "f32" | "f64" => Some(Conversion::Float),
+                "i8" | "i16" | "i32" | "u8" | "u16" | "u32" => Some(Conversion::Int),
+                // `usize` is edited as an `i64` (see `imgui_ext::Unsigned`)
+                "i64" | "isize" | "u64" | "usize" => Some(Conversion::Int64),
+                // wrappers of a single value, and ranges (both bounds)
+                "Option" | "Box" | "Rc" | "Arc" | "RefCell" | "Mutex" | "Range"
+                | "RangeInclusive" => match &segment.arguments {
+                    syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                        match args.args.first() {
+                            Some(syn::GenericArgument::Type(ty)) => conversion(ty),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Rejects numeric options that don't apply to the type of the field: `power`
+/// (a float curve) on integers, and `min`/`max` literals of the other kind of
+/// number. String values are parsed as either kind, so they are not checked.
+///
+/// `ty` is `None` when the type of the value is unknown (for example, when the
+/// field is mapped), in which case nothing is checked.
+fn check_numeric(
+    ty: Option<&Type>,
+    power: Option<&Lit>,
+    bounds: &[Option<&Lit>],
+) -> Result<(), Error> {
+    let kind = match ty.and_then(conversion) {
+        Some(kind) => kind,
+        None => return Ok(()),
+    };
+    if let (Conversion::Int, Some(power)) | (Conversion::Int64, Some(power)) = (kind, power) {
+        return Err(Error::float_only(power.span(), "power"));
+    }
+    for bound in bounds.iter().flatten() {
+        match (kind, bound) {
+            (Conversion::Int, Lit::Float(_)) | (Conversion::Int64, Lit::Float(_)) => {
+                return Err(Error::literal(bound.span(), "an integer"))
+            }
+            (Conversion::Float, Lit::Int(_)) => {
+                return Err(Error::literal(bound.span(), "a float"))
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a `format` string can be safely passed to `printf` along with a
+/// single value of type `ty`: exactly one conversion, of the right kind and
+/// size, and no `*` width/precision (it would read more arguments than the ones
+/// imgui passes). The only length modifier is `ll`, required by (and only
+/// accepted on) 64-bit integers.
+///
+/// `ty` is `None` when the type of the value is unknown (for example, when the
+/// field is mapped), in which case any numeric conversion is accepted.
+fn validate_format(fmt: &syn::LitStr, ty: Option<&Type>) -> Result<(), Error> {
+    validate_text(fmt)?;
+
+    let err = |reason| Err(Error::printf(fmt.span(), reason));
+    let value = fmt.value();
+    let mut chars = value.chars().peekable();
+    let mut found = None;
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            continue;
+        }
+        // flags, width & precision
+        while let Some(&c) = chars.peek() {
+            match c {
+                '-' | '+' | ' ' | '#' | '.' | '0'..='9' => {
+                    chars.next();
+                }
+                '*' => return err("`*` width and precision are not supported."),
+                _ => break,
+            }
+        }
+        let long = chars.peek() == Some(&'l');
+        if long {
+            chars.next();
+            if chars.next() != Some('l') {
+                return err("length modifiers other than `ll` are not supported.");
+            }
+        }
+        let kind = match chars.next() {
+            Some('f') | Some('F') | Some('e') | Some('E') | Some('g') | Some('G') | Some('a')
+            | Some('A') => {
+                if long {
+                    return err("`ll` is only valid on integer conversions.");
+                }
+                Conversion::Float
+            }
+            Some('d') | Some('i') | Some('u') | Some('x') | Some('X') | Some('o') => {
+                if long {
+                    Conversion::Int64
+                } else {
+                    Conversion::Int
+                }
+            }
+            Some('h') | Some('l') | Some('L') | Some('q') | Some('j') | Some('z') | Some('t') => {
+                return err("length modifiers other than `ll` are not supported.")
+            }
+            Some(_) => return err("unsupported conversion."),
+            None => return err("incomplete conversion at the end of the string."),
+        };
+        if found.is_some() {
+            return err("expected a single conversion.");
+        }
+        found = Some(kind);
+    }
+
+    match (found, ty.and_then(conversion)) {
+        (None, _) => err("missing conversion (`%...`)."),
+        (Some(found), Some(expected)) if found == expected => Ok(()),
+        (Some(_), Some(Conversion::Float)) => {
+            err("expected a float conversion (`%f`, `%e`, `%g` or `%a`).")
+        }
+        (Some(_), Some(Conversion::Int)) => {
+            err("expected an integer conversion (`%d`, `%i`, `%u`, `%x` or `%o`).")
+        }
+        (Some(_), Some(Conversion::Int64)) => {
+            err("expected a 64-bit integer conversion (`%lld`, `%llu` or `%llx`).")
+        }
+        (Some(_), None) => Ok(()),
+    }
+}
+
+/// Checks text that imgui draws as is, without formatting it (the overlay of
+/// progress bars and plots). Only a NUL character (which would cut the C
+/// string short) is rejected.
+fn validate_text(text: &syn::LitStr) -> Result<(), Error> {
+    if text.value().contains('\0') {
+        Err(Error::printf(text.span(), "unexpected NUL character."))
+    } else {
+        Ok(())
+    }
+}
+
+/// Parses the expression of the `reset_to` option of drags and sliders
+/// (`reset_to = "100.0"`).
+fn reset_value(attr: &Attribute, reset_to: Option<&Lit>) -> Result<Option<TokenStream>, Error> {
+    match reset_to {
+        Some(Lit::Str(value)) => {
+            let expr: TokenStream =
+                syn::parse_str(&value.value()).map_err(|_| Error::parsing_error(value.span()))?;
+            if expr.is_empty() {
+                return Err(Error::invalid_format(value.span()));
+            }
+            Ok(Some(expr))
+        }
+        None => Ok(None),
+        _ => Err(Error::invalid_format(attr.span())),
+    }
+}
+
+/// `Reset` button of the `reset_to` option, drawn on the same line as the
+/// widget. Clicking it sets `elem` (a `&mut` to the value) to `value`, and
+/// triggers the change event.
+fn reset_button(
+    ident: &Ident,
+    id_offset: Option<&Lit>,
+    elem: TokenStream,
+    value: &TokenStream,
+    catch: &Ident,
+) -> TokenStream {
+    let id = Literal::string(&ident.to_string());
+    let offset = match id_offset {
+        Some(Lit::Int(offset)) => quote!(#offset),
+        _ => quote!(0),
+    };
+    quote! {
+        let _reset = imgui_ext::reset_button(ui, #id, #offset);
+        events.#catch |= imgui_ext::reset_to(_reset, #elem, || #value);
+    }
+}
+
+/// Parses the `labels` option of a drag (`"[\"X\", \"Y\", \"Z\"]"`). If the
+/// annotated field is an array, there must be one label per component.
+fn parse_labels(labels: &syn::LitStr, ty: Option<&Type>) -> Result<Vec<syn::LitStr>, Error> {
+    use syn::parse::Parser;
+    use syn::punctuated::Punctuated;
+
+    let error = || Error::parsing_error(labels.span());
+    let tokens: TokenStream = syn::parse_str(&labels.value()).map_err(|_| error())?;
+    let mut tokens = tokens.into_iter();
+    let group = match (tokens.next(), tokens.next()) {
+        (Some(proc_macro2::TokenTree::Group(group)), None)
+            if group.delimiter() == proc_macro2::Delimiter::Bracket =>
+        {
+            group
+        }
+        _ => return Err(error()),
+    };
+    let list = Punctuated::<syn::LitStr, syn::Token![,]>::parse_terminated
+        .parse2(group.stream())
+        .map_err(|_| error())?;
+
+    let len = match ty {
+        Some(Type::Array(array)) => match &array.len {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: Lit::Int(len), ..
+            }) => len.base10_parse::<usize>().ok(),
+            _ => None,
+        },
+        _ => None,
+    };
+    match len {
+        Some(len) if len != list.len() => Err(Error::labels(labels.span(), len)),
+        _ => Ok(list.into_iter().collect()),
+    }
+}
+
+/// Parses a color param: either a path to a function that returns the color,
+/// or a literal array of numbers (`"[1, 0, 0, 1]"`).
+fn color_expr(color: &syn::LitStr) -> Result<TokenStream, Error> {
+    if let Ok(path) = syn::parse_str::<syn::Path>(&color.value()) {
+        return Ok(quote!(#path().into()));
+    }
+    let value = color.value();
+    let components = value
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|c| c.trim().parse::<f32>().map(Literal::f32_unsuffixed))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| Error::parsing_error(color.span()))?;
+    let trimmed = value.trim();
+    if !trimmed.starts_with('[') || !trimmed.ends_with(']') || components.len() != 4 {
+        return Err(Error::parsing_error(color.span()));
+    }
+    Ok(quote!([#( #components ),*]))
+}
+
+/// `unit` option of the widgets that can edit a `Duration` (`"s"` or `"ms"`).
+fn time_unit(unit: Option<&Lit>) -> Result<Option<TokenStream>, Error> {
+    match unit {
+        Some(Lit::Str(unit)) => match unit.value().as_str() {
+            "s" => Ok(Some(quote!(imgui_ext::duration::TimeUnit::Seconds))),
+            "ms" => Ok(Some(quote!(imgui_ext::duration::TimeUnit::Millis))),
+            _ => Err(Error::invalid_format(unit.span())),
+        },
+        Some(unit) => Err(Error::invalid_format(unit.span())),
+        None => Ok(None),
+    }
+}
+
+/// Number of widgets drawn by `tags`. Modifiers (`newtype`, `order`...)
+/// don't count, and nested UIs count as a single widget.
+pub fn widget_count(tags: &[Tag]) -> usize {
+    tags.iter().filter(|tag| !is_modifier(tag)).count()
+}
+
+/// `imgui_ext::WidgetInfo` entries of the widgets drawn by `tags` (one per
+/// widget counted by `widget_count`).
+pub fn widget_info(ident: &Ident, tags: &[Tag]) -> Vec<TokenStream> {
+    let field = ident.to_string();
+    tags.iter()
+        .filter(|tag| !is_modifier(tag))
+        .map(|tag| {
+            let kind = widget_kind(tag);
+            let label = match widget_label(tag) {
+                Some((Some(Lit::Str(label)), _)) => quote!(Some(#label)),
+                Some((_, Some(path))) => quote!(Some(#path)),
+                Some(_) => quote!(Some(#field)),
+                None => quote!(None),
+            };
+            let (min, max) = match tag {
+                Tag::Slider(Slider { min, max, .. }) | Tag::Drag(Drag { min, max, .. }) => {
+                    (bound_value(min.as_ref()), bound_value(max.as_ref()))
+                }
+                _ => (quote!(None), quote!(None)),
+            };
+            quote! {
+                imgui_ext::WidgetInfo {
+                    field: #field,
+                    kind: #kind,
+                    label: #label,
+                    min: #min,
+                    max: #max,
+                }
+            }
+        })
+        .collect()
+}
+
+/// `label` param of the widgets that have a label (either a literal or a
+/// path to a constant).
+fn widget_label(tag: &Tag) -> Option<(Option<&Lit>, Option<&Path>)> {
+    match tag {
+        Tag::Combobox(t) => Some((t.label.as_ref(), t.label_path.as_ref())),
+        Tag::Checkbox(t) => Some((t.label.as_ref(), t.label_path.as_ref())),
+        Tag::Input(t) | Tag::InputN(_, t) => Some((t.label.as_ref(), t.label_path.as_ref())),
+        Tag::Slider(t) => Some((t.label.as_ref(), t.label_path.as_ref())),
+        Tag::Drag(t) => Some((t.label.as_ref(), t.label_path.as_ref())),
+        Tag::ColorButton(t) => Some((t.label.as_ref(), t.label_path.as_ref())),
+        Tag::ColorPicker(t) => Some((t.label.as_ref(), t.label_path.as_ref())),
+        Tag::ColorEdit(t) => Some((t.label.as_ref(), t.label_path.as_ref())),
+        Tag::PlotLines(t) | Tag::PlotHistogram(t) => {
+            Some((t.label.as_ref(), t.label_path.as_ref()))
+        }
+        Tag::Button(t) => Some((Some(&t.label), None)),
+        Tag::List(t) => Some((t.label.as_ref(), None)),
+        _ => None,
+    }
+}
+
+/// Name of the annotation of `tag`, as it is written in `#[imgui(...)]`.
+fn widget_kind(tag: &Tag) -> &'static str {
+    match tag {
+        Tag::None => "none",
+        Tag::Display(_) => "display",
+        Tag::Combobox(_) => "combobox",
+        Tag::Checkbox(_) => "checkbox",
+        Tag::Input(_) => "input",
+        Tag::InputN(2, _) => "input2",
+        Tag::InputN(3, _) => "input3",
+        Tag::InputN(_, _) => "input4",
+        Tag::Slider(_) => "slider",
+        Tag::Drag(_) => "drag",
+        Tag::Nested(_) => "nested",
+        Tag::Group(_) => "group",
+        Tag::TabBar(_) => "tab_bar",
+        Tag::TabItem(_) => "tab_item",
+        Tag::List(_) => "list",
+        Tag::Progress(_) => "progress",
+        Tag::Image(_) => "image",
+        Tag::ImageButton(_) => "image_button",
+        Tag::Button(_) => "button",
+        Tag::Buttons(_) => "buttons",
+        Tag::ColorButton(_) => "color(button)",
+        Tag::ColorPicker(_) => "color(picker)",
+        Tag::ColorEdit(_) => "color(edit)",
+        Tag::PlotLines(_) => "plot(lines)",
+        Tag::PlotHistogram(_) => "plot(histogram)",
+        Tag::Separator(_) => "separator",
+        Tag::NewLine => "new_line",
+        Tag::Text(Text { wrapped: true, .. }) => "text_wrap",
+        Tag::Text(_) => "text",
+        Tag::BulletParent | Tag::Bullet(_) => "bullet",
+        Tag::Tree(_) => "tree",
+        Tag::Vars(_) => "vars",
+        Tag::Newtype => "newtype",
+        Tag::Borrow => "borrow",
+        Tag::Lock => "lock",
+        Tag::Deref => "deref",
+        Tag::Cell => "cell",
+        Tag::Atomic(_) => "atomic",
+        Tag::Order(_) => "order",
+    }
+}
+
+/// Value of a `min`/`max` literal, as an `Option<f64>` expression. Bounds that
+/// are not literals (or not given) are `None`.
+fn bound_value(bound: Option<&Lit>) -> TokenStream {
+    let value = match bound {
+        Some(Lit::Int(int)) => int.base10_digits().parse::<f64>().ok(),
+        Some(Lit::Float(float)) => float.base10_digits().parse::<f64>().ok(),
+        Some(Lit::Str(string)) => string.value().parse::<f64>().ok(),
+        _ => None,
+    };
+    match value {
+        // negative literals are a `-` followed by the absolute value
+        Some(value) if value < 0.0 => {
+            let value = Literal::f64_unsuffixed(-value);
+            quote!(Some(-#value))
+        }
+        Some(value) => {
+            let value = Literal::f64_unsuffixed(value);
+            quote!(Some(#value))
+        }
+        None => quote!(None),
+    }
+}
+
+/// Whether the widgets of `tag` read or modify the annotated field.
+pub fn uses_field(tag: &Tag) -> bool {
+    !is_modifier(tag) && !is_layout(tag)
+}
+
+/// Wraps the `tokens` of a callback (such as `on_click`) so it is only called
+/// while the widget is enabled (see `disabled_if`).
+fn enabled_only(tag: &Tag, tokens: TokenStream) -> TokenStream {
+    match tag.disabled_if() {
+        Some(_) => quote! {
+            if !_disabled.is_disabled() {
+                #tokens
+            }
+        },
+        None => tokens,
+    }
+}
+
+/// Widgets that can edit the value of the field (the rest only read it).
+fn edits_field(tag: &Tag) -> bool {
+    match tag {
+        Tag::Progress(_)
+        | Tag::Image(_)
+        | Tag::ImageButton(_)
+        | Tag::ColorButton(_)
+        | Tag::PlotLines(_)
+        | Tag::PlotHistogram(_)
+        | Tag::Bullet(_) => false,
+        tag => uses_field(tag),
+    }
+}
+
+/// Modifiers change how the field is accessed, but draw nothing.
+fn is_modifier(tag: &Tag) -> bool {
+    matches!(
+        tag,
+        Tag::None
+            | Tag::Newtype
+            | Tag::Borrow
+            | Tag::Lock
+            | Tag::Deref
+            | Tag::Cell
+            | Tag::Atomic(_)
+            | Tag::Order(_)
+    )
+}
+
+/// Type drawn with its own `Gui` implementation by `tags` (`nested`,
+/// `tab_bar`, `tab_item` and `group`), if any.
+pub fn nested_type(ty: &Type, tags: &[Tag]) -> Option<Type> {
+    let mut nested = false;
+    for tag in tags {
+        match tag {
+            // the drawn type is not the type of the field
+            Tag::Newtype | Tag::Borrow | Tag::Lock | Tag::Deref | Tag::Cell | Tag::Atomic(_) => {
+                return None
+            }
+            Tag::Nested(Nested { map: None, .. })
+            | Tag::TabBar(_)
+            | Tag::TabItem(_)
+            | Tag::Group(_) => nested = true,
+            _ => {}
+        }
+    }
+    match ty {
+        _ if !nested => None,
+        Type::Reference(reference) => Some((*reference.elem).clone()),
+        ty => Some(ty.clone()),
+    }
+}
+
+/// `min: .., max: ..` fields of the `SliderParams`, from the `min` and `max`
+/// literals of a `slider`. String literals are parsed as the same kind of
+/// number as the other bound.
+fn slider_min_max(attr: &Attribute, min: &Lit, max: &Lit) -> Result<TokenStream, Error> {
+    let min_max = match (min, max) {
+        (Lit::Int(min), Lit::Int(max)) => quote! { min: #min, max: #max },
+        (Lit::Float(min), Lit::Float(max)) => quote! { min: #min, max: #max },
+        (Lit::Str(min), Lit::Int(max)) => {
+            let min = min
+                .value()
+                .parse()
+                .map(Literal::i64_unsuffixed)
+                .map_err(|_| Error::parsing_error(min.span()))?;
+
+            quote! { min: #min, max: #max }
+        }
+        (Lit::Str(min), Lit::Float(max)) => {
+            let min = min
+                .value()
+                .parse()
+                .map(Literal::f64_unsuffixed)
+                .map_err(|_| Error::parsing_error(min.span()))?;
+
+            quote! { min: #min, max: #max }
+        }
+        (Lit::Int(min), Lit::Str(max)) => {
+            let max = max
+                .value()
+                .parse()
+                .map(Literal::i64_unsuffixed)
+                .map_err(|_| Error::parsing_error(max.span()))?;
+
+            quote! { min: #min, max: #max }
+        }
+        (Lit::Float(min), Lit::Str(max)) => {
+            let max = max
+                .value()
+                .parse()
+                .map(Literal::f64_unsuffixed)
+                .map_err(|_| Error::parsing_error(max.span()))?;
+
+            quote! { min: #min, max: #max }
+        }
+        (Lit::Str(min), Lit::Str(max)) => {
+            let min_f64 = min.value().parse().map(Literal::f64_unsuffixed);
+            let max_f64 = max.value().parse().map(Literal::f64_unsuffixed);
+            let min_i32 = min.value().parse().map(Literal::i64_unsuffixed);
+            let max_i32 = max.value().parse().map(Literal::i64_unsuffixed);
+
+            match (min_f64, max_f64, min_i32, max_i32) {
+                (_, _, Ok(min), Ok(max)) => quote! { min: #min, max: #max },
+                (Ok(min), Ok(max), _, _) => quote! { min: #min, max: #max },
+
+                // Nope
+                _ => return Err(Error::parsing_error(max.span())),
+            }
+        }
+        _ => return Err(Error::invalid_format(attr.span())),
+    };
+    Ok(min_max)
+}
+
+/// Value of a single `min`/`max` literal (strings are parsed as an integer, or
+/// as a float).
+fn bound_literal(bound: &Lit) -> Result<TokenStream, Error> {
+    match bound {
+        Lit::Int(bound) => Ok(quote!(#bound)),
+        Lit::Float(bound) => Ok(quote!(#bound)),
+        Lit::Str(bound) => {
+            let bound_i64 = bound.value().parse().map(Literal::i64_unsuffixed);
+            let bound_f64 = bound.value().parse().map(Literal::f64_unsuffixed);
+            match (bound_i64, bound_f64) {
+                (Err(_), Ok(bound)) => Ok(quote!(#bound)),
+                (Ok(bound), _) => Ok(quote!(#bound)),
+                _ => Err(Error::parsing_error(bound.span())),
+            }
+        }
+        _ => Err(Error::invalid_format(bound.span())),
+    }
+}
+
+/// Parses the `[x, y]` literal of the `frame_padding` option. Both values
+/// must be finite and non-negative.
+fn frame_padding(padding: &syn::LitStr) -> Result<[f32; 2], Error> {
+    let value = padding.value();
+    let value = value.trim();
+    if !value.starts_with('[') || !value.ends_with(']') {
+        return Err(Error::invalid_format(padding.span()));
+    }
+    let xy = value[1..value.len() - 1]
+        .split(',')
+        .map(|value| value.trim().parse::<f32>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| Error::parsing_error(padding.span()))?;
+    match xy[..] {
+        [x, y] if x.is_finite() && y.is_finite() && x >= 0.0 && y >= 0.0 => Ok([x, y]),
+        _ => Err(Error::invalid_format(padding.span())),
+    }
+}
+
+/// Type that implements `Bounded` for the `bounded` flag: the type of the
+/// field, behind any reference.
+fn bounded_type(ty: &Type) -> &Type {
+    match ty {
+        Type::Reference(reference) => bounded_type(&reference.elem),
+        Type::Paren(paren) => bounded_type(&paren.elem),
+        ty => ty,
+    }
+}
+
+/// Whether the field is an `Option<bool>` (or a reference to one), which is
+/// drawn as a tri-state checkbox.
+fn is_option_bool(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => is_option_bool(&reference.elem),
+        Type::Paren(paren) => is_option_bool(&paren.elem),
+        Type::Path(path) if path.qself.is_none() => match path.path.segments.last() {
+            Some(segment) if segment.ident == "Option" => match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                    match args.args.first() {
+                        Some(syn::GenericArgument::Type(Type::Path(inner))) => {
+                            inner.qself.is_none() && inner.path.is_ident("bool")
+                        }
+                        _ => false,
+                    }
+                }
+                _ => false,
+            },
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether the field is a `PhantomData`, which can only host layout tags.
+fn is_phantom(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "PhantomData")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Tags that never read nor write the annotated field.
+fn is_layout(tag: &Tag) -> bool {
+    match tag {
+        Tag::None | Tag::Order(_) | Tag::Separator(_) | Tag::NewLine | Tag::Button(_) => true,
+        Tag::Text(Text { lit, .. }) => lit.is_some(),
+        Tag::Bullet(Bullet { text, .. }) => text.is_some(),
+        _ => false,
+    }
+}
+
+/// Checks that the field annotated with `inputN` is a `[f32; N]` (or a mutable
+/// reference to one).
+fn check_arity(ty: &Type, n: usize) -> Result<(), Error> {
+    let array = match ty {
+        Type::Reference(reference) => &*reference.elem,
+        ty => ty,
+    };
+    let ok = match array {
+        Type::Array(array) => {
+            let f32_elem = match &*array.elem {
+                Type::Path(path) => path.path.is_ident("f32"),
+                _ => false,
+            };
+            let len = match &array.len {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: Lit::Int(len), ..
+                }) => len.base10_parse::<usize>().ok(),
+                _ => None,
+            };
+            f32_elem && len == Some(n)
+        }
+        _ => false,
+    };
+    if ok {
+        Ok(())
+    } else {
+        Err(Error::arity(ty.span(), n))
+    }
+}
+
+/// Parses a string param that is either a path to a function (called and
+/// converted with `into()`), or an expression (`"[24.0, 24.0]"`).
+fn path_or_expr(lit: &syn::LitStr) -> Result<TokenStream, Error> {
+    if let Ok(path) = syn::parse_str::<syn::Path>(&lit.value()) {
+        Ok(quote!(#path().into()))
+    } else {
+        syn::parse_str(&lit.value()).map_err(|_| Error::parsing_error(lit.span()))
+    }
 }
 
 fn catch_ident(
@@ -1930,6 +4906,7 @@ fn catch_ident(
     field_set: &mut HashSet<String>,
     fields: &mut TokenStream,
     methods: &mut TokenStream,
+    changed: &mut TokenStream,
 ) -> Result<Ident, Error> {
     match catch {
         Some(Lit::Str(lit)) => {
@@ -1937,6 +4914,7 @@ fn catch_ident(
 
             fields.extend(quote! { pub #ident: bool , });
             methods.extend(quote! { pub fn #ident(&self) -> bool { self.#ident } });
+            changed.extend(catch_changed(&ident));
 
             Ok(ident)
         }
@@ -1948,6 +4926,7 @@ fn catch_ident(
                 methods.extend(
                     quote! { #[inline(always)] pub fn #field(&self) -> bool { self.#field } },
                 );
+                changed.extend(catch_changed(field));
             }
 
             Ok(field.clone())
@@ -1957,6 +4936,110 @@ fn catch_ident(
     }
 }
 
+/// Same as `catch_ident`, for click (and submit) events. Their accessor is
+/// `#[must_use]`: calling it without reading the result is always a mistake.
+fn click_ident(
+    attr: &Attribute,
+    field: &Ident,
+    catch: Option<&Lit>,
+    field_set: &mut HashSet<String>,
+    fields: &mut TokenStream,
+    methods: &mut TokenStream,
+    changed: &mut TokenStream,
+) -> Result<Ident, Error> {
+    let mut method = TokenStream::new();
+    let ident = catch_ident(attr, field, catch, field_set, fields, &mut method, changed)?;
+    if !method.is_empty() {
+        methods.extend(quote!(#[must_use] #method));
+    }
+    Ok(ident)
+}
+
+/// Declares the `<catch>_index` event of a `buttons` grid, next to its `catch`
+/// event: the index of the clicked button (`Option<usize>`, zeroed to `None`).
+fn index_ident(
+    catch: &Ident,
+    field_set: &mut HashSet<String>,
+    fields: &mut TokenStream,
+    methods: &mut TokenStream,
+) -> Ident {
+    let ident = Ident::new(&format!("{}_index", catch), catch.span());
+    if field_set.insert(ident.to_string()) {
+        fields.extend(quote! { pub #ident: Option<usize> , });
+        methods.extend(quote! { pub fn #ident(&self) -> Option<usize> { self.#ident } });
+    }
+    ident
+}
+
+/// Declarations of the events of a field, as taken by `catch_ident`: the names
+/// of the declared events, the fields of the events type, their accessors and
+/// the statements of `widgets_changed`.
+struct EventSinks<'a> {
+    field_set: &'a mut HashSet<String>,
+    fields: &'a mut TokenStream,
+    methods: &'a mut TokenStream,
+    changed: &'a mut TokenStream,
+}
+
+/// Statements that set the `started` and `finished` events of a drag or a
+/// slider, read right after the widget is drawn.
+fn edit_events(
+    attr: &Attribute,
+    field: &Ident,
+    started: Option<&Lit>,
+    finished: Option<&Lit>,
+    sinks: EventSinks,
+) -> Result<TokenStream, Error> {
+    let EventSinks {
+        field_set,
+        fields,
+        methods,
+        changed,
+    } = sinks;
+    if started.is_none() && finished.is_none() {
+        return Ok(TokenStream::new());
+    }
+    let mut tokens = quote! {
+        let (_started, _finished) = imgui_ext::item_edit(ui);
+    };
+    if let Some(started) = started {
+        let started = catch_ident(
+            attr,
+            field,
+            Some(started),
+            field_set,
+            fields,
+            methods,
+            changed,
+        )?;
+        tokens.extend(quote!(events.#started |= _started;));
+    }
+    if let Some(finished) = finished {
+        let finished = catch_ident(
+            attr,
+            field,
+            Some(finished),
+            field_set,
+            fields,
+            methods,
+            changed,
+        )?;
+        tokens.extend(quote!(events.#finished |= _finished;));
+    }
+    Ok(tokens)
+}
+
+/// Statement used by the generated `widgets_changed` method to report a bool
+/// event by name.
+fn catch_changed(ident: &Ident) -> TokenStream {
+    let name = Literal::string(&ident.to_string());
+    quote! {
+        if self.#ident {
+            changed.push(#name);
+        }
+    }
+}
+
 // TODO code repetition bad nono FIXME naw
 fn catch_ident_nested(
     attr: &Attribute,
@@ -1996,3 +5079,901 @@ fn catch_ident_nested(
         _ => return Err(Error::invalid_format(attr.span())),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use proc_macro2::Span;
+
+    #[test]
+    fn swatch_only() {
+        let attr: Attribute = syn::parse_quote!(#[imgui(color(edit(swatch_only)))]);
+        let ident = Ident::new("color", Span::call_site());
+        let ty: Type = syn::parse_quote!([f32; 4]);
+
+        let tags = parse_meta(attr.parse_meta().unwrap()).unwrap();
+        let tokens = tags
+            .iter()
+            .map(|tag| {
+                emmit_tag_tokens(
+                    &ident,
+                    &quote!(ext.color),
+                    &ty,
+                    &attr,
+                    tag,
+                    &mut TokenStream::new(),
+                    &mut TokenStream::new(),
+                    &mut HashSet::new(),
+                    &mut TokenStream::new(),
+                )
+                .unwrap()
+                .to_string()
+            })
+            .collect::<String>();
+
+        let flags = quote!(Some(flags | Flags::NO_INPUTS | Flags::NO_LABEL)).to_string();
+        assert!(tokens.contains(&flags));
+    }
+
+    fn emmit_error(attr: Attribute, ty: Type) -> Option<ErrorKind> {
+        let ident = Ident::new("value", Span::call_site());
+        let tags = parse_attr(&attr).unwrap();
+        emmit_tag_tokens(
+            &ident,
+            &quote!(ext.value),
+            &ty,
+            &attr,
+            &tags[0],
+            &mut TokenStream::new(),
+            &mut TokenStream::new(),
+            &mut HashSet::new(),
+            &mut TokenStream::new(),
+        )
+        .err()
+        .map(|err| err.kind())
+    }
+
+    #[test]
+    fn format_valid() {
+        let float: Type = syn::parse_quote!([f32; 3]);
+        let int: Type = syn::parse_quote!(Option<i32>);
+        let custom: Type = syn::parse_quote!(Meters);
+
+        let attr = syn::parse_quote!(#[imgui(slider(min = 0.0, max = 1.0, format = "%.3f"))]);
+        assert_eq!(None, emmit_error(attr, float.clone()));
+        let attr = syn::parse_quote!(#[imgui(slider(min = 0, max = 9, format = "%d%% done"))]);
+        assert_eq!(None, emmit_error(attr, int.clone()));
+        let attr = syn::parse_quote!(#[imgui(drag(format = "x = %+08.2e"))]);
+        assert_eq!(None, emmit_error(attr, float.clone()));
+        let attr = syn::parse_quote!(#[imgui(drag(format = "%04X"))]);
+        assert_eq!(None, emmit_error(attr, int.clone()));
+        let attr = syn::parse_quote!(#[imgui(drag(format = "%g m"))]);
+        assert_eq!(None, emmit_error(attr, custom));
+        let attr = syn::parse_quote!(#[imgui(drag(format = "%.1f", map = "to_float"))]);
+        assert_eq!(None, emmit_error(attr, int.clone()));
+        let attr = syn::parse_quote!(#[imgui(input(format = "%.2f"))]);
+        assert_eq!(None, emmit_error(attr, float.clone()));
+        let attr = syn::parse_quote!(#[imgui(input(format = "%08X", step = 1))]);
+        assert_eq!(None, emmit_error(attr, int));
+
+        // 64-bit integers need the `ll` length modifier
+        let long: Type = syn::parse_quote!(u64);
+        let attr = syn::parse_quote!(#[imgui(slider(min = 0, max = 9, format = "%llu"))]);
+        assert_eq!(None, emmit_error(attr, long.clone()));
+        let attr = syn::parse_quote!(#[imgui(drag(format = "%08llx"))]);
+        assert_eq!(None, emmit_error(attr, long));
+        let attr = syn::parse_quote!(#[imgui(input(format = "%lld"))]);
+        assert_eq!(None, emmit_error(attr, syn::parse_quote!(Option<i64>)));
+
+        // overlays are drawn as is (`%` is not a conversion)
+        let attr = syn::parse_quote!(#[imgui(progress(overlay = "100%"))]);
+        assert_eq!(None, emmit_error(attr, syn::parse_quote!(f32)));
+        let attr = syn::parse_quote!(#[imgui(plot(lines(overlay = "%d samples")))]);
+        assert_eq!(None, emmit_error(attr, float));
+    }
+
+    #[test]
+    fn format_invalid() {
+        let float: Type = syn::parse_quote!(f32);
+        let int: Type = syn::parse_quote!([u32; 2]);
+        let long: Type = syn::parse_quote!(i64);
+        let size: Type = syn::parse_quote!(usize);
+
+        let cases: Vec<(Attribute, &Type)> = vec![
+            (
+                syn::parse_quote!(#[imgui(slider(min = 0.0, max = 1.0, format = "%d"))]),
+                &float,
+            ),
+            (
+                syn::parse_quote!(#[imgui(slider(min = 0, max = 16, format = "%.02f"))]),
+                &int,
+            ),
+            (
+                syn::parse_quote!(#[imgui(slider(min = 0.0, max = 1.0, format = "%s"))]),
+                &float,
+            ),
+            (
+                syn::parse_quote!(#[imgui(slider(min = 0.0, max = 1.0, format = "none"))]),
+                &float,
+            ),
+            (syn::parse_quote!(#[imgui(drag(format = "%f %f"))]), &float),
+            (syn::parse_quote!(#[imgui(drag(format = "%*f"))]), &float),
+            (syn::parse_quote!(#[imgui(drag(format = "%lld"))]), &int),
+            (syn::parse_quote!(#[imgui(drag(format = "%n"))]), &int),
+            (syn::parse_quote!(#[imgui(drag(format = "100%"))]), &int),
+            (syn::parse_quote!(#[imgui(input(format = "%d"))]), &float),
+            (syn::parse_quote!(#[imgui(drag(format = "%d"))]), &long),
+            (syn::parse_quote!(#[imgui(drag(format = "%u"))]), &size),
+            (syn::parse_quote!(#[imgui(input(format = "%x"))]), &long),
+            (syn::parse_quote!(#[imgui(drag(format = "%ld"))]), &long),
+            (syn::parse_quote!(#[imgui(drag(format = "%llf"))]), &float),
+            (syn::parse_quote!(#[imgui(drag(format = "%lf"))]), &float),
+            (syn::parse_quote!(#[imgui(input(format = "%x %x"))]), &int),
+            (
+                syn::parse_quote!(#[imgui(input(format = "%.3f\0"))]),
+                &float,
+            ),
+            (
+                syn::parse_quote!(#[imgui(progress(overlay = "50%\0"))]),
+                &float,
+            ),
+            (
+                syn::parse_quote!(#[imgui(plot(lines(overlay = "a\0b")))]),
+                &float,
+            ),
+        ];
+        for (attr, ty) in cases {
+            match emmit_error(attr, ty.clone()) {
+                Some(ErrorKind::Printf(_)) => {}
+                other => panic!("expected a format error, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn slider_range() {
+        let range = |attr: Attribute| {
+            desugar(&attr)
+                .map(|attr| attr.tokens.to_string())
+                .map_err(|err| err.kind())
+        };
+
+        let cases: Vec<(Attribute, TokenStream)> = vec![
+            (
+                syn::parse_quote!(#[imgui(slider(0.0..=1.0))]),
+                quote!((slider(min = 0.0, max = 1.0))),
+            ),
+            (
+                syn::parse_quote!(#[imgui(slider(0..10, format = "%d"))]),
+                quote!((slider(min = 0, max = 9, format = "%d"))),
+            ),
+            (
+                syn::parse_quote!(#[imgui(slider(-1.0..=1.0, wrap))]),
+                quote!((slider(min = "-1.0", max = 1.0, wrap))),
+            ),
+            (
+                syn::parse_quote!(#[imgui(slider(-10..0))]),
+                quote!((slider(min = "-10", max = "-1"))),
+            ),
+            (
+                syn::parse_quote!(#[imgui(bullet(slider(0..=4)), slider(1..=2))]),
+                quote!((bullet(slider(min = 0, max = 4)), slider(min = 1, max = 2))),
+            ),
+            // the long form is left untouched
+            (
+                syn::parse_quote!(#[imgui(slider(min = 0.0, max = 1.0))]),
+                quote!((slider(min = 0.0, max = 1.0))),
+            ),
+        ];
+        for (attr, expected) in cases {
+            assert_eq!(Ok(expected.to_string()), range(attr));
+        }
+
+        let attr = syn::parse_quote!(#[imgui(slider(0.0..1.0))]);
+        assert_eq!(Err(ErrorKind::ExclusiveRange), range(attr));
+        let attr = syn::parse_quote!(#[imgui(slider(0..=max))]);
+        assert_eq!(Err(ErrorKind::ParseError), range(attr));
+
+        let int: Type = syn::parse_quote!(i32);
+        let attr = syn::parse_quote!(#[imgui(slider(0..=4, catch = "changed"))]);
+        assert_eq!(None, emmit_error(attr, int));
+    }
+
+    #[test]
+    fn label_path() {
+        let label = |attr: Attribute| desugar(&attr).unwrap().tokens.to_string();
+
+        let attr = syn::parse_quote!(#[imgui(checkbox(label = labels::ENABLED))]);
+        assert_eq!(
+            quote!((checkbox(label(labels::ENABLED)))).to_string(),
+            label(attr)
+        );
+        let attr = syn::parse_quote!(#[imgui(checkbox(label = "Enabled"))]);
+        assert_eq!(
+            quote!((checkbox(label = "Enabled"))).to_string(),
+            label(attr)
+        );
+
+        let ty: Type = syn::parse_quote!(bool);
+        let attr = syn::parse_quote!(#[imgui(checkbox(label = ENABLED))]);
+        assert_eq!(None, emmit_error(attr, ty));
+
+        // a single label, on every tag
+        let parse = |attr: Attribute| parse_attr(&attr).err().map(|error| error.kind());
+        let attr = syn::parse_quote!(#[imgui(checkbox(label = ENABLED, label = "Enabled"))]);
+        assert_eq!(Some(ErrorKind::AlreadyDefined), parse(attr));
+        let attr = syn::parse_quote!(#[imgui(drag(label = "Gain", label = GAIN))]);
+        assert_eq!(Some(ErrorKind::AlreadyDefined), parse(attr));
+        // `tree` only takes literals
+        let attr = syn::parse_quote!(#[imgui(tree(label = LABEL))]);
+        assert_eq!(Some(ErrorKind::InvalidFormat), parse(attr));
+    }
+
+    #[test]
+    fn bounded() {
+        let ty: Type = syn::parse_quote!(Volume);
+
+        let attr = syn::parse_quote!(#[imgui(slider(bounded))]);
+        assert_eq!(None, emmit_error(attr, ty.clone()));
+        let attr = syn::parse_quote!(#[imgui(slider(bounded, max = "0.5"))]);
+        assert_eq!(None, emmit_error(attr, ty.clone()));
+        let attr = syn::parse_quote!(#[imgui(drag(bounded, min = 0.0))]);
+        assert_eq!(None, emmit_error(attr, ty.clone()));
+
+        // both bounds are required without `bounded`
+        let attr = syn::parse_quote!(#[imgui(slider(min = 0.0))]);
+        assert_eq!(
+            Some(ErrorKind::MissingParam("max")),
+            emmit_error(attr, ty.clone())
+        );
+        let attr = syn::parse_quote!(#[imgui(slider(label = "Volume"))]);
+        assert_eq!(Some(ErrorKind::MissingParam("min")), emmit_error(attr, ty));
+    }
+
+    #[test]
+    fn numeric_options() {
+        let float: Type = syn::parse_quote!([f32; 3]);
+        let int: Type = syn::parse_quote!(Option<i32>);
+        let custom: Type = syn::parse_quote!(Meters);
+
+        let attr = syn::parse_quote!(#[imgui(drag(power = 2.0))]);
+        assert_eq!(None, emmit_error(attr, float.clone()));
+        let attr = syn::parse_quote!(#[imgui(drag(power = 2.0))]);
+        assert_eq!(
+            Some(ErrorKind::FloatOnly("power")),
+            emmit_error(attr, int.clone())
+        );
+        let attr = syn::parse_quote!(#[imgui(slider(min = 0, max = 8, power = "2.0"))]);
+        assert_eq!(
+            Some(ErrorKind::FloatOnly("power")),
+            emmit_error(attr, int.clone())
+        );
+        // unknown types (and mapped fields) are not checked
+        let attr = syn::parse_quote!(#[imgui(drag(power = 2.0))]);
+        assert_eq!(None, emmit_error(attr, custom.clone()));
+        let attr = syn::parse_quote!(#[imgui(drag(power = 2.0, map = "as_f32"))]);
+        assert_eq!(None, emmit_error(attr, int.clone()));
+
+        let attr = syn::parse_quote!(#[imgui(drag(min = 0, max = 1.5))]);
+        assert_eq!(
+            Some(ErrorKind::Literal("an integer")),
+            emmit_error(attr, int.clone())
+        );
+        let attr = syn::parse_quote!(#[imgui(slider(min = 0, max = 1))]);
+        assert_eq!(
+            Some(ErrorKind::Literal("a float")),
+            emmit_error(attr, float.clone())
+        );
+        let attr = syn::parse_quote!(#[imgui(slider(min = 0, max = 1))]);
+        assert_eq!(None, emmit_error(attr, custom));
+        // strings are parsed as either kind
+        let attr = syn::parse_quote!(#[imgui(slider(min = "-1", max = 1.0))]);
+        assert_eq!(None, emmit_error(attr, float));
+        let attr = syn::parse_quote!(#[imgui(slider(min = "-180", max = 180))]);
+        assert_eq!(None, emmit_error(attr, int));
+
+        // ranges are checked against the type of their bounds
+        let range: Type = syn::parse_quote!(std::ops::Range<f32>);
+        let attr = syn::parse_quote!(#[imgui(drag(min = 0, max = 1))]);
+        assert_eq!(
+            Some(ErrorKind::Literal("a float")),
+            emmit_error(attr, range.clone())
+        );
+        let attr = syn::parse_quote!(#[imgui(drag(min = 0.0, power = 2.0))]);
+        assert_eq!(None, emmit_error(attr, range));
+    }
+
+    #[test]
+    fn input_arity() {
+        let types: [(usize, Type); 3] = [
+            (2, syn::parse_quote!([f32; 2])),
+            (3, syn::parse_quote!([f32; 3])),
+            (4, syn::parse_quote!(&'a mut [f32; 4])),
+        ];
+        for (n, ty) in types.iter() {
+            let n = *n;
+            let name = Ident::new(&format!("input{}", n), Span::call_site());
+            let attr = syn::parse_quote!(#[imgui(#name)]);
+            assert_eq!(None, emmit_error(attr, ty.clone()));
+
+            let attr = syn::parse_quote!(#[imgui(#name(step = 0.1))]);
+            let wrong: Type = syn::parse_quote!([f32; 5]);
+            assert_eq!(Some(ErrorKind::Arity(n)), emmit_error(attr, wrong));
+
+            let attr = syn::parse_quote!(#[imgui(#name)]);
+            let wrong: Type = syn::parse_quote!([i32; #n]);
+            assert_eq!(Some(ErrorKind::Arity(n)), emmit_error(attr, wrong));
+        }
+    }
+
+    #[test]
+    fn id_offset() {
+        let attr: Attribute = syn::parse_quote!(#[imgui(slider(min = 0, max = 4, id_offset = 3))]);
+        let ident = Ident::new("level", Span::call_site());
+        let ty: Type = syn::parse_quote!(i32);
+
+        let tags = parse_meta(attr.parse_meta().unwrap()).unwrap();
+        let tokens = emmit_tag_tokens(
+            &ident,
+            &quote!(ext.level),
+            &ty,
+            &attr,
+            &tags[0],
+            &mut TokenStream::new(),
+            &mut TokenStream::new(),
+            &mut HashSet::new(),
+            &mut TokenStream::new(),
+        )
+        .unwrap()
+        .to_string();
+
+        let push = quote!(let _id = ui.push_id(3);).to_string();
+        let pop = quote!(_id.pop(ui);).to_string();
+        assert!(tokens.starts_with(&format!("{{ {}", push)));
+        assert!(tokens.ends_with(&format!("{} }}", pop)));
+    }
+
+    #[test]
+    fn buttons_cols() {
+        let ty: Type = syn::parse_quote!([ImString; 9]);
+        let attr = syn::parse_quote!(#[imgui(buttons(cols = 3))]);
+        assert_eq!(None, emmit_error(attr, ty.clone()));
+        let attr = syn::parse_quote!(#[imgui(buttons(cols = 0))]);
+        assert_eq!(
+            Some(ErrorKind::InvalidFormat),
+            emmit_error(attr, ty.clone())
+        );
+        let attr = syn::parse_quote!(#[imgui(buttons(cols = "3"))]);
+        assert_eq!(
+            Some(ErrorKind::InvalidFormat),
+            emmit_error(attr, ty.clone())
+        );
+        let attr: Attribute = syn::parse_quote!(#[imgui(buttons(size = "[32.0, 32.0]"))]);
+        assert_eq!(
+            Some(ErrorKind::MissingParam("cols")),
+            parse_attr(&attr).err().map(|e| e.kind())
+        );
+        let attr: Attribute = syn::parse_quote!(#[imgui(buttons)]);
+        assert_eq!(
+            Some(ErrorKind::MissingParam("cols")),
+            parse_attr(&attr).err().map(|e| e.kind())
+        );
+    }
+
+    #[test]
+    fn buttons_index() {
+        let ident = Ident::new("keys", Span::call_site());
+        let ty: Type = syn::parse_quote!([ImString; 9]);
+        let attr: Attribute = syn::parse_quote!(#[imgui(buttons(cols = 3, catch = "key"))]);
+        let tags = parse_attr(&attr).unwrap();
+        let mut fields = TokenStream::new();
+        let mut methods = TokenStream::new();
+        emmit_tag_tokens(
+            &ident,
+            &quote!(ext.keys),
+            &ty,
+            &attr,
+            &tags[0],
+            &mut fields,
+            &mut methods,
+            &mut HashSet::new(),
+            &mut TokenStream::new(),
+        )
+        .unwrap();
+        assert!(fields
+            .to_string()
+            .contains("pub key_index : Option < usize >"));
+        assert!(methods
+            .to_string()
+            .contains("pub fn key_index (& self) -> Option < usize >"));
+    }
+
+    #[test]
+    fn frame_padding() {
+        let ident = Ident::new("volume", Span::call_site());
+        let ty: Type = syn::parse_quote!(f32);
+        let emmit = |attr: Attribute| {
+            let tags = parse_attr(&attr).unwrap();
+            emmit_tag_tokens(
+                &ident,
+                &quote!(ext.volume),
+                &ty,
+                &attr,
+                &tags[0],
+                &mut TokenStream::new(),
+                &mut TokenStream::new(),
+                &mut HashSet::new(),
+                &mut TokenStream::new(),
+            )
+            .map(|tokens| tokens.to_string())
+            .map_err(|error| error.kind())
+        };
+
+        let push = quote!(ui.push_style_var(imgui::StyleVar::FramePadding([8f32, 2f32])));
+        let pop = quote!(_padding.pop(ui);).to_string();
+        let slider = quote!(Slider::build).to_string();
+
+        // a single push, popped right after the widget
+        let tokens = emmit(syn::parse_quote!(
+            #[imgui(slider(min = 0.0, max = 1.0, frame_padding = "[8.0, 2]", same_line))]
+        ))
+        .unwrap();
+        let push = push.to_string();
+        assert_eq!(1, tokens.matches(&push).count());
+        assert_eq!(1, tokens.matches(&pop).count());
+        assert!(tokens.find(&push).unwrap() < tokens.find(&slider).unwrap());
+        assert!(tokens.find(&slider).unwrap() < tokens.find(&pop).unwrap());
+
+        let tokens = emmit(syn::parse_quote!(#[imgui(slider(min = 0.0, max = 1.0))])).unwrap();
+        assert!(!tokens.contains(&pop));
+
+        for padding in &["[8.0]", "[1.0, 2.0, 3.0]", "8.0, 2.0", "[-1.0, 2.0]"] {
+            let attr = syn::parse_quote!(#[imgui(checkbox(frame_padding = #padding))]);
+            assert_eq!(Err(ErrorKind::InvalidFormat), emmit(attr));
+        }
+        let attr = syn::parse_quote!(#[imgui(checkbox(frame_padding = "[x, 2.0]"))]);
+        assert_eq!(Err(ErrorKind::ParseError), emmit(attr));
+
+        // the padding of the image is `image_padding`
+        let attr = syn::parse_quote!(#[imgui(image_button(size = "[24.0, 24.0]", frame_padding = "[8.0, 2.0]"))]);
+        let tokens = emmit(attr).unwrap();
+        assert_eq!(1, tokens.matches(&push).count());
+        let attr =
+            syn::parse_quote!(#[imgui(image_button(size = "[24.0, 24.0]", frame_padding = 2))]);
+        assert_eq!(Err(ErrorKind::InvalidFormat), emmit(attr));
+        let attr =
+            syn::parse_quote!(#[imgui(image_button(size = "[24.0, 24.0]", image_padding = 2))]);
+        assert!(!emmit(attr).unwrap().contains(&pop));
+    }
+
+    #[test]
+    fn plot_stride() {
+        let ident = Ident::new("samples", Span::call_site());
+        let ty: Type = syn::parse_quote!(Vec<f32>);
+        let emmit = |attr: Attribute| {
+            let tags = parse_attr(&attr).unwrap();
+            emmit_tag_tokens(
+                &ident,
+                &quote!(ext.samples),
+                &ty,
+                &attr,
+                &tags[0],
+                &mut TokenStream::new(),
+                &mut TokenStream::new(),
+                &mut HashSet::new(),
+                &mut TokenStream::new(),
+            )
+            .map(|_| ())
+            .map_err(|error| error.kind())
+        };
+
+        assert_eq!(
+            Ok(()),
+            emmit(syn::parse_quote!(#[imgui(plot(lines(stride = 4)))]))
+        );
+        assert_eq!(
+            Ok(()),
+            emmit(syn::parse_quote!(#[imgui(plot(histogram(stride = 8)))]))
+        );
+        // smaller than a value, or misaligned
+        for stride in &[0usize, 2, 6, 9] {
+            let stride = Literal::usize_unsuffixed(*stride);
+            let attr = syn::parse_quote!(#[imgui(plot(lines(stride = #stride)))]);
+            assert_eq!(Err(ErrorKind::InvalidFormat), emmit(attr));
+        }
+    }
+
+    #[test]
+    fn reset_to() {
+        let ident = Ident::new("gain", Span::call_site());
+        let ty: Type = syn::parse_quote!(f32);
+        let emmit = |attr: Attribute| {
+            let tags = parse_attr(&attr).unwrap();
+            emmit_tag_tokens(
+                &ident,
+                &quote!(ext.gain),
+                &ty,
+                &attr,
+                &tags[0],
+                &mut TokenStream::new(),
+                &mut TokenStream::new(),
+                &mut HashSet::new(),
+                &mut TokenStream::new(),
+            )
+            .map(|tokens| tokens.to_string())
+            .map_err(|error| error.kind())
+        };
+
+        let button = quote!(imgui_ext::reset_button(ui, "gain", 0)).to_string();
+        let value = quote!(|| 100.0).to_string();
+
+        // the button is drawn after the widget
+        let attr = syn::parse_quote!(#[imgui(slider(min = 0.0, max = 200.0, reset_to = "100.0"))]);
+        let tokens = emmit(attr).unwrap();
+        assert!(
+            tokens.find(&quote!(Slider::build).to_string()).unwrap()
+                < tokens.find(&button).unwrap()
+        );
+        assert!(tokens.contains(&value));
+
+        let attr = syn::parse_quote!(#[imgui(drag(reset_to = "100.0"))]);
+        let tokens = emmit(attr).unwrap();
+        assert!(
+            tokens.find(&quote!(Drag::build).to_string()).unwrap() < tokens.find(&button).unwrap()
+        );
+
+        // double clicks reset to the same value
+        let attr = syn::parse_quote!(#[imgui(drag(reset_on_dclick, reset_to = "100.0"))]);
+        let tokens = emmit(attr).unwrap();
+        assert_eq!(2, tokens.matches(&value).count());
+        assert!(!tokens.contains("reset_on_double_click"));
+
+        // the ID of the button includes the `id_offset` of the widget
+        let attr = syn::parse_quote!(#[imgui(drag(reset_to = "100.0", id_offset = 3))]);
+        let tokens = emmit(attr).unwrap();
+        let button = quote!(imgui_ext::reset_button(ui, "gain", 3)).to_string();
+        assert!(tokens.contains(&button));
+
+        let attr = syn::parse_quote!(#[imgui(drag(reset_to = ""))]);
+        assert_eq!(Err(ErrorKind::InvalidFormat), emmit(attr));
+        let attr = syn::parse_quote!(#[imgui(drag(reset_to = 100.0))]);
+        assert_eq!(Err(ErrorKind::InvalidFormat), emmit(attr));
+        let attr = syn::parse_quote!(#[imgui(slider(min = 0.0, max = 1.0, reset_to = "(1.0"))]);
+        assert_eq!(Err(ErrorKind::ParseError), emmit(attr));
+    }
+
+    #[test]
+    fn align() {
+        let ident = Ident::new("volume", Span::call_site());
+        let ty: Type = syn::parse_quote!(f32);
+        let emmit = |attr: Attribute| {
+            let tags = parse_meta(attr.parse_meta().unwrap()).unwrap();
+            emmit_tag_tokens(
+                &ident,
+                &quote!(ext.volume),
+                &ty,
+                &attr,
+                &tags[0],
+                &mut TokenStream::new(),
+                &mut TokenStream::new(),
+                &mut HashSet::new(),
+                &mut TokenStream::new(),
+            )
+            .unwrap()
+            .to_string()
+        };
+
+        let align = quote!(ui.align_text_to_frame_padding();).to_string();
+        let text = quote!(ui.text(imgui::im_str!("volume"));).to_string();
+
+        // before the inline label, after `same_line`
+        let tokens = emmit(
+            syn::parse_quote!(#[imgui(slider(min = 0.0, max = 1.0, align, label_inline, same_line))]),
+        );
+        let same_line = quote!(ui.same_line(0.0);).to_string();
+        let align_at = tokens.find(&align).unwrap();
+        assert!(tokens.find(&same_line).unwrap() < align_at);
+        assert!(align_at < tokens.find(&text).unwrap());
+
+        let tokens = emmit(syn::parse_quote!(#[imgui(drag(align))]));
+        assert!(tokens.contains(&align));
+        let tokens = emmit(syn::parse_quote!(#[imgui(drag)]));
+        assert!(!tokens.contains(&align));
+    }
+
+    #[test]
+    fn atomic_ordering() {
+        let kind = |attr: Attribute| parse_attr(&attr).err().map(|error| error.kind());
+
+        assert_eq!(None, kind(syn::parse_quote!(#[imgui(atomic, checkbox)])));
+        assert_eq!(
+            None,
+            kind(syn::parse_quote!(#[imgui(atomic = "AcqRel", checkbox)]))
+        );
+        assert_eq!(
+            Some(ErrorKind::Suggestion("SeqCst")),
+            kind(syn::parse_quote!(#[imgui(atomic = "SeqCts", checkbox)]))
+        );
+        assert_eq!(
+            Some(ErrorKind::InvalidFormat),
+            kind(syn::parse_quote!(#[imgui(atomic = 1, checkbox)]))
+        );
+    }
+
+    #[test]
+    fn label_inline() {
+        let attr: Attribute = syn::parse_quote!(#[imgui(slider(label_inline))]);
+        let ident = Ident::new("volume", Span::call_site());
+        let label = Lit::Str(syn::LitStr::new("Volume", Span::call_site()));
+
+        let tokens = emmit_label(&attr, &ident, Some(&label), None, true).unwrap();
+        let expected = quote!({
+            ui.text(imgui::im_str!("Volume"));
+            ui.same_line(0.0);
+            imgui::im_str!("##volume")
+        });
+        assert_eq!(expected.to_string(), tokens.to_string());
+
+        let tokens = emmit_label(&attr, &ident, Some(&label), None, false).unwrap();
+        assert_eq!(
+            quote!(imgui::im_str!("Volume")).to_string(),
+            tokens.to_string()
+        );
+    }
+
+    #[test]
+    fn doc_tooltips() {
+        let car: syn::DeriveInput = syn::parse_quote! {
+            struct Car {
+                /// Speed of the car.
+                ///
+                /// In meters per second.
+                #[imgui(slider(min = 0.0, max = 1.0))]
+                speed: f32,
+                #[imgui]
+                name: String,
+            }
+        };
+        let fields = match car.data {
+            syn::Data::Struct(body) => body.fields,
+            _ => unreachable!(),
+        };
+        let mut fields = fields.iter();
+        let doc = doc_text(&fields.next().unwrap().attrs).unwrap();
+        assert_eq!("Speed of the car.\n\nIn meters per second.", doc);
+
+        let tokens = emmit_doc_tooltip(&doc).to_string();
+        assert!(tokens.contains("is_item_hovered"));
+        assert!(tokens.contains("tooltip_text"));
+
+        assert_eq!(None, doc_text(&fields.next().unwrap().attrs));
+
+        let attr = syn::parse_quote!(#[imgui(patch, doc_tooltips)]);
+        assert!(Options::from_attrs(&[attr]).unwrap().doc_tooltips);
+    }
+
+    #[test]
+    fn phantom() {
+        let phantom: Type = syn::parse_quote!(std::marker::PhantomData<()>);
+
+        let attr = syn::parse_quote!(#[imgui(separator, text("Header"))]);
+        assert_eq!(None, emmit_error(attr, phantom.clone()));
+        let attr = syn::parse_quote!(#[imgui(button(label = "Save"))]);
+        assert_eq!(None, emmit_error(attr, phantom.clone()));
+
+        let attr = syn::parse_quote!(#[imgui(checkbox)]);
+        assert_eq!(Some(ErrorKind::Phantom), emmit_error(attr, phantom.clone()));
+        let attr = syn::parse_quote!(#[imgui(text())]);
+        assert_eq!(Some(ErrorKind::Phantom), emmit_error(attr, phantom));
+    }
+
+    /// String literals passed to `im_str!` in `tokens`.
+    fn im_str_literals(tokens: TokenStream) -> Vec<String> {
+        let mut literals = Vec::new();
+        for tree in tokens {
+            match tree {
+                proc_macro2::TokenTree::Group(group) => {
+                    literals.extend(im_str_literals(group.stream()))
+                }
+                proc_macro2::TokenTree::Literal(lit) => {
+                    if let Ok(Lit::Str(lit)) = syn::parse_str::<Lit>(&lit.to_string()) {
+                        literals.push(lit.value());
+                    }
+                }
+                _ => {}
+            }
+        }
+        literals
+    }
+
+    #[test]
+    fn label_utf8() {
+        let ident = Ident::new("save", Span::call_site());
+        let ty: Type = syn::parse_quote!(bool);
+        let glyph = "\u{f0c7} Guardar ✓";
+
+        let attrs: Vec<Attribute> = vec![
+            syn::parse_quote!(#[imgui(button(label = "\u{f0c7} Guardar ✓"))]),
+            syn::parse_quote!(#[imgui(checkbox(label = "\u{f0c7} Guardar ✓"))]),
+            syn::parse_quote!(#[imgui(checkbox(label = "\u{f0c7} Guardar ✓", label_inline))]),
+            syn::parse_quote!(#[imgui(text("\u{f0c7} Guardar ✓"))]),
+        ];
+        for attr in attrs {
+            let tags = parse_meta(attr.parse_meta().unwrap()).unwrap();
+            let tokens = emmit_tag_tokens(
+                &ident,
+                &quote!(ext.save),
+                &ty,
+                &attr,
+                &tags[0],
+                &mut TokenStream::new(),
+                &mut TokenStream::new(),
+                &mut HashSet::new(),
+                &mut TokenStream::new(),
+            )
+            .unwrap();
+            assert!(im_str_literals(tokens).iter().any(|lit| lit == glyph));
+        }
+    }
+
+    #[test]
+    fn qualified_im_str() {
+        // the deriving module doesn't need to import `im_str!`
+        let input: syn::DeriveInput = syn::parse_quote! {
+            struct Test {
+                #[imgui(text("Header"), bullet(text = "Item"), slider(min = 0.0, max = 1.0, format = "%.1f"))]
+                slider: f32,
+                #[imgui(drag(format = "%.3f", labels = "[\"X\", \"Y\"]"))]
+                drag: [f32; 2],
+                #[imgui(progress(overlay = "Loading"), display)]
+                progress: f32,
+                #[imgui(combobox)]
+                items: [imgui::ImString; 2],
+            }
+        };
+        let expanded = crate::impl_derive(&input).unwrap().to_string();
+        assert!(expanded.contains("im_str !"));
+        assert_eq!(
+            expanded.matches("im_str !").count(),
+            expanded.matches("imgui :: im_str !").count()
+        );
+        assert!(!expanded.contains("use imgui :: im_str"));
+    }
+
+    #[test]
+    fn disabled_if() {
+        let unit: Type = syn::parse_quote!(());
+
+        let attr = syn::parse_quote!(#[imgui(button(label = "Save", disabled_if = "saved"))]);
+        assert_eq!(None, emmit_error(attr, unit.clone()));
+        let attr = syn::parse_quote!(#[imgui(button(label = "Undo", disabled_if = "!can_undo"))]);
+        assert_eq!(None, emmit_error(attr, unit.clone()));
+        let attr = syn::parse_quote!(#[imgui(button(label = "Save", disabled_if = "a || b"))]);
+        assert_eq!(Some(ErrorKind::ParseError), emmit_error(attr, unit.clone()));
+        let attr = syn::parse_quote!(#[imgui(button(label = "Save", disabled_if = true))]);
+        assert_eq!(Some(ErrorKind::InvalidFormat), emmit_error(attr, unit));
+
+        // edits are discarded by restoring a copy of the field
+        let attr = syn::parse_quote!(#[imgui(checkbox(disabled_if = "locked"))]);
+        let tags = parse_attr(&attr).unwrap();
+        let ident = Ident::new("vsync", Span::call_site());
+        let ty: Type = syn::parse_quote!(bool);
+        let tokens = emmit_tag_tokens(
+            &ident,
+            &quote!(ext.vsync),
+            &ty,
+            &attr,
+            &tags[0],
+            &mut TokenStream::new(),
+            &mut TokenStream::new(),
+            &mut HashSet::new(),
+            &mut TokenStream::new(),
+        )
+        .unwrap()
+        .to_string();
+        let restore = quote!(ext.vsync = _restore;).to_string();
+        assert!(tokens.contains(&restore));
+
+        // callbacks are not called while disabled
+        let attr = syn::parse_quote!(
+            #[imgui(buttons(cols = 3, on_click = "Keypad::press", disabled_if = "locked"))]
+        );
+        let tags = parse_attr(&attr).unwrap();
+        let ident = Ident::new("keys", Span::call_site());
+        let ty: Type = syn::parse_quote!(Vec<ImString>);
+        let tokens = emmit_tag_tokens(
+            &ident,
+            &quote!(ext.keys),
+            &ty,
+            &attr,
+            &tags[0],
+            &mut TokenStream::new(),
+            &mut TokenStream::new(),
+            &mut HashSet::new(),
+            &mut TokenStream::new(),
+        )
+        .unwrap()
+        .to_string();
+        let enabled = quote!(if !_disabled.is_disabled()).to_string();
+        let on_click = quote!(Keypad::press(_index, ext)).to_string();
+        assert!(tokens.find(&enabled).unwrap() < tokens.find(&on_click).unwrap());
+    }
+
+    #[test]
+    fn hidden_fn() {
+        let ty: Type = syn::parse_quote!(f32);
+
+        let attr = syn::parse_quote!(#[imgui(drag(hidden_fn = "Light::is_off"))]);
+        assert_eq!(None, emmit_error(attr, ty.clone()));
+        let attr = syn::parse_quote!(#[imgui(drag(hidden_fn = "!is_off"))]);
+        assert_eq!(Some(ErrorKind::ParseError), emmit_error(attr, ty.clone()));
+        let attr = syn::parse_quote!(#[imgui(drag(hidden_fn = true))]);
+        assert_eq!(Some(ErrorKind::InvalidFormat), emmit_error(attr, ty));
+        let attr = syn::parse_quote!(#[imgui(drag(hidden_fn = "a", hidden_fn = "b"))]);
+        let kind = parse_attr(&attr).err().map(|error| error.kind());
+        assert_eq!(Some(ErrorKind::AlreadyDefined), kind);
+    }
+
+    #[test]
+    fn no_events() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            struct Header {
+                #[imgui(text("Settings"), separator)]
+                title: (),
+                #[imgui(display(label = "Version"))]
+                version: u32,
+            }
+        };
+        let expanded = crate::impl_derive(&input).unwrap().to_string();
+        assert!(expanded.contains("type Events = ()"));
+        assert!(!expanded.contains("__Header_Events"));
+
+        let input: syn::DeriveInput = syn::parse_quote! {
+            struct Header {
+                #[imgui(text("Settings"), checkbox)]
+                visible: bool,
+            }
+        };
+        let expanded = crate::impl_derive(&input).unwrap().to_string();
+        assert!(expanded.contains("type Events = __Header_Events"));
+    }
+
+    #[test]
+    fn accessors() {
+        let phantom: Type = syn::parse_quote!(std::marker::PhantomData<()>);
+
+        let attr =
+            syn::parse_quote!(#[imgui(slider(min = 0.0, max = 1.0, get = "get", set = "set"))]);
+        assert_eq!(None, emmit_error(attr, phantom.clone()));
+        let attr = syn::parse_quote!(#[imgui(input3(get = "get", set = "set"))]);
+        assert_eq!(None, emmit_error(attr, phantom.clone()));
+        let attr = syn::parse_quote!(#[imgui(drag(get = "get"))]);
+        assert_eq!(
+            Some(ErrorKind::MissingParam("set")),
+            emmit_error(attr, phantom.clone())
+        );
+        let attr = syn::parse_quote!(#[imgui(drag)]);
+        assert_eq!(Some(ErrorKind::Phantom), emmit_error(attr, phantom));
+    }
+
+    #[test]
+    fn must_use_clicks() {
+        let input: syn::DeriveInput = syn::parse_quote! {
+            struct Toolbar {
+                #[imgui(button(label = "Save", catch = "save"))]
+                save: (),
+                #[imgui(input(enter_returns_true))]
+                search: String,
+                #[imgui(slider(min = 0.0, max = 1.0))]
+                zoom: f32,
+            }
+        };
+        let expanded = crate::impl_derive(&input).unwrap().to_string();
+        assert!(expanded.contains("# [must_use] pub fn save"));
+        assert!(expanded.contains("# [must_use] # [inline (always)] pub fn search"));
+        // only clicks, other events are often ignored on purpose
+        assert!(expanded.contains("# [inline (always)] pub fn zoom"));
+        assert_eq!(2, expanded.matches("must_use").count());
+    }
+}
@@ -0,0 +1,284 @@
+//! Snapshot tests of the code generated by the derive macro.
+//!
+//! Each test expands a representative struct and compares the (pretty printed)
+//! output against a file in the `snapshots/` directory of this crate. When the
+//! generated code changes on purpose, regenerate the files with:
+//!
+//! ```text
+//! IMGUI_EXT_SNAPSHOTS=overwrite cargo test -p imgui-ext-derive
+//! ```
+//!
+//! and review the diff before committing it.
+use std::fs;
+use std::path::PathBuf;
+
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+use syn::DeriveInput;
+
+/// Expands `input` and compares it against the `snapshots/<name>.rs` file.
+pub fn assert_snapshot(name: &str, input: DeriveInput) {
+    let tokens = match crate::impl_derive(&input) {
+        Ok(tokens) => tokens,
+        Err(error) => panic!("{}: derive failed: {}", name, error.kind()),
+    };
+    let expanded = render(tokens);
+
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(format!("{}.rs", name));
+
+    let overwrite = std::env::var("IMGUI_EXT_SNAPSHOTS")
+        .map(|var| var == "overwrite")
+        .unwrap_or(false);
+    if overwrite {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &expanded).unwrap();
+        return;
+    }
+
+    let snapshot = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "{}: missing snapshot {}. Run with IMGUI_EXT_SNAPSHOTS=overwrite to create it.",
+            name,
+            path.display()
+        )
+    });
+    assert!(
+        snapshot == expanded,
+        "{}: generated code doesn't match {}\n\n{}",
+        name,
+        path.display(),
+        expanded
+    );
+}
+
+/// Prints `tokens` with one statement per line, and indented blocks, so the
+/// snapshots produce readable diffs.
+fn render(tokens: TokenStream) -> String {
+    let mut out = String::new();
+    render_stream(tokens, 0, &mut out);
+    new_line(0, &mut out);
+    out.trim_start().to_string()
+}
+
+fn render_stream(tokens: TokenStream, depth: usize, out: &mut String) {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut hash = false;
+    while let Some(tree) = tokens.next() {
+        let attribute = hash;
+        hash = false;
+        match tree {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                out.push('{');
+                new_line(depth + 1, out);
+                render_stream(group.stream(), depth + 1, out);
+                new_line(depth, out);
+                out.push('}');
+                // keep `})`, `};`, `},` and `}.` on the same line
+                match tokens.peek() {
+                    None => {}
+                    Some(TokenTree::Punct(p)) if ";,.?".contains(p.as_char()) => {}
+                    Some(_) => new_line(depth, out),
+                }
+            }
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    _ => ("", ""),
+                };
+                out.push_str(open);
+                render_stream(group.stream(), depth, out);
+                trim_end(out);
+                out.push_str(close);
+                out.push(' ');
+                // attributes (`#[...]`) go on their own line
+                if attribute && group.delimiter() == Delimiter::Bracket {
+                    new_line(depth, out);
+                }
+            }
+            TokenTree::Punct(punct) => {
+                hash = punct.as_char() == '#';
+                out.push(punct.as_char());
+                if punct.as_char() == ';' {
+                    new_line(depth, out);
+                } else if punct.spacing() == Spacing::Alone {
+                    out.push(' ');
+                }
+            }
+            tree => {
+                out.push_str(&tree.to_string());
+                out.push(' ');
+            }
+        }
+    }
+}
+
+/// Starts a new line, unless the current one is already empty.
+fn new_line(depth: usize, out: &mut String) {
+    trim_end(out);
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+    // indentation of an empty line is replaced by the next call
+    out.push_str(&"    ".repeat(depth));
+}
+
+fn trim_end(out: &mut String) {
+    let len = out.trim_end_matches(' ').len();
+    out.truncate(len);
+}
+
+#[test]
+fn slider() {
+    assert_snapshot(
+        "slider",
+        syn::parse_quote! {
+            struct Slider {
+                #[imgui(slider(min = 0.0, max = 1.0, keyboard_step = 0.1))]
+                a: f32,
+                #[imgui(slider(min = 0, max = 8, format = "%d", catch = "b_changed"))]
+                b: [i32; 2],
+                #[imgui(slider(min = 20.0, max = 20000.0, logarithmic))]
+                c: f32,
+                #[imgui(slider(min = 0.0, max = 1024.0, format_value = "bytes"))]
+                d: f32,
+            }
+        },
+    );
+}
+
+#[test]
+fn drag() {
+    assert_snapshot(
+        "drag",
+        syn::parse_quote! {
+            struct Drag {
+                #[imgui(drag)]
+                a: f32,
+                #[imgui(drag(speed = 0.1, min = 0.0, same_line))]
+                b: [f32; 3],
+                #[imgui(drag(reset_on_dclick, catch = "reset"))]
+                c: f32,
+                #[imgui(drag(started = "grab", finished = "release"))]
+                d: f32,
+            }
+        },
+    );
+}
+
+#[test]
+fn input() {
+    assert_snapshot(
+        "input",
+        syn::parse_quote! {
+            struct Input {
+                #[imgui(input(label = "Name"))]
+                a: imgui::ImString,
+                #[imgui(input3(step = 1.0))]
+                b: [f32; 3],
+            }
+        },
+    );
+}
+
+#[test]
+fn combobox() {
+    assert_snapshot(
+        "combobox",
+        syn::parse_quote! {
+            struct Fruits {
+                #[imgui(combobox(typeahead))]
+                a: [imgui::ImString; 3],
+                #[imgui(combobox(selected = "1", on_select = "Fruits::select"))]
+                b: [imgui::ImString; 3],
+                #[imgui(combobox(icons = "icons", sections = "sections"))]
+                c: Vec<imgui::ImString>,
+                icons: Vec<imgui::ImString>,
+                sections: Vec<Option<imgui::ImString>>,
+            }
+        },
+    );
+}
+
+#[test]
+fn tree() {
+    // the node contents go inside of the closure, which is only called while
+    // the node is open
+    assert_snapshot(
+        "tree",
+        syn::parse_quote! {
+            struct Tree {
+                #[imgui(tree(label = "Inner", node(nested)))]
+                inner: Inner,
+                #[imgui(tree(cond = "FirstUseEver", node(drag(map = "as_f32"))))]
+                value: i32,
+            }
+        },
+    );
+}
+
+#[test]
+fn foreign_attributes() {
+    // only `imgui` attributes are read, the rest are left to other derives
+    assert_snapshot(
+        "foreign_attributes",
+        syn::parse_quote! {
+            #[serde(rename_all = "camelCase")]
+            struct Settings {
+                #[serde(default)]
+                #[imgui(checkbox)]
+                vsync: bool,
+                #[imgui(skip)]
+                #[serde(skip)]
+                cache: Vec<u8>,
+                #[serde(skip)]
+                dirty: bool,
+            }
+        },
+    );
+}
+
+#[test]
+fn struct_tree() {
+    // the whole body goes inside of the node
+    assert_snapshot(
+        "struct_tree",
+        syn::parse_quote! {
+            #[imgui(tree)]
+            struct Light {
+                #[imgui(slider(min = 0.0, max = 10.0))]
+                intensity: f32,
+            }
+        },
+    );
+}
+
+#[test]
+fn variants() {
+    // field-less enums are edited with a slider over their discriminants
+    assert_snapshot(
+        "variants",
+        syn::parse_quote! {
+            #[repr(i32)]
+            enum Quality {
+                Low,
+                Medium = 4,
+                High = 8,
+            }
+        },
+    );
+}
+
+#[test]
+fn buttons() {
+    assert_snapshot(
+        "buttons",
+        syn::parse_quote! {
+            struct Keypad {
+                #[imgui(buttons(cols = 3, size = "[32.0, 32.0]", on_click = "Keypad::press"))]
+                keys: [imgui::ImString; 9],
+            }
+        },
+    );
+}
@@ -0,0 +1,100 @@
+//! `buttons(...)` draws a grid of buttons, one per label of a `[ImString; N]`
+//! or `Vec<ImString>` field (or any slice of labels).
+//!
+//! The buttons are laid out in rows of `cols` buttons, left to right. The
+//! last row has the remaining buttons, if there are fewer than `cols` left.
+//!
+//! ## Fields
+//!
+//! * `cols` number of columns of the grid.
+//!
+//! ## Optional fields
+//!
+//! * `size` path to a function that returns the size of every button, or an
+//!   array literal (same as in [`button(...)`](../button/index.html)). By
+//!   default, each button fits its label.
+//! * `catch` name of the event, triggered when any of the buttons is clicked.
+//!   The index of the clicked button is reported by the `<catch>_index` event
+//!   (`keys_index()` for a `keys` field without `catch`), as an
+//!   `Option<usize>`.
+//! * `on_click` path to a function called with the index of the clicked
+//!   button, and the annotated struct: `fn(usize, &mut Self)`.
+//!
+//! ## Example
+//!
+//! ```
+//! use imgui::ImString;
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Keypad {
+//!     #[imgui(display)]
+//!     code: String,
+//!     // "1" to "9", in a 3x3 grid
+//!     #[imgui(buttons(cols = 3, size = "[32.0, 32.0]", on_click = "Keypad::press"))]
+//!     keys: [ImString; 9],
+//! }
+//!
+//! impl Keypad {
+//!     fn press(index: usize, keypad: &mut Keypad) {
+//!         keypad.code.push_str(keypad.keys[index].to_str());
+//!     }
+//! }
+//! ```
+use imgui::{ImStr, Ui};
+
+/// Structure generated by the `buttons` annotation.
+pub struct ButtonsParams {
+    pub cols: usize,
+    pub size: [f32; 2],
+}
+
+/// Whether the button at `index` goes on the same line as the previous one,
+/// in a grid of `cols` columns.
+fn same_row(index: usize, cols: usize) -> bool {
+    index % cols.max(1) != 0
+}
+
+/// Draws a button per label. Returns the index of the clicked button, if any.
+pub fn build<S: AsRef<ImStr>>(ui: &Ui, labels: &[S], params: ButtonsParams) -> Option<usize> {
+    let mut clicked = None;
+    for (index, label) in labels.iter().enumerate() {
+        if same_row(index, params.cols) {
+            ui.same_line(0.0);
+        }
+        // labels are not required to be unique
+        let id = ui.push_id(index as i32);
+        if ui.button(label.as_ref(), params.size) {
+            clicked = Some(index);
+        }
+        id.pop(ui);
+    }
+    clicked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::same_row;
+
+    #[test]
+    fn rows() {
+        let rows = |len, cols| {
+            let mut rows: Vec<usize> = Vec::new();
+            for index in 0..len {
+                match rows.last_mut() {
+                    Some(row) if same_row(index, cols) => *row += 1,
+                    _ => rows.push(1),
+                }
+            }
+            rows
+        };
+
+        assert_eq!(vec![3, 3, 3], rows(9, 3));
+        // the last row is partial
+        assert_eq!(vec![3, 3, 3, 1], rows(10, 3));
+        assert_eq!(vec![2], rows(2, 3));
+        // one button per row
+        assert_eq!(vec![1, 1], rows(2, 1));
+        assert_eq!(vec![1, 1], rows(2, 0));
+        assert!(rows(0, 3).is_empty());
+    }
+}
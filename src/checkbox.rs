@@ -1,9 +1,13 @@
 //! ## Optional fields
 //!
-//! * `label` override widget label.
+//! * `label` override widget label (a string literal, or the path of a
+//!   `&'static str` constant).
+//! * `label_inline` render the label before the widget, instead of after it.
 //! * `catch`
 //! * `map` Applies a mapping function to `&mut Self` (works the same as in the
 //!   [input example](../input/index.html#mapping))
+//! * `toggled` Name of an extra event that is only triggered on the frame the
+//!   value flips. Requires `Self: Clone + PartialEq`.
 //!
 //! ## Example
 //!
@@ -17,6 +21,10 @@
 //!     // Optionally, you can override the label:
 //!     #[imgui(checkbox(label = "Checkbox!"))]
 //!     check: bool,
+//!
+//!     // `events.flipped()` is true when `debug` changes value.
+//!     #[imgui(checkbox(toggled = "flipped"))]
+//!     debug: bool,
 //! }
 //! ```
 //!
@@ -25,6 +33,22 @@
 //! ![][result]
 //!
 //! [result]: https://i.imgur.com/1hTR89V.png
+//!
+//! ## Tri-state
+//!
+//! `Option<bool>` fields are drawn as a tri-state checkbox, for settings that
+//! can also be inherited: `None` is shown as a mixed value, and clicking cycles
+//! through `None`, `Some(true)` and `Some(false)`.
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! struct Overrides {
+//!     // `None` inherits the global setting.
+//!     #[imgui(checkbox)]
+//!     vsync: Option<bool>,
+//! }
+//! ```
+use imgui::sys;
 use imgui::{ImStr, Ui};
 
 /// Structure generated by the annoration.
@@ -59,3 +83,59 @@ impl<T: Checkbox> Checkbox for Box<T> {
         T::build(ui, elem, params)
     }
 }
+
+/// Value of a tri-state checkbox after it is clicked: `None` -> `Some(true)`
+/// -> `Some(false)` -> `None`.
+pub fn next_state(state: Option<bool>) -> Option<bool> {
+    match state {
+        None => Some(true),
+        Some(true) => Some(false),
+        Some(false) => None,
+    }
+}
+
+/// Draws a tri-state checkbox. This is what `checkbox` expands to on
+/// `Option<bool>` fields.
+pub fn build_tri_state(ui: &Ui, elem: &mut Option<bool>, params: CheckboxParams) -> bool {
+    let mut checked = *elem == Some(true);
+    let clicked = ui.checkbox(params.label, &mut checked);
+    if clicked {
+        *elem = next_state(*elem);
+    }
+    if elem.is_none() {
+        draw_mixed(ui);
+    }
+    clicked
+}
+
+/// Draws the mixed value mark (a small square) over the box of the last
+/// checkbox.
+fn draw_mixed(ui: &Ui) {
+    let [x, y] = ui.get_item_rect_min();
+    unsafe {
+        let size = sys::igGetFrameHeight();
+        let pad = (size / 3.6).floor().max(1.0);
+        let color = sys::igGetColorU32(sys::ImGuiCol_CheckMark as _, 1.0);
+        let draw_list = sys::igGetWindowDrawList();
+        sys::ImDrawList_AddRectFilled(
+            draw_list,
+            [x + pad, y + pad].into(),
+            [x + size - pad, y + size - pad].into(),
+            color,
+            0.0,
+            sys::ImDrawCornerFlags_All as _,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::next_state;
+
+    #[test]
+    fn tri_state() {
+        assert_eq!(Some(true), next_state(None));
+        assert_eq!(Some(false), next_state(Some(true)));
+        assert_eq!(None, next_state(Some(false)));
+    }
+}
@@ -7,12 +7,14 @@
 //!
 //! ### Optional params
 //!
-//! * `label`
+//! * `label` (a string literal, or the path of a `&'static str` constant)
+//! * `label_inline` render the label before the widget, instead of after it.
 //! * `flags` Function identifier that returns a
 //!   [`ColorEditFlags`][ColorEditFlags].
 //! * `preview` Allowed values: `"Opaque"`, `"HalfAlpha"`, `"Alpha"`
 //!   ([`ColorPreview`][ColorPreview] variants).
 //! * `size` Function identifier that returns the button size.
+//! * `alpha_preview` & `alpha_preview_half` flags (see [alpha](#alpha)).
 //! * `catch`
 //! * `map` Applies a mapping function to `&mut Self`.
 //!
@@ -22,7 +24,8 @@
 //!
 //! ### Optional params
 //!
-//! * `label`
+//! * `label` (a string literal, or the path of a `&'static str` constant)
+//! * `label_inline` render the label before the widget, instead of after it.
 //! * `flags` Function identifier that returns a
 //!   [`ColorEditFlags`][ColorEditFlags].
 //! * `preview` Allowed values: `"Opaque"`, `"HalfAlpha"`, `"Alpha"`
@@ -30,7 +33,13 @@
 //! * `mode` Allowed values: `"RGB"`, `"HSV"`, `"HEX"`
 //!   ([`ColorEditMode`][ColorEditMode] variants).
 //! * `format` Allowed values: `"Float"`, `"U8"` ([`ColorFormat`][ColorFormat]
-//!   variants).
+//!   variants), and `"u32"` for packed colors (see [packed
+//!   colors](#packed-colors)).
+//! * `alpha_bar`, `alpha_preview` & `alpha_preview_half` flags (see
+//!   [alpha](#alpha)).
+//! * `swatch_only` flag. Only the color swatch is drawn (no inputs, no label).
+//!   Clicking it opens the picker. Same as adding the `NO_INPUTS` and
+//!   `NO_LABEL` flags.
 //! * `catch`
 //! * `map` Applies a mapping function to `&mut Self`.
 //!
@@ -40,7 +49,8 @@
 //!
 //! ### Optional params
 //!
-//! * `label`
+//! * `label` (a string literal, or the path of a `&'static str` constant)
+//! * `label_inline` render the label before the widget, instead of after it.
 //! * `flags` Function identifier that returns a
 //!   [`ColorEditFlags`][ColorEditFlags].
 //! * `preview` Allowed values: `"Opaque"`, `"HalfAlpha"`, `"Alpha"`
@@ -49,6 +59,8 @@
 //!   ([`ColorPickerMode`][ColorEditMode] variants).
 //! * `format` Allowed values: `"Float"`, `"U8"` ([`ColorFormat`][ColorFormat]
 //!   variants).
+//! * `alpha_bar`, `alpha_preview` & `alpha_preview_half` flags (see
+//!   [alpha](#alpha)).
 //! * `catch`
 //! * `map` Applies a mapping function to `&mut Self`.
 //!
@@ -71,6 +83,39 @@
 //!
 //! ![][result]
 //!
+//! ## Alpha
+//!
+//! The following flags control how the alpha channel is displayed. They are
+//! combined with the ones returned by `flags`, and can only be used on RGBA
+//! colors (`[f32; 3]` fields raise a compilation error):
+//!
+//! * `alpha_bar` show a vertical alpha bar (edit & picker only).
+//! * `alpha_preview` show the color over a checkerboard.
+//! * `alpha_preview_half` show half opaque / half checkerboard.
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! struct Example {
+//!     #[imgui(color(edit(alpha_bar, alpha_preview_half), button(alpha_preview)))]
+//!     color: [f32; 4],
+//! }
+//! ```
+//!
+//! ## Packed colors
+//!
+//! `color(edit(format = "u32"))` edits a `u32` holding a packed `0xRRGGBBAA`
+//! color: red in the most significant byte, alpha in the least significant
+//! one. The color is unpacked into a `[f32; 4]` for editing, and packed back
+//! (rounding each channel to the nearest byte) when it changes.
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! struct Theme {
+//!     #[imgui(color(edit(format = "u32", alpha_bar)))]
+//!     background: u32,
+//! }
+//! ```
+//!
 //! [result]: https://i.imgur.com/hWD08K0.png?1
 //! [ColorEditFlags]: https://docs.rs/imgui/0.0/imgui/struct.ColorEditFlags.html
 //! [ColorPreview]: https://docs.rs/imgui/0.0/imgui/enum.ColorPreview.html
@@ -162,6 +207,36 @@ impl<'a, C: Into<EditableColor<'a>>> ColorEdit for C {
     }
 }
 
+/// Packed `0xRRGGBBAA` color, edited by `color(edit(format = "u32"))`. See
+/// [packed colors](#packed-colors).
+pub struct Packed<'a>(pub &'a mut u32);
+
+impl ColorEdit for Packed<'_> {
+    fn build(ui: &Ui, elem: Self, params: ColorEditParams) -> bool {
+        let mut color = unpack(*elem.0);
+        let changed = ColorEdit::build(ui, &mut color, params);
+        if changed {
+            *elem.0 = pack(color);
+        }
+        changed
+    }
+}
+
+/// Splits a `0xRRGGBBAA` color into its channels, in the `0.0..=1.0` range.
+pub fn unpack(color: u32) -> [f32; 4] {
+    let channel = |shift: u32| ((color >> shift) & 0xff) as f32 / 255.0;
+    [channel(24), channel(16), channel(8), channel(0)]
+}
+
+/// Packs `[r, g, b, a]` channels (clamped to the `0.0..=1.0` range) into a
+/// `0xRRGGBBAA` color.
+pub fn pack(color: [f32; 4]) -> u32 {
+    color.iter().fold(0, |packed, &channel| {
+        let byte = (channel.max(0.0).min(1.0) * 255.0).round() as u32;
+        (packed << 8) | byte
+    })
+}
+
 impl<'a, C: Into<EditableColor<'a>>> ColorPicker for C {
     fn build(ui: &Ui, elem: Self, params: ColorPickerParams) -> bool {
         let mut picker = ImColorPicker::new(params.label, elem.into());
@@ -207,4 +282,42 @@ mod tests {
             Flags::all()
         }
     }
+
+    #[test]
+    fn color_alpha() {
+        #[derive(imgui_ext::Gui)]
+        struct Example {
+            #[imgui(color(edit(alpha_bar), picker(alpha_bar, alpha_preview)))]
+            a: [f32; 4],
+            #[imgui(color(edit(alpha_preview_half, flags = "flags"), button(alpha_preview)))]
+            b: [f32; 4],
+        }
+
+        fn flags() -> Flags {
+            Flags::NO_INPUTS
+        }
+    }
+
+    #[test]
+    fn packed() {
+        use super::{pack, unpack};
+
+        assert_eq!([1.0, 0.0, 0.0, 1.0], unpack(0xff00_00ff));
+        assert_eq!(0x1234_5678, pack(unpack(0x1234_5678)));
+        assert_eq!(0xff00_80ff, pack([2.0, -1.0, 0.5, 1.0]));
+        for &color in &[0u32, 0xffff_ffff, 0x8040_20c0, 0x0102_0304] {
+            assert_eq!(color, pack(unpack(color)));
+        }
+    }
+
+    #[test]
+    fn color_swatch_only() {
+        #[derive(imgui_ext::Gui)]
+        struct Example {
+            #[imgui(color(edit(swatch_only)))]
+            a: [f32; 3],
+            #[imgui(color(edit(swatch_only, alpha_preview)))]
+            b: [f32; 4],
+        }
+    }
 }
@@ -1,9 +1,40 @@
 //! ## Optional fields
 //!
-//! * `label` override widget label.
+//! * `label` override widget label (a string literal, or the path of a
+//!   `&'static str` constant).
+//! * `label_inline` render the label before the widget, instead of after it.
 //! * `catch`
 //! * `map` Applies a mapping function to `&mut Self` (works the same as in the
 //!   [input example](../input/index.html#mapping))
+//! * `typeahead` typing while the combobox is open jumps to the first item that
+//!   starts with the typed text (case insensitive). The search is reset when
+//!   the combobox is closed.
+//! * `on_select` path to a function called with the index of the new item
+//!   whenever the selection changes. The function must have the signature
+//!   `fn(new_index: usize, ext: &mut Self)`, where `Self` is the annotated
+//!   type. Runs after the widget is drawn, so it may modify any field.
+//! * `icons` name of a sibling `[ImString; N]` or `Vec<ImString>` field with
+//!   one icon (glyph) per item, drawn before the label of each item (and in
+//!   the preview). There must be exactly one icon per item, otherwise drawing
+//!   the combobox panics.
+//! * `sections` name of a sibling `[Option<ImString>; N]` or
+//!   `Vec<Option<ImString>>` field, parallel to the items. A `Some(name)`
+//!   starts a new section: a header row that can't be selected is drawn before
+//!   that item. Same as with `icons`, there must be one entry per item (see
+//!   [example](#sections)).
+//!
+//! ## Items
+//!
+//! The combobox trait is implemented for arrays of 3 items and for `Vec`s of
+//! any type that implements `AsRef<ImStr>` (such as `ImString`). An empty
+//! `Vec` is drawn as a disabled combobox with an empty preview, and a
+//! `selected` index past the last item previews the last item.
+//!
+//! Inline containers are supported behind optional features, with the same
+//! behavior as `Vec`:
+//!
+//! * `smallvec`: `smallvec::SmallVec<[S; N]>`.
+//! * `arrayvec`: `arrayvec::ArrayVec<[S; N]>`.
 //!
 //! ## Example
 //!
@@ -20,22 +51,92 @@
 //! }
 //! ```
 //!
+//! ### Selection callback
+//!
+//! ```
+//! use imgui::ImString;
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Fruits {
+//!     #[imgui(combobox(on_select = "Fruits::select"))]
+//!     fruits: [ImString; 3],
+//!     selected: usize,
+//! }
+//!
+//! impl Fruits {
+//!     fn select(new_index: usize, ext: &mut Self) {
+//!         ext.selected = new_index;
+//!     }
+//! }
+//! ```
+//!
+//! ### Icons
+//!
+//! ```
+//! use imgui::ImString;
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Tool {
+//!     #[imgui(combobox(icons = "tool_icons"))]
+//!     tools: Vec<ImString>,
+//!     tool_icons: Vec<ImString>,
+//! }
+//! ```
+//!
+//! ### Sections
+//!
+//! ```
+//! use imgui::ImString;
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Food {
+//!     #[imgui(combobox(sections = "food_sections"))]
+//!     food: Vec<ImString>,
+//!     // "Fruits" header before the apple, "Vegetables" before the carrot
+//!     food_sections: Vec<Option<ImString>>,
+//! }
+//! ```
+//!
 //! ### Result
 //!
 //! ![][result]
 //!
 //! [result]: 
-use imgui::{ImStr, Ui};
+use imgui::sys;
+use imgui::{im_str, ImStr, ImString, Ui};
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::{ptr, slice};
 
 /// Structure generated by the annoration.
 pub struct ComboboxParams<'a> {
     pub label: &'a ImStr,
     pub selected: usize,
+    pub typeahead: bool,
+    /// One icon per item, drawn before the label.
+    pub icons: Option<&'a [ImString]>,
+    /// One entry per item, the name of the section started by the item.
+    pub sections: Option<&'a [Option<ImString>]>,
 }
 
 /// Trait for types that can be represented with a combobox.
 pub trait Combobox {
     fn build(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> bool;
+
+    /// Same as `build`, but returns the index of the newly selected item when
+    /// the selection changes.
+    ///
+    /// The default implementation reports `params.selected`. Override it if the
+    /// type keeps track of the selected item.
+    fn select(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> Option<usize> {
+        let selected = params.selected;
+        if Self::build(ui, elem, params) {
+            Some(selected)
+        } else {
+            None
+        }
+    }
 }
 
 impl<C: Combobox> Combobox for Option<C> {
@@ -46,14 +147,274 @@ impl<C: Combobox> Combobox for Option<C> {
             false
         }
     }
+
+    fn select(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> Option<usize> {
+        if let Some(ref mut elem) = elem {
+            C::select(ui, elem, params)
+        } else {
+            None
+        }
+    }
 }
 
 impl<S: AsRef<ImStr>> Combobox for [S; 3] { // TODO: Other array sizes
     fn build(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> bool {
-        let items = elem.iter().collect::<Vec<_>>(); // TODO: Avoid alloc
-        let mut current_item = params.selected; // TODO: Handle mutability
+        Self::select(ui, elem, params).is_some()
+    }
+
+    fn select(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> Option<usize> {
+        select_item(ui, &elem[..], params)
+    }
+}
+
+impl<S: AsRef<ImStr>> Combobox for Vec<S> {
+    fn build(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> bool {
+        Self::select(ui, elem, params).is_some()
+    }
+
+    fn select(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> Option<usize> {
+        select_item(ui, &elem[..], params)
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A> Combobox for smallvec::SmallVec<A>
+where
+    A: smallvec::Array,
+    A::Item: AsRef<ImStr>,
+{
+    fn build(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> bool {
+        Self::select(ui, elem, params).is_some()
+    }
+
+    fn select(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> Option<usize> {
+        select_item(ui, &elem[..], params)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<A> Combobox for arrayvec::ArrayVec<A>
+where
+    A: arrayvec::Array,
+    A::Item: AsRef<ImStr>,
+{
+    fn build(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> bool {
+        Self::select(ui, elem, params).is_some()
+    }
+
+    fn select(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> Option<usize> {
+        select_item(ui, &elem[..], params)
+    }
+}
+
+/// Draws a combobox of `items`. Returns the index of the new selected item
+/// when the selection changes.
+fn select_item<S: AsRef<ImStr>>(ui: &Ui, items: &[S], params: ComboboxParams) -> Option<usize> {
+    if items.is_empty() {
+        empty_combo(params.label);
+        return None;
+    }
+    // never index past the last item
+    let mut current_item = params.selected.min(items.len() - 1); // TODO: Handle mutability
+    let changed = if params.typeahead || params.icons.is_some() || params.sections.is_some() {
+        let items = icon_rows(params.label, items, params.icons);
+        let rows = section_rows(params.label, items.len(), params.sections);
+        custom_combo(
+            params.label,
+            &mut current_item,
+            &items,
+            &rows,
+            params.typeahead,
+        )
+    } else {
+        let items = items.iter().collect::<Vec<_>>(); // TODO: Avoid alloc
         imgui::ComboBox::new(params.label).build_simple_string(ui, &mut current_item, &items)
+    };
+    if changed {
+        Some(current_item)
+    } else {
+        None
+    }
+}
+
+/// Draws a disabled (faded) combobox with an empty preview. Its popup is
+/// closed as soon as it opens, since there is nothing to select.
+fn empty_combo(label: &ImStr) {
+    unsafe {
+        let alpha = (*sys::igGetStyle()).Alpha;
+        sys::igPushStyleVarFloat(sys::ImGuiStyleVar_Alpha as _, alpha * 0.5);
+        let preview = b"\0".as_ptr() as *const _;
+        if sys::igBeginCombo(label.as_ptr(), preview, 0) {
+            sys::igCloseCurrentPopup();
+            sys::igEndCombo();
+        }
+        sys::igPopStyleVar(1);
+    }
+}
+
+/// Pairs each item with its icon (`None` if the combobox has no icons).
+///
+/// # Panics
+///
+/// If there isn't exactly one icon per item.
+fn icon_rows<'a, S: AsRef<ImStr>>(
+    label: &ImStr,
+    items: &'a [S],
+    icons: Option<&'a [ImString]>,
+) -> Vec<(Option<&'a ImStr>, &'a ImStr)> {
+    if let Some(icons) = icons {
+        assert!(
+            icons.len() == items.len(),
+            "combobox `{}` has {} icons for {} items",
+            label.to_str(),
+            icons.len(),
+            items.len()
+        );
+    }
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| (icons.map(|icons| icons[index].as_ref()), item.as_ref()))
+        .collect()
+}
+
+/// Row of the popup of a combobox.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Row<'a> {
+    /// Section header, which can't be selected.
+    Header(&'a ImStr),
+    /// Item, given by its index.
+    Item(usize),
+}
+
+/// Rows of the popup of a combobox of `len` items, with the header of each
+/// section right before its first item.
+///
+/// # Panics
+///
+/// If there isn't exactly one section entry per item.
+fn section_rows<'a>(
+    label: &ImStr,
+    len: usize,
+    sections: Option<&'a [Option<ImString>]>,
+) -> Vec<Row<'a>> {
+    let sections = match sections {
+        Some(sections) => sections,
+        None => return (0..len).map(Row::Item).collect(),
+    };
+    assert!(
+        sections.len() == len,
+        "combobox `{}` has {} sections for {} items",
+        label.to_str(),
+        sections.len(),
+        len
+    );
+    let mut rows = Vec::with_capacity(len);
+    for (index, section) in sections.iter().enumerate() {
+        if let Some(section) = section {
+            rows.push(Row::Header(section.as_ref()));
+        }
+        rows.push(Row::Item(index));
     }
+    rows
+}
+
+/// Same as `ComboBox::build_simple_string`, but each item may have an icon
+/// before its label, the `rows` may have section headers between the items,
+/// and with `typeahead` the characters typed while the combo is open are used
+/// to jump to the first matching item.
+fn custom_combo(
+    label: &ImStr,
+    current: &mut usize,
+    items: &[(Option<&ImStr>, &ImStr)],
+    rows: &[Row],
+    typeahead: bool,
+) -> bool {
+    thread_local! {
+        // typed text of the open comboboxes
+        static SEARCH: RefCell<HashMap<sys::ImGuiID, String>> = RefCell::new(HashMap::new());
+    }
+
+    let mut changed = false;
+    unsafe {
+        let id = sys::igGetIDStr(label.as_ptr());
+        let preview = items.get(*current).map(|&(icon, item)| match icon {
+            Some(icon) => ImString::new(format!("{} {}", icon.to_str(), item.to_str())),
+            None => ImString::new(item.to_str()),
+        });
+        let preview = preview
+            .as_ref()
+            .map(|preview| preview.as_ptr())
+            .unwrap_or(ptr::null());
+
+        if !sys::igBeginCombo(label.as_ptr(), preview, 0) {
+            SEARCH.with(|search| search.borrow_mut().remove(&id));
+            return false;
+        }
+
+        let queue = &(*sys::igGetIO()).InputQueueCharacters;
+        let typed = if typeahead && queue.Size > 0 {
+            slice::from_raw_parts(queue.Data, queue.Size as usize)
+        } else {
+            &[]
+        };
+
+        let matched = SEARCH.with(|search| {
+            let mut search = search.borrow_mut();
+            let query = search.entry(id).or_default();
+            let len = query.len();
+            query.extend(typed.iter().filter_map(|&c| std::char::from_u32(c as u32)));
+            if query.len() != len {
+                let items = items.iter().map(|&(_, item)| item).collect::<Vec<_>>();
+                typeahead_match(&items, query)
+            } else {
+                None
+            }
+        });
+
+        if let Some(index) = matched {
+            changed = *current != index;
+            *current = index;
+        }
+
+        for row in rows.iter() {
+            let index = match *row {
+                Row::Item(index) => index,
+                Row::Header(header) => {
+                    // own ID, so a header doesn't collide with an item of the same name
+                    sys::igPushIDStr(im_str!("section").as_ptr());
+                    let flags = sys::ImGuiSelectableFlags_Disabled;
+                    sys::igSelectable(header.as_ptr(), false, flags as _, [0.0, 0.0].into());
+                    sys::igPopID();
+                    continue;
+                }
+            };
+            let (icon, item) = items[index];
+            let selected = index == *current;
+            if let Some(icon) = icon {
+                sys::igTextUnformatted(icon.as_ptr(), ptr::null());
+                sys::igSameLine(0.0, -1.0);
+            }
+            if sys::igSelectable(item.as_ptr(), selected, 0, [0.0, 0.0].into()) {
+                changed = true;
+                *current = index;
+            }
+            if matched == Some(index) {
+                sys::igSetScrollHereY(0.5);
+            }
+        }
+
+        sys::igEndCombo();
+    }
+    changed
+}
+
+/// Index of the first item that starts with `query` (case insensitive).
+fn typeahead_match<S: AsRef<ImStr>>(items: &[S], query: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    items
+        .iter()
+        .position(|item| item.as_ref().to_str().to_lowercase().starts_with(&query))
 }
 
 impl<T: Combobox> Combobox for Box<T> {
@@ -61,4 +422,85 @@ impl<T: Combobox> Combobox for Box<T> {
     fn build(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> bool {
         T::build(ui, elem, params)
     }
+
+    #[inline]
+    fn select(ui: &Ui, elem: &mut Self, params: ComboboxParams) -> Option<usize> {
+        T::select(ui, elem, params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{icon_rows, section_rows, typeahead_match, Row};
+
+    use imgui::{im_str, ImString};
+
+    #[test]
+    fn typeahead() {
+        let items = [im_str!("Apple"), im_str!("Banana"), im_str!("Blueberry")];
+
+        assert_eq!(Some(0), typeahead_match(&items, "a"));
+        assert_eq!(Some(1), typeahead_match(&items, "b"));
+        assert_eq!(Some(2), typeahead_match(&items, "BL"));
+        assert_eq!(None, typeahead_match(&items, "c"));
+        assert_eq!(Some(0), typeahead_match(&items, ""));
+    }
+    #[test]
+    fn icons() {
+        let items = [im_str!("Pen"), im_str!("Eraser")];
+        let icons = [ImString::new("P"), ImString::new("E")];
+
+        let rows = icon_rows(im_str!("Tool"), &items, Some(&icons[..]));
+        assert_eq!(
+            vec![
+                (Some(im_str!("P")), im_str!("Pen")),
+                (Some(im_str!("E")), im_str!("Eraser")),
+            ],
+            rows
+        );
+
+        let rows = icon_rows(im_str!("Tool"), &items, None);
+        assert_eq!(
+            vec![(None, im_str!("Pen")), (None, im_str!("Eraser"))],
+            rows
+        );
+    }
+
+    #[test]
+    fn sections() {
+        let sections = [
+            Some(ImString::new("Fruits")),
+            None,
+            Some(ImString::new("Vegetables")),
+            None,
+        ];
+        let rows = section_rows(im_str!("Food"), 4, Some(&sections[..]));
+        assert_eq!(
+            vec![
+                Row::Header(im_str!("Fruits")),
+                Row::Item(0),
+                Row::Item(1),
+                Row::Header(im_str!("Vegetables")),
+                Row::Item(2),
+                Row::Item(3),
+            ],
+            rows
+        );
+
+        let rows = section_rows(im_str!("Food"), 2, None);
+        assert_eq!(vec![Row::Item(0), Row::Item(1)], rows);
+    }
+
+    #[test]
+    #[should_panic(expected = "combobox `Food` has 1 sections for 2 items")]
+    fn sections_mismatch() {
+        section_rows(im_str!("Food"), 2, Some(&[None][..]));
+    }
+
+    #[test]
+    #[should_panic(expected = "combobox `Tool` has 1 icons for 2 items")]
+    fn icons_mismatch() {
+        let items = [im_str!("Pen"), im_str!("Eraser")];
+        icon_rows(im_str!("Tool"), &items, Some(&[ImString::new("P")][..]));
+    }
 }
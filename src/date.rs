@@ -0,0 +1,181 @@
+//! Date fields can be edited with `drag(...)` and `input(...)`. The date is
+//! edited as three integers (year, month and day), in the same widget.
+//!
+//! Edits that would produce an invalid date (month outside of `1..=12`, a day
+//! past the end of the month, ...) are reverted, so the field always holds a
+//! valid date.
+//!
+//! Implementations are provided behind optional features:
+//!
+//! * `chrono`: `chrono::NaiveDate` and `chrono::NaiveDateTime` (only the date
+//!   is edited, the time is kept).
+//!
+//! Other date types can be edited the same way by implementing the [`Date`]
+//! trait, and forwarding the widget traits to [`with_date`].
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use chrono::NaiveDate;
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Release {
+//!     #[imgui(drag(speed = 0.1))]
+//!     date: NaiveDate,
+//!     #[imgui(input)]
+//!     deadline: NaiveDate,
+//! }
+//! ```
+//!
+//! [`Date`]: trait.Date.html
+//! [`with_date`]: fn.with_date.html
+
+/// Types that represent a calendar date.
+pub trait Date: Sized {
+    /// Year, month (`1..=12`) and day of the month (`1..=31`).
+    fn ymd(&self) -> [i32; 3];
+
+    /// Same date as `self` (keeping everything that isn't the date, such as
+    /// the time) on the given year, month and day. Only called with valid
+    /// dates. Returns `None` if the type can't represent it.
+    fn with_ymd(&self, ymd: [i32; 3]) -> Option<Self>;
+}
+
+fn is_leap(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days of `month` (`1..=12`) of `year`.
+fn days_in_month(year: i32, month: i32) -> i32 {
+    match month {
+        2 if is_leap(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Whether `[year, month, day]` is a valid date of the (proleptic) Gregorian
+/// calendar.
+pub fn is_valid([year, month, day]: [i32; 3]) -> bool {
+    (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month)
+}
+
+/// Edits `elem` through its year, month and day. The edited date is written
+/// back if `build` returns `true` and the date is valid. Returns `true` if
+/// `elem` changed.
+pub fn with_date<D, F>(elem: &mut D, build: F) -> bool
+where
+    D: Date,
+    F: FnOnce(&mut [i32; 3]) -> bool,
+{
+    let mut ymd = elem.ymd();
+    if !build(&mut ymd) || !is_valid(ymd) {
+        return false;
+    }
+    match elem.with_ymd(ymd) {
+        Some(date) => {
+            *elem = date;
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use chrono::{Datelike, NaiveDate, NaiveDateTime};
+    use imgui::Ui;
+
+    use super::{with_date, Date};
+    use crate::drag::{Drag, DragParams};
+    use crate::input::{Input, InputParams};
+
+    impl Date for NaiveDate {
+        fn ymd(&self) -> [i32; 3] {
+            [self.year(), self.month() as i32, self.day() as i32]
+        }
+
+        fn with_ymd(&self, [year, month, day]: [i32; 3]) -> Option<Self> {
+            NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+        }
+    }
+
+    impl Date for NaiveDateTime {
+        fn ymd(&self) -> [i32; 3] {
+            self.date().ymd()
+        }
+
+        fn with_ymd(&self, ymd: [i32; 3]) -> Option<Self> {
+            Some(self.date().with_ymd(ymd)?.and_time(self.time()))
+        }
+    }
+
+    macro_rules! impl_date {
+        ( $( $date:ty ),* ) => {
+            $(
+                impl Drag<i32> for $date {
+                    fn build(ui: &Ui, elem: &mut Self, params: DragParams<i32>) -> bool {
+                        with_date(elem, |ymd| Drag::build(ui, ymd, params))
+                    }
+                }
+
+                impl Input<i32> for $date {
+                    fn build(ui: &Ui, elem: &mut Self, params: InputParams<i32>) -> bool {
+                        with_date(elem, |ymd| Input::build(ui, ymd, params))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_date! { NaiveDate, NaiveDateTime }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_valid, with_date, Date};
+
+    #[derive(Debug, PartialEq)]
+    struct Day([i32; 3]);
+
+    impl Date for Day {
+        fn ymd(&self) -> [i32; 3] {
+            self.0
+        }
+
+        fn with_ymd(&self, ymd: [i32; 3]) -> Option<Self> {
+            Some(Day(ymd))
+        }
+    }
+
+    #[test]
+    fn valid() {
+        assert!(is_valid([2020, 2, 29]));
+        assert!(!is_valid([2019, 2, 29]));
+        assert!(!is_valid([1900, 2, 29]));
+        assert!(is_valid([2000, 2, 29]));
+        assert!(is_valid([2020, 12, 31]));
+        assert!(!is_valid([2020, 4, 31]));
+        assert!(!is_valid([2020, 13, 1]));
+        assert!(!is_valid([2020, 0, 1]));
+        assert!(!is_valid([2020, 1, 0]));
+    }
+
+    #[test]
+    fn revert_invalid() {
+        let mut day = Day([2020, 1, 31]);
+        assert!(with_date(&mut day, |ymd| {
+            ymd[1] = 3;
+            true
+        }));
+        assert_eq!(Day([2020, 3, 31]), day);
+
+        // April 31st doesn't exist
+        assert!(!with_date(&mut day, |ymd| {
+            ymd[1] = 4;
+            true
+        }));
+        assert_eq!(Day([2020, 3, 31]), day);
+    }
+}
@@ -1,17 +1,80 @@
 //!
 //! ## Optional params
 //!
+//! * `label` override widget label (a string literal, or the path of a
+//!   `&'static str` constant).
+//! * `label_inline` render the label before the widget, instead of after it.
+//! * `align` align the text of the row (such as the `label_inline` label, or
+//!   a `text` on the same line) vertically with the frame of the widget.
 //! * `min` maximum value
 //! * `max` minmum value
 //! * `speed`
-//! * `power`
-//! * `format` (format string in `printf` format)
+//! * `speed_field` name of a sibling `f32` field to read the speed from, so it
+//!   can be changed at runtime.
+//! * `speed_fast` speed used while the shift key is held down (coarse
+//!   dragging). `speed` (or `speed_field`) is used otherwise. Note that imgui
+//!   already scales the drag speed by 10 while Shift is held (and by 0.01
+//!   with Alt), on top of `speed_fast`.
+//! * `power` exponent of the curve of float values. Integer fields are
+//!   rejected at compile time, as are `min`/`max` literals that don't match
+//!   the kind of number of the field (`0` on a float, `0.5` on an integer).
+//! * `sanitize` replace `NaN` and infinite values with `min` (or zero) before
+//!   drawing the widget, so it doesn't get stuck.
+//! * `labels` one label per component of an array (`"[\"X\", \"Y\", \"Z\"]"`).
+//!   Each component gets its own drag, on its own line. By default, all the
+//!   components share the same widget.
+//! * `unit` unit of the value of `Duration` fields (see
+//!   [duration](../duration/index.html)).
+//! * `reset_on_dclick` reset the field to its default value
+//!   (`Default::default()`) when the drag is double clicked. The type of the
+//!   field must implement `Default`. Note that imgui also enters text input
+//!   mode on double click, so the old value is shown until the widget loses
+//!   focus.
+//! * `reset_to` expression to reset the field to, for when the logical default
+//!   differs from `Default::default()` (`reset_to = "100.0"`). Adds a small
+//!   `Reset` button next to the drag, which sets the field and triggers the
+//!   change event. With `reset_on_dclick`, double clicks reset to it too.
+//! * `bounded` read the bounds from the type of the field, which must implement
+//!   [`Bounded`](../trait.Bounded.html). Explicit `min` and `max` take
+//!   precedence over the bounds of the type, which take precedence over the
+//!   default (unbounded) drag.
+//! * `format` (format string in `printf` format). Checked at compile time
+//!   the same way as the [slider format](../slider/index.html).
 //! * `catch` override widget label.
+//! * `started` name of an event triggered when the mouse is pressed on the
+//!   drag (the start of an edit).
+//! * `finished` name of an event triggered when the drag is released after
+//!   editing the value. Unlike the change event, it is triggered once per
+//!   edit, which makes it the place to push an undo entry. With `labels`,
+//!   both events refer to the last component.
 //! * `map` Applies a mapping function to `&mut Self` (works the same as in the
 //!   [input example](../input/index.html#mapping))
+//! * `get` and `set` read and write the value through accessor functions
+//!   instead of the field (works the same as the [slider
+//!   accessors](../slider/index.html#accessors)).
 //!
+//! ## Example
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! struct Drags {
+//!     #[imgui(slider(min = 0.01, max = 10.0))]
+//!     sensitivity: f32,
+//!     // Drag speed is controlled by the slider above.
+//!     #[imgui(drag(speed_field = "sensitivity"))]
+//!     position: [f32; 3],
+//!     // One drag per axis.
+//!     #[imgui(drag(speed = 0.1, labels = "[\"X\", \"Y\", \"Z\"]"))]
+//!     scale: [f32; 3],
+//!     // `events.rotated()` is true once per edit, when the drag is released.
+//!     #[imgui(drag(finished = "rotated"))]
+//!     rotation: f32,
+//! }
+//! ```
+use crate::duration::TimeUnit;
 use imgui::sys;
 use imgui::{ImStr, Ui};
+use std::time::Duration;
 
 pub struct DragParams<'a, T> {
     pub label: &'a ImStr,
@@ -20,6 +83,56 @@ pub struct DragParams<'a, T> {
     pub max: Option<T>,
     pub speed: Option<f32>,
     pub power: Option<f32>,
+    pub sanitize: bool,
+    /// Unit of the value (`Duration` fields only).
+    pub unit: Option<TimeUnit>,
+}
+
+/// Selects the drag speed of the current frame: `speed_fast` while `shift` is
+/// held down (if set), `speed` otherwise.
+pub fn select_speed(shift: bool, speed: Option<f32>, speed_fast: Option<f32>) -> Option<f32> {
+    match (shift, speed_fast) {
+        (true, Some(fast)) => Some(fast),
+        _ => speed,
+    }
+}
+
+/// Resets `elem` to its default value if the widget was `double_clicked`
+/// (`reset_on_dclick` option). Returns whether the value was reset.
+pub fn reset_on_double_click<T: Default>(double_clicked: bool, elem: &mut T) -> bool {
+    if double_clicked {
+        *elem = T::default();
+    }
+    double_clicked
+}
+
+/// Draws each component of `elem` with its own drag and axis label (`labels`
+/// option). The label of the field is drawn above the components.
+pub fn build_axes<T: Copy, D: Drag<T>>(
+    ui: &Ui,
+    elem: &mut [D],
+    labels: &[&ImStr],
+    params: DragParams<T>,
+) -> bool {
+    ui.text(params.label);
+    // axis labels are shared by all fields
+    let id = ui.push_id(params.label.to_str());
+    let mut changed = false;
+    for (elem, &label) in elem.iter_mut().zip(labels) {
+        let params = DragParams {
+            label,
+            format: params.format,
+            min: params.min,
+            max: params.max,
+            speed: params.speed,
+            power: params.power,
+            sanitize: params.sanitize,
+            unit: params.unit,
+        };
+        changed |= D::build(ui, elem, params);
+    }
+    id.pop(ui);
+    changed
 }
 
 pub trait Drag<T> {
@@ -43,10 +156,32 @@ impl<T, D: Drag<T>> Drag<T> for Box<D> {
     }
 }
 
+impl Drag<f32> for Duration {
+    fn build(ui: &Ui, elem: &mut Self, params: DragParams<f32>) -> bool {
+        let unit = params.unit.unwrap_or_default();
+        let mut value = unit.from_duration(*elem);
+        let params = DragParams {
+            unit: None,
+            ..params
+        };
+        if f32::build(ui, &mut value, params) {
+            if let Some(duration) = unit.to_duration(value) {
+                *elem = duration;
+            }
+            true
+        } else {
+            false
+        }
+    }
+}
+
 imgui_drag_scalar! { (f32, f32, f32, f32, f32, f32, f32, f32, ), 8, sys::ImGuiDataType_Float }
 imgui_drag_scalar! { (f64, f64, f64, f64, f64, f64, f64, f64, ), 8, sys::ImGuiDataType_Double }
 imgui_drag_scalar! { (u32, u32, u32, u32, u32, u32, u32, u32, ), 8, sys::ImGuiDataType_U32 }
 imgui_drag_scalar! { (i32, i32, i32, i32, i32, i32, i32, i32, ), 8, sys::ImGuiDataType_S32 }
+imgui_drag_scalar! { (i64, i64, i64, i64, i64, i64, i64, i64, ), 8, sys::ImGuiDataType_S64 }
+
+imgui_drag_unsigned! { u8, u16, u64, usize }
 
 // matrix
 
@@ -85,3 +220,26 @@ imgui_drag_matrix! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, 4, sys::ImGuiD
 imgui_drag_matrix! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, 3, sys::ImGuiDataType_S32 }
 imgui_drag_matrix! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, 2, sys::ImGuiDataType_S32 }
 imgui_drag_matrix! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, 1, sys::ImGuiDataType_S32 }
+
+#[cfg(test)]
+mod tests {
+    use super::{reset_on_double_click, select_speed};
+
+    #[test]
+    fn reset() {
+        let mut value = [1.0, 2.0, 3.0];
+        assert!(!reset_on_double_click(false, &mut value));
+        assert_eq!([1.0, 2.0, 3.0], value);
+        assert!(reset_on_double_click(true, &mut value));
+        assert_eq!([0.0; 3], value);
+    }
+
+    #[test]
+    fn speed_fast() {
+        assert_eq!(Some(0.1), select_speed(false, Some(0.1), Some(10.0)));
+        assert_eq!(Some(10.0), select_speed(true, Some(0.1), Some(10.0)));
+        assert_eq!(Some(10.0), select_speed(true, None, Some(10.0)));
+        assert_eq!(None, select_speed(false, None, Some(10.0)));
+        assert_eq!(Some(0.1), select_speed(true, Some(0.1), None));
+    }
+}
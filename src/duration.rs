@@ -0,0 +1,86 @@
+//! `Duration` fields can be edited with `drag(...)` and `input(...)`. The value
+//! is edited as an `f32`, in seconds, or in milliseconds with the `unit`
+//! option:
+//!
+//! * `unit = "s"` seconds (default).
+//! * `unit = "ms"` milliseconds.
+//!
+//! Negative values are clamped to zero, as a `Duration` can't be negative.
+//!
+//! ## Example
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Config {
+//!     #[imgui(drag(speed = 0.1))]
+//!     timeout: Duration,
+//!     #[imgui(input(step = 10.0, unit = "ms"))]
+//!     debounce: Duration,
+//! }
+//! ```
+use std::time::Duration;
+
+/// Unit of the value edited by a widget on a `Duration` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Seconds,
+    Millis,
+}
+
+impl Default for TimeUnit {
+    fn default() -> Self {
+        TimeUnit::Seconds
+    }
+}
+
+impl TimeUnit {
+    /// Value of `duration`, in this unit.
+    pub fn from_duration(self, duration: Duration) -> f32 {
+        match self {
+            TimeUnit::Seconds => duration.as_secs_f32(),
+            TimeUnit::Millis => duration.as_secs_f32() * 1000.0,
+        }
+    }
+
+    /// Duration of `value` units. Negative values are clamped to zero.
+    /// Returns `None` if the value is `NaN` or too large for a `Duration`.
+    pub fn to_duration(self, value: f32) -> Option<Duration> {
+        let secs = match self {
+            TimeUnit::Seconds => f64::from(value),
+            TimeUnit::Millis => f64::from(value) / 1000.0,
+        };
+        if secs.is_nan() || secs >= u64::max_value() as f64 {
+            None
+        } else if secs <= 0.0 {
+            Some(Duration::from_secs(0))
+        } else {
+            Some(Duration::from_secs_f64(secs))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TimeUnit;
+    use std::time::Duration;
+
+    #[test]
+    fn units() {
+        let duration = Duration::from_millis(1500);
+        assert_eq!(1.5, TimeUnit::Seconds.from_duration(duration));
+        assert_eq!(1500.0, TimeUnit::Millis.from_duration(duration));
+        assert_eq!(Some(duration), TimeUnit::Seconds.to_duration(1.5));
+        assert_eq!(Some(duration), TimeUnit::Millis.to_duration(1500.0));
+    }
+
+    #[test]
+    fn clamp() {
+        let zero = Some(Duration::from_secs(0));
+        assert_eq!(zero, TimeUnit::Seconds.to_duration(-1.0));
+        assert_eq!(zero, TimeUnit::Millis.to_duration(-0.0));
+        assert_eq!(None, TimeUnit::Millis.to_duration(std::f32::NAN));
+        assert_eq!(None, TimeUnit::Seconds.to_duration(std::f32::INFINITY));
+    }
+}
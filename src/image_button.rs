@@ -1,21 +1,44 @@
 //! ## Params
 //!
-//! * `size` path to a function that returns the size of the image.
+//! * `size` path to a function that returns the size of the image, or an array
+//!   expression (`"[24.0, 24.0]"`).
 //!
 //! ## Optional params
 //!
-//! * `background` path to a function that returns the background color to be
-//!   used.
+//! * `background` (or `bg`) path to a function that returns the background
+//!   color to be used.
 //! * `tint` path to a function that returns a color to tint the image with.
-//! * `frame_padding` an `i32`.
+//! * `image_padding` padding around the image, in pixels (an `i32`). Not to be
+//!   confused with `frame_padding`, which sets the `FramePadding` style like
+//!   on any other widget.
 //! * `uv0` path to a function that returns the first uv coordinate to be used.
 //!   The default value is `[0.0, 0.0]`.
-//! * `uv0` path to a function that returns the second uv coordinate. The
+//! * `uv1` path to a function that returns the second uv coordinate. The
 //!   default value is `[1.0, 1.0]`.
+//! * `catch` override the name of the event (triggered when the button is
+//!   clicked).
+//!
+//! Like `size`, the color and uv params also accept array expressions.
 //!
 //! ## Example
 //!
-//! ### Result
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! struct Toolbar {
+//!     #[imgui(image_button(size = "[24.0, 24.0]"))]
+//!     open: usize,
+//!     #[imgui(image_button(size = "[24.0, 24.0]", bg = "bg", same_line))]
+//!     save: usize,
+//!     #[imgui(image_button(size = "[24.0, 24.0]", tint = "[1.0, 0.0, 0.0, 1.0]", same_line))]
+//!     close: usize,
+//! }
+//!
+//! fn bg() -> [f32; 4] {
+//!     [0.2, 0.2, 0.2, 1.0]
+//! }
+//!
+//! // `events.save()` is true on the frame the button was clicked.
+//! ```
 //!
 use imgui::{TextureId, Ui};
 
@@ -28,15 +51,17 @@ pub struct ImageButtonParams {
     pub frame_padding: Option<i32>,
 }
 
+/// Trait for types that can be drawn as a clickable image button.
 pub trait ImageButton {
-    fn build(ui: &Ui, elem: Self, params: ImageButtonParams);
+    /// Returns `true` when the button is clicked.
+    fn build(ui: &Ui, elem: Self, params: ImageButtonParams) -> bool;
 }
 
 impl<T> ImageButton for T
 where
     T: Copy + Into<TextureId>,
 {
-    fn build(ui: &Ui, elem: Self, params: ImageButtonParams) {
+    fn build(ui: &Ui, elem: Self, params: ImageButtonParams) -> bool {
         let mut image = imgui::ImageButton::new(elem.into(), params.size);
         if let Some(tint) = params.tint {
             image = image.tint_col(tint);
@@ -53,6 +78,6 @@ where
         if let Some(uv1) = params.uv1 {
             image = image.uv1(uv1);
         }
-        image.build(ui);
+        image.build(ui)
     }
 }
@@ -1,12 +1,40 @@
 //! # Optional fields
 //!
-//! * `label` override widget label.
+//! * `label` override widget label (a string literal, or the path of a
+//!   `&'static str` constant).
+//! * `label_inline` render the label before the widget, instead of after it.
 //! * `step`
 //! * `step_fast`
 //! * `flags` path to a function that returns the input [flags].
 //! * `size` size of the text box (multiline text input).
+//! * `buffer_size` minimum capacity of the text buffer (`ImString` only).
+//!   Text can't grow past the capacity of the buffer, so it is reserved before
+//!   the input is drawn. Defaults to 256 bytes.
+//! * `max_len` maximum length of the text, in characters (not bytes), for
+//!   `ImString` and `String` fields. Longer text is truncated when it is
+//!   written back to the field, without splitting multi-byte characters.
+//! * `unit` unit of the value of `Duration` fields (see
+//!   [duration](../duration/index.html)).
+//! * `format` format of numeric values (in `printf` format). Checked at compile
+//!   time the same way as the [slider format](../slider/index.html).
 //! * `catch`
 //! * `map` Applies a mapping function to `&mut Self` (see [example](#mapping)).
+//! * `get` and `set` read and write the value through accessor functions
+//!   instead of the field (see the [slider
+//!   accessors](../slider/index.html#accessors)). The value is only written
+//!   when the event is triggered, so `enter_returns_true` discards the edits
+//!   until Enter is pressed.
+//! * `auto_select_all` select the whole text when the input gains focus.
+//! * `enter_returns_true` the event is only triggered when the Enter key is
+//!   pressed, instead of every time the value changes (see
+//!   [example](#submit-on-enter)).
+//! * `clear_button` draw a small `x` button on the same line, which empties
+//!   the text (`ImString` and `String`) and triggers the event when clicked.
+//!   Handy for search fields.
+//! * `completion` path to a function that completes the text when TAB is
+//!   pressed (`ImString` and `String`, see [example](#tab-completion)).
+//! * `sanitize` path to a function that cleans up the text after each edit
+//!   (`ImString` and `String`, see [example](#sanitizing-text)).
 //!
 //! [flags]: https://docs.rs/imgui/0.0/imgui/struct.ImGuiInputTextFlags.html
 //!
@@ -14,6 +42,32 @@
 //!
 //! Text input is only supported for [`imgui::ImString`](https://docs.rs/imgui/*/imgui/struct.ImString.html) types.
 //!
+//! `String` fields are also supported. Unlike `ImString`, the text buffer
+//! grows as the text is typed (`buffer_size` is not needed). If the edited
+//! text is not valid UTF-8, the field keeps its previous value.
+//!
+//! Undo and redo (`Ctrl+Z` and `Ctrl+Y`) work across frames, for both types.
+//! While an input is active, imgui edits its own copy of the text (where the
+//! undo stack lives), and the field is only written when the text changes.
+//! Unchanged fields keep their buffer from one frame to the next.
+//!
+//! Float fields (`f32` and `f64`, and their arrays and tuples) only take finite
+//! values. Text that parses to `NaN` or to an infinity (`"nan"`, `"inf"`, or
+//! `"1e99"` in an `f32` input) leaves the value as it was before the edit, and
+//! doesn't trigger the event.
+//!
+//! `char` fields are edited with a single-character text input. Typing over the
+//! character replaces it, clearing the input keeps the previous character, and
+//! pasting more than one character only keeps the last one.
+//!
+//! `[u8; N]` fields are edited in place, as nul-terminated UTF-8 strings (the
+//! C string buffers found in FFI structs). The text can't grow past `N - 1`
+//! bytes, so the terminator always fits. A buffer without a terminator is
+//! truncated to `N - 1` bytes (without splitting a character) before it is
+//! drawn. Implemented for `N` up to 32 and powers of two up to 4096. Other
+//! sizes can `map` the field to a `&mut [u8]`. Only `flags` and `size` apply
+//! to them.
+//!
 //! # Example
 //!
 //! The input trait is implemented for numeric types (`f32`, `f64`, `i32` and
@@ -38,6 +92,22 @@
 //!
 //! ![result][result]
 //!
+//! # Explicit arity
+//!
+//! `input2`, `input3` and `input4` work the same as `input`, but they fail to
+//! compile if the field is not a `[f32; 2]`, `[f32; 3]` or `[f32; 4]`
+//! (respectively).
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! struct Example {
+//!     #[imgui(input3)]
+//!     position: [f32; 3],
+//!     #[imgui(input4(step = 0.1))]
+//!     rotation: [f32; 4],
+//! }
+//! ```
+//!
 //! # Input flags
 //!
 //! You can load input flags from a function:
@@ -58,6 +128,65 @@
 //!
 //! [result]: https://i.imgur.com/BPvMGAp.png
 //!
+//! # Submit on Enter
+//!
+//! With `enter_returns_true`, the caught event no longer means "the value has
+//! changed". It is only triggered on the frame the Enter key is pressed, so it
+//! can be used to submit a form:
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! struct Search {
+//!     #[imgui(input(auto_select_all, enter_returns_true, catch = "submit"))]
+//!     query: imgui::ImString,
+//! }
+//! ```
+//!
+//! # TAB completion
+//!
+//! `completion` references a function with the signature
+//! `fn(text: &str) -> Option<String>`. It is called with the current text of
+//! the input whenever TAB is pressed while the input is active. If it returns
+//! `Some`, the returned string replaces the whole text (and the cursor moves to
+//! its end). `None` leaves the text untouched. The field is written (and the
+//! event is triggered) as if the completion had been typed.
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! struct CommandBar {
+//!     #[imgui(input(completion = "complete_command", enter_returns_true, catch = "run"))]
+//!     command: String,
+//! }
+//!
+//! fn complete_command(text: &str) -> Option<String> {
+//!     ["help", "history", "quit"]
+//!         .iter()
+//!         .find(|command| command.starts_with(text))
+//!         .map(|command| command.to_string())
+//! }
+//! ```
+//!
+//! # Sanitizing text
+//!
+//! `sanitize` references a function with the signature
+//! `fn(text: &str) -> String`. It is called with the edited text every time
+//! the input changes (typed or pasted text alike), and its result is what gets
+//! written to the field. Handy for single-line identifiers, which must not
+//! contain newlines nor control characters. It runs before `max_len` is
+//! applied.
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! struct Asset {
+//!     #[imgui(input(sanitize = "identifier"))]
+//!     name: String,
+//! }
+//!
+//! fn identifier(text: &str) -> String {
+//!     text.chars().filter(|c| !c.is_control()).collect()
+//! }
+//! ```
+//!
 //! # Mapping
 //!
 //! The attribite `map` references a function to map from a `&mut Self` of the
@@ -80,17 +209,46 @@
 //!     &mut foo.inner
 //! }
 //! ```
+use crate::duration::TimeUnit;
+use crate::Finite;
 use imgui::sys;
 use imgui::{ImGuiInputTextFlags, ImStr, ImString, InputText, InputTextMultiline, Ui};
 
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::os::raw::{c_int, c_void};
+use std::slice;
+use std::time::Duration;
+
 pub struct InputParams<'a, T> {
     pub label: &'a ImStr,
     pub step: Option<T>,
     pub step_fast: Option<T>,
     pub flags: Option<ImGuiInputTextFlags>,
     pub size: Option<[f32; 2]>,
+    pub buffer_size: Option<usize>,
+    /// Maximum number of characters of the text (`ImString` and `String`).
+    pub max_len: Option<usize>,
+    /// Unit of the value (`Duration` fields only).
+    pub unit: Option<TimeUnit>,
+    /// Completes the text when TAB is pressed (`ImString` and `String`).
+    pub completion: Option<Completion>,
+    /// Cleans up the edited text (`ImString` and `String`).
+    pub sanitize: Option<Sanitize>,
+    /// Format of the value, in `printf` format (numeric fields only).
+    pub format: Option<&'a ImStr>,
 }
 
+/// Completion function of a text input. See [TAB completion](#tab-completion).
+pub type Completion = fn(&str) -> Option<String>;
+
+/// Sanitizing function of a text input. See
+/// [sanitizing text](#sanitizing-text).
+pub type Sanitize = fn(&str) -> String;
+
+/// Default capacity of text input buffers.
+pub const DEFAULT_BUFFER_SIZE: usize = 256;
+
 pub trait Input<T> {
     fn build(ui: &Ui, elem: &mut Self, params: InputParams<T>) -> bool;
 }
@@ -113,7 +271,20 @@ impl<T, I: Input<T>> Input<T> for Option<I> {
 
 impl Input<()> for ImString {
     fn build(ui: &Ui, elem: &mut Self, params: InputParams<()>) -> bool {
-        if let Some(size) = params.size {
+        // the builders of imgui don't take callbacks, so completed inputs are
+        // drawn the same way as `String` inputs (and so are sanitized ones,
+        // which are written back the same way).
+        if params.completion.is_some() || params.sanitize.is_some() {
+            let mut text = elem.to_str().to_string();
+            let changed = String::build(ui, &mut text, params);
+            if changed {
+                elem.clear();
+                elem.push_str(&text);
+            }
+            return changed;
+        }
+        reserve(elem, params.buffer_size.unwrap_or(DEFAULT_BUFFER_SIZE));
+        let changed = if let Some(size) = params.size {
             let mut input = InputTextMultiline::new(ui, params.label, elem, size);
             if let Some(flags) = params.flags {
                 input = input.flags(flags);
@@ -125,14 +296,282 @@ impl Input<()> for ImString {
                 input = input.flags(flags);
             }
             input.build()
+        };
+        if let (true, Some(max_len)) = (changed, params.max_len) {
+            let len = truncate_chars(elem.to_str(), max_len).len();
+            if len < elem.to_str().len() {
+                let text = elem.to_str()[..len].to_string();
+                elem.clear();
+                elem.push_str(&text);
+            }
+        }
+        changed
+    }
+}
+
+impl Input<()> for String {
+    fn build(_: &Ui, elem: &mut Self, params: InputParams<()>) -> bool {
+        thread_local! {
+            // Scratch buffer shared by the `String` inputs, so the text isn't
+            // copied into a new allocation every frame.
+            static BUFFER: RefCell<Vec<u8>> = RefCell::new(Vec::new());
+        }
+        BUFFER.with(|buf| build_string(&mut buf.borrow_mut(), elem, params))
+    }
+}
+
+/// State of the `input_callback` of a `String` input.
+struct Callbacks<'a> {
+    /// Nul-terminated copy of the text, grown on resize events.
+    buf: &'a mut Vec<u8>,
+    completion: Option<Completion>,
+}
+
+/// Draws a `String` input, using `buf` to hold a nul-terminated copy of the
+/// text (grown by `input_callback`).
+fn build_string(buf: &mut Vec<u8>, elem: &mut String, params: InputParams<()>) -> bool {
+    buf.clear();
+    buf.extend_from_slice(elem.as_bytes());
+    buf.push(0);
+
+    let mut flags = params.flags.unwrap_or(ImGuiInputTextFlags::empty()).bits()
+        | sys::ImGuiInputTextFlags_CallbackResize as c_int;
+    if params.completion.is_some() {
+        flags |= sys::ImGuiInputTextFlags_CallbackCompletion as c_int;
+    }
+    let (ptr, len) = (buf.as_mut_ptr(), buf.len());
+    let mut callbacks = Callbacks {
+        buf,
+        completion: params.completion,
+    };
+    let user_data = &mut callbacks as *mut Callbacks as *mut c_void;
+
+    let changed = unsafe {
+        if let Some(size) = params.size {
+            sys::igInputTextMultiline(
+                params.label.as_ptr(),
+                ptr as _,
+                len,
+                size.into(),
+                flags as _,
+                Some(input_callback),
+                user_data,
+            )
+        } else {
+            sys::igInputText(
+                params.label.as_ptr(),
+                ptr as _,
+                len,
+                flags as _,
+                Some(input_callback),
+                user_data,
+            )
+        }
+    };
+
+    changed && write_back(elem, callbacks.buf, params.max_len, params.sanitize)
+}
+
+/// Grows the buffer of a `String` input when imgui asks for more room, and
+/// completes its text when TAB is pressed.
+extern "C" fn input_callback(data: *mut sys::ImGuiInputTextCallbackData) -> c_int {
+    unsafe {
+        let data = &mut *data;
+        let callbacks = &mut *(data.UserData as *mut Callbacks);
+        if data.EventFlag == sys::ImGuiInputTextFlags_CallbackResize as _ {
+            callbacks.buf.resize(data.BufSize as usize, 0);
+            data.Buf = callbacks.buf.as_mut_ptr() as _;
+        } else if data.EventFlag == sys::ImGuiInputTextFlags_CallbackCompletion as _ {
+            if let Some(completion) = callbacks.completion {
+                complete(data, completion);
+            }
         }
     }
+    0
+}
+
+/// Replaces the text of the input with its completion (if there is one), and
+/// moves the cursor to the end.
+unsafe fn complete(data: &mut sys::ImGuiInputTextCallbackData, completion: Completion) {
+    let text = slice::from_raw_parts(data.Buf as *const u8, data.BufTextLen as usize);
+    let completed = match std::str::from_utf8(text).ok().and_then(completion) {
+        Some(completed) => completed,
+        None => return,
+    };
+    let start = completed.as_ptr();
+    let end = start.add(completed.len());
+    sys::ImGuiInputTextCallbackData_DeleteChars(data, 0, data.BufTextLen);
+    sys::ImGuiInputTextCallbackData_InsertChars(data, 0, start as _, end as _);
+}
+
+/// Writes the nul-terminated text in `buf` into `elem`, truncated to
+/// `max_len` characters. Returns `false` (and leaves `elem` untouched) if the
+/// text is not valid UTF-8.
+fn write_back(
+    elem: &mut String,
+    buf: &[u8],
+    max_len: Option<usize>,
+    sanitize: Option<Sanitize>,
+) -> bool {
+    let text = match buf.iter().position(|&b| b == 0) {
+        Some(nul) => &buf[..nul],
+        None => buf,
+    };
+    let text = match std::str::from_utf8(text) {
+        Ok(text) => text,
+        Err(_) => return false,
+    };
+    let sanitized;
+    let text = match sanitize {
+        Some(sanitize) => {
+            sanitized = sanitize(text);
+            &sanitized[..]
+        }
+        None => text,
+    };
+    elem.clear();
+    elem.push_str(match max_len {
+        Some(max_len) => truncate_chars(text, max_len),
+        None => text,
+    });
+    true
+}
+
+/// Returns the first `max_len` characters of `text`. Never splits a
+/// multi-byte character.
+fn truncate_chars(text: &str, max_len: usize) -> &str {
+    match text.char_indices().nth(max_len) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    }
+}
+
+impl Input<()> for char {
+    fn build(ui: &Ui, elem: &mut Self, params: InputParams<()>) -> bool {
+        let mut buf = ImString::new(elem.to_string());
+        // room for a multi-character paste (truncated afterwards)
+        buf.reserve(16);
+        let mut input = InputText::new(ui, params.label, &mut buf);
+        if let Some(flags) = params.flags {
+            input = input.flags(flags);
+        }
+        input.build() && update_char(elem, buf.to_str())
+    }
+}
+
+impl Input<()> for [u8] {
+    fn build(_: &Ui, elem: &mut Self, params: InputParams<()>) -> bool {
+        if elem.is_empty() {
+            return false;
+        }
+        terminate(elem);
+        let flags = params.flags.unwrap_or(ImGuiInputTextFlags::empty()).bits();
+        let (ptr, len) = (elem.as_mut_ptr(), elem.len());
+        unsafe {
+            if let Some(size) = params.size {
+                sys::igInputTextMultiline(
+                    params.label.as_ptr(),
+                    ptr as _,
+                    len,
+                    size.into(),
+                    flags as _,
+                    None,
+                    std::ptr::null_mut(),
+                )
+            } else {
+                sys::igInputText(
+                    params.label.as_ptr(),
+                    ptr as _,
+                    len,
+                    flags as _,
+                    None,
+                    std::ptr::null_mut(),
+                )
+            }
+        }
+    }
+}
+
+/// Makes sure `buf` holds a nul-terminated UTF-8 string. Text without a
+/// terminator is truncated to `buf.len() - 1` bytes, and invalid UTF-8 is cut
+/// at the first invalid byte. `buf` must not be empty.
+fn terminate(buf: &mut [u8]) {
+    let last = buf.len() - 1;
+    let len = buf[..last].iter().position(|&b| b == 0).unwrap_or(last);
+    let len = match std::str::from_utf8(&buf[..len]) {
+        Ok(_) => len,
+        Err(error) => error.valid_up_to(),
+    };
+    buf[len] = 0;
+}
+
+impl Input<f32> for Duration {
+    fn build(ui: &Ui, elem: &mut Self, params: InputParams<f32>) -> bool {
+        let unit = params.unit.unwrap_or_default();
+        let mut value = unit.from_duration(*elem);
+        let params = InputParams {
+            unit: None,
+            ..params
+        };
+        if f32::build(ui, &mut value, params) {
+            if let Some(duration) = unit.to_duration(value) {
+                *elem = duration;
+            }
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Makes sure `buf` can hold at least `size` bytes of text.
+fn reserve(buf: &mut ImString, size: usize) {
+    let len = buf.to_str().len();
+    if buf.capacity() < size {
+        buf.reserve(size - len);
+    }
+}
+
+/// Reverts the values that an edit made non-finite (`NaN` or infinite, such
+/// as `"nan"` or `"1e99"` typed into an `f32` input) to their value before the
+/// edit, in `prev`. Returns `true` if any value is still changed.
+pub(crate) fn revert_non_finite<T: Finite + PartialEq>(prev: &[T], values: &mut [T]) -> bool {
+    let mut changed = false;
+    for (prev, value) in prev.iter().zip(values.iter_mut()) {
+        if !value.is_finite() {
+            *value = *prev;
+        }
+        changed |= crate::differs(prev, value);
+    }
+    changed
+}
+
+/// Writes the newly typed char of `text` (the last one that differs from
+/// `elem`) into `elem`. Empty text leaves `elem` untouched. Returns `true` if
+/// `elem` changed.
+fn update_char(elem: &mut char, text: &str) -> bool {
+    match text.chars().rev().find(|c| *c != *elem) {
+        Some(c) if c != *elem => {
+            *elem = c;
+            true
+        }
+        _ => false,
+    }
 }
 
 imgui_input_scalar! { (f32, f32, f32, f32, f32, f32, f32, f32), 8, sys::ImGuiDataType_Float }
 imgui_input_scalar! { (f64, f64, f64, f64, f64, f64, f64, f64), 8, sys::ImGuiDataType_Double }
 imgui_input_scalar! { (u32, u32, u32, u32, u32, u32, u32, u32), 8, sys::ImGuiDataType_U32 }
 imgui_input_scalar! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, sys::ImGuiDataType_S32 }
+imgui_input_scalar! { (i64, i64, i64, i64, i64, i64, i64, i64), 8, sys::ImGuiDataType_S64 }
+
+imgui_input_unsigned! { u8, u16, u64, usize }
+
+imgui_input_cstr! {
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+    64, 128, 256, 512, 1024, 2048, 4096
+}
 
 // matrix types
 // TODO macro Y expansion
@@ -172,3 +611,210 @@ imgui_input_matrix! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, 4, sys::ImGui
 imgui_input_matrix! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, 3, sys::ImGuiDataType_S32 }
 imgui_input_matrix! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, 2, sys::ImGuiDataType_S32 }
 imgui_input_matrix! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, 1, sys::ImGuiDataType_S32 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        input_callback, reserve, revert_non_finite, terminate, truncate_chars, update_char,
+        write_back, Callbacks,
+    };
+
+    use imgui::sys;
+    use imgui::ImString;
+
+    #[test]
+    fn string_resize() {
+        let mut buf = b"foo\0".to_vec();
+        let mut data: sys::ImGuiInputTextCallbackData = unsafe { std::mem::zeroed() };
+        data.EventFlag = sys::ImGuiInputTextFlags_CallbackResize as _;
+        data.Buf = buf.as_mut_ptr() as _;
+        data.BufTextLen = 255;
+        data.BufSize = 256;
+        let mut callbacks = Callbacks {
+            buf: &mut buf,
+            completion: None,
+        };
+        data.UserData = &mut callbacks as *mut Callbacks as _;
+
+        input_callback(&mut data);
+
+        // the buffer grew past the initial capacity, keeping the text.
+        assert_eq!(256, buf.len());
+        assert_eq!(buf.as_mut_ptr() as *mut _, data.Buf);
+        assert_eq!(b"foo\0", &buf[..4]);
+
+        // long text typed into the new room.
+        let long = "x".repeat(255);
+        buf[..255].copy_from_slice(long.as_bytes());
+        let mut text = String::from("foo");
+        assert!(write_back(&mut text, &buf, None, None));
+        assert_eq!(long, text);
+    }
+
+    #[test]
+    fn completion() {
+        fn complete(text: &str) -> Option<String> {
+            ["help", "history"]
+                .iter()
+                .find(|command| command.starts_with(text))
+                .map(|command| command.to_string())
+        }
+
+        let mut buf = b"he\0".to_vec();
+        buf.resize(64, 0);
+        let mut data: sys::ImGuiInputTextCallbackData = unsafe { std::mem::zeroed() };
+        data.EventFlag = sys::ImGuiInputTextFlags_CallbackCompletion as _;
+        data.Buf = buf.as_mut_ptr() as _;
+        data.BufTextLen = 2;
+        data.BufSize = 64;
+        data.CursorPos = 2;
+        let mut callbacks = Callbacks {
+            buf: &mut buf,
+            completion: Some(complete),
+        };
+        data.UserData = &mut callbacks as *mut Callbacks as _;
+
+        input_callback(&mut data);
+        assert_eq!(4, data.BufTextLen);
+        assert_eq!(4, data.CursorPos);
+        assert!(data.BufDirty);
+
+        // no completion, the text is left untouched
+        data.BufTextLen = 1;
+        buf[..2].copy_from_slice(b"x\0");
+        input_callback(&mut data);
+        assert_eq!(1, data.BufTextLen);
+
+        let mut text = String::new();
+        assert!(write_back(&mut text, &buf, None, None));
+        assert_eq!("x", text);
+    }
+
+    #[test]
+    fn non_finite() {
+        let prev = [1.0f32, 2.0, 3.0];
+        let mut values = [std::f32::NAN, 2.0, std::f32::INFINITY];
+        assert!(!revert_non_finite(&prev, &mut values));
+        assert_eq!(prev, values);
+
+        // finite edits are kept
+        let mut values = [std::f32::NEG_INFINITY, 4.0, 3.0];
+        assert!(revert_non_finite(&prev, &mut values));
+        assert_eq!([1.0, 4.0, 3.0], values);
+
+        let mut values = [1, 2];
+        assert!(!revert_non_finite(&[1, 2], &mut values));
+    }
+
+    #[test]
+    fn cstr_terminate() {
+        let mut buf = *b"foo\0bar";
+        terminate(&mut buf);
+        assert_eq!(b"foo\0bar", &buf);
+
+        // no terminator
+        let mut buf = *b"foobar";
+        terminate(&mut buf);
+        assert_eq!(b"fooba\0", &buf);
+
+        // the last character doesn't fit
+        let mut buf = [b'a', b'b', 0xc3, 0xa9];
+        terminate(&mut buf);
+        assert_eq!([b'a', b'b', 0, 0xa9], buf);
+
+        let mut buf = [0xff, b'a', 0];
+        terminate(&mut buf);
+        assert_eq!(0, buf[0]);
+    }
+
+    #[test]
+    fn string_invalid_utf8() {
+        let mut text = String::from("foo");
+        assert!(!write_back(&mut text, &[0xff, 0xfe, 0], None, None));
+        assert_eq!("foo", text);
+
+        assert!(write_back(
+            &mut text,
+            "héllo\0garbage".as_bytes(),
+            None,
+            None
+        ));
+        assert_eq!("héllo", text);
+    }
+
+    #[test]
+    fn max_len() {
+        assert_eq!("日本", truncate_chars("日本語", 2));
+        assert_eq!("日本語", truncate_chars("日本語", 3));
+        assert_eq!("日本語", truncate_chars("日本語", 8));
+        assert_eq!("", truncate_chars("ü", 0));
+
+        // pasted text is truncated at a char boundary
+        let mut text = String::from("foo");
+        assert!(write_back(
+            &mut text,
+            "ñandú 🦀\0".as_bytes(),
+            Some(7),
+            None
+        ));
+        assert_eq!("ñandú 🦀", text);
+        assert!(write_back(
+            &mut text,
+            "ñandú 🦀🦀\0".as_bytes(),
+            Some(6),
+            None
+        ));
+        assert_eq!("ñandú ", text);
+    }
+
+    #[test]
+    fn sanitize() {
+        fn single_line(text: &str) -> String {
+            text.chars().filter(|c| !c.is_control()).collect()
+        }
+
+        // pasted newlines are stripped, before truncating the text
+        let mut text = String::new();
+        let pasted = "foo\r\nbar\tbaz\0".as_bytes();
+        assert!(write_back(&mut text, pasted, None, Some(single_line)));
+        assert_eq!("foobarbaz", text);
+        assert!(write_back(&mut text, pasted, Some(6), Some(single_line)));
+        assert_eq!("foobar", text);
+    }
+
+    #[test]
+    fn buffer_size() {
+        let mut buf = ImString::new("foo");
+        reserve(&mut buf, 64);
+        assert!(buf.capacity() >= 64);
+        assert_eq!("foo", buf.to_str());
+
+        // never shrinks
+        reserve(&mut buf, 4);
+        assert!(buf.capacity() >= 64);
+    }
+
+    #[test]
+    fn char_input() {
+        let mut c = 'a';
+        assert!(update_char(&mut c, "b"));
+        assert_eq!('b', c);
+
+        // typing after or before the current char replaces it
+        assert!(update_char(&mut c, "bc"));
+        assert_eq!('c', c);
+        assert!(update_char(&mut c, "dc"));
+        assert_eq!('d', c);
+
+        // empty input keeps the previous char
+        assert!(!update_char(&mut c, ""));
+        assert_eq!('d', c);
+
+        // pasted text is truncated to the last char
+        assert!(update_char(&mut c, "dxyz"));
+        assert_eq!('z', c);
+
+        assert!(!update_char(&mut c, "z"));
+        assert!(!update_char(&mut c, "zz"));
+    }
+}
@@ -20,6 +20,17 @@
 //!
 //! [result]: https://i.imgur.com/Xrl1Nt0.png
 //!
+//! `Gui` can't be derived on unions (there is no way to tell which field to
+//! draw):
+//!
+//! ```compile_fail
+//! #[derive(imgui_ext::Gui)]
+//! union Bits {
+//!     int: u32,
+//!     float: f32,
+//! }
+//! ```
+//!
 //! # Input events
 //!
 //! Rendering a UI with `imgui` & `imgui-ext` returns a type with all the
@@ -78,12 +89,101 @@
 //! }
 //! ```
 //!
+//! The methods of click events (`button`, `image_button`, `color(button)` and
+//! `input(enter_returns_true)`) are `#[must_use]`, so a click that is never
+//! read is reported by the compiler.
+//!
+//! The returned type also has a `widgets_changed()` method that returns the
+//! names of all the events that were triggered, which is handy for logging:
+//!
+//! ```ignore
+//! println!("changed: {:?}", events.widgets_changed());
+//! ```
+//!
+//! Types whose annotations can't trigger any event (only `text`, `separator`,
+//! `display`...) don't get an events type: `draw_gui` returns `()`.
+//!
+//! The events live in their own type, and the derive never adds fields to the
+//! annotated type, so its layout is left as is (`#[repr(C)]` structs shared
+//! with C code can derive `Gui` too).
+//!
+//! # Patches
+//!
+//! Annotating the struct itself with `#[imgui(patch)]` generates an extra
+//! `draw_gui_patch` method. Instead of editing the struct in place, it draws
+//! the UI on a clone and returns it along with the events, so the old and new
+//! values can be compared (to implement undo, for example). The struct must
+//! implement `Clone`.
+//!
+//! ```ignore
+//! #[derive(Clone, imgui_ext::Gui)]
+//! #[imgui(patch)]
+//! struct Example {
+//!     #[imgui(slider(min = 0.0, max = 1.0))]
+//!     value: f32,
+//! }
+//!
+//! let (new, events) = Example::draw_gui_patch(&ui, &example);
+//! if events.value() {
+//!     undo_stack.push(std::mem::replace(&mut example, new));
+//! }
+//! ```
+//!
+//! # Tree nodes
+//!
+//! Annotating the struct with `#[imgui(tree)]` draws its UI inside of a
+//! collapsible tree node, labeled with the name of the type. The node goes
+//! wherever the type is drawn, so every `nested` field of that type gets one,
+//! without repeating a `tree(...)` annotation on each of them.
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! #[imgui(tree)]
+//! struct Light {
+//!     #[imgui(slider(min = 0.0, max = 10.0))]
+//!     intensity: f32,
+//! }
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Scene {
+//!     // two "Light" nodes, opened and closed independently
+//!     #[imgui(nested)]
+//!     key: Light,
+//!     #[imgui(nested)]
+//!     fill: Light,
+//! }
+//! ```
+//!
+//! # Doc tooltips
+//!
+//! Annotating the struct with `#[imgui(doc_tooltips)]` shows the `///` doc
+//! comment of each annotated field as a tooltip when its widget is hovered.
+//! Fields with more than one widget show the tooltip on the last one.
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! #[imgui(doc_tooltips)]
+//! struct Car {
+//!     /// Speed of the car, in meters per second.
+//!     #[imgui(slider(min = 0.0, max = 100.0))]
+//!     speed: f32,
+//! }
+//! ```
+//!
 //! [repo]: https://github.com/germangb/imgui-ext
 #![deny(warnings)]
 
+use imgui::sys;
 use imgui::Ui;
 
+use std::cell::Cell;
+use std::convert::TryFrom;
+
 pub use imgui_ext_derive::Gui;
+/// Name of the `Gui` derive macro in previous versions.
+///
+/// Deprecated: deriving it warns, use `#[derive(Gui)]` instead.
+pub use imgui_ext_derive::ImGuiExt;
 
 include!("macros.rs");
 
@@ -154,6 +254,10 @@ pub mod tree {
     //! - `node(..)` list of widget annotations.
     //! - `cond` One of the [`Condition`] variants.
     //!
+    //! The contents of a node are only evaluated while it is open: the widgets
+    //! of a collapsed node (nested UIs and `map` functions included) are never
+    //! drawn nor called, so large trees only pay for their open nodes.
+    //!
     //! [`ImGuiCond`]: https://docs.rs/imgui/*/imgui/struct.Condition.html
     //! [`ImGuiTreeNodeFlags`]: https://docs.rs/imgui/*/imgui/struct.ImGuiTreeNodeFlags.html
     //!
@@ -206,24 +310,44 @@ pub mod tree {
     //!
     //! ![](https://i.imgur.com/Rn2RJJG.png)
 }
+/// `buttons(...)` docs.
+pub mod buttons;
 /// `combobox(...)` docs.
 pub mod combobox;
 /// `checkbox(...)` docs.
 pub mod checkbox;
 /// `color(...)` docs.
 pub mod color;
+/// Date fields (`chrono`...).
+pub mod date;
 /// `drag(...)` docs.
 pub mod drag;
+/// `Duration` fields.
+pub mod duration;
 /// `image(...)` docs.
 pub mod image;
 /// `image_button(...)` docs.
 pub mod image_button;
 /// `input(...)` docs.
 pub mod input;
+/// `list(...)` docs.
+pub mod list;
+/// `Wrapping<T>` & `NonZero*` fields.
+pub mod num;
+/// `plot(...)` docs.
+pub mod plot;
 /// `progress(...)` docs.
 pub mod progress;
+/// `Range<T>` & `RangeInclusive<T>` fields.
+pub mod range;
 /// `slider(...)` docs.
 pub mod slider;
+/// `tab_bar(...)` & `tab_item(...)` docs.
+pub mod tab;
+/// Field-less `enum` fields.
+pub mod variants;
+/// Vector types of math libraries (`glam`, `mint`...).
+pub mod vector;
 /// `text(...)` & `text_wrap(...)` docs.
 pub mod text {
     //!
@@ -240,7 +364,28 @@ pub mod text {
     //!
     //! * `#[imgui(text("literal..."))]`
     //!
-    //! which is a shorthand for `text(lit = "literal...")`.
+    //! which is a shorthand for `text(lit = "literal...")`. Without a literal,
+    //! the value of the field is displayed (it must implement `Display`).
+    //!
+    //! # Decorators
+    //!
+    //! The following options can be combined in a single annotation:
+    //!
+    //! * `wrapped` wraps the text (same as `text_wrap(...)`).
+    //! * `colored(color = "...")` text color. Either a path to a function that
+    //!   returns the color, or a literal array (`"[1, 0, 0, 1]"`).
+    //! * `disabled` uses the color of disabled text.
+    //! * `bullet` places a bullet before the text.
+    //!
+    //! ```
+    //! #[derive(imgui_ext::Gui)]
+    //! struct Log {
+    //!     #[imgui(text(wrapped, colored(color = "[1, 0, 0, 1]")))]
+    //!     error: String,
+    //!     #[imgui(text("Warnings are disabled", bullet, disabled))]
+    //!     warnings: (),
+    //! }
+    //! ```
     //!
     //! # Example
     //!
@@ -264,7 +409,101 @@ pub mod misc {
     //! []()
     //!
     //! * `#[imgui(separator)]` inserts a separator
+    //! * `#[imgui(separator(vertical))]` inserts a vertical separator on the
+    //!   same line as the previous widget. It only makes sense in a horizontal
+    //!   layout: the widget that follows it needs `same_line` too.
     //! * `#[imgui(new_line)]` inserts an empty line
+    //! * `#[imgui(newtype, ...)]` makes the rest of the widgets target the
+    //!   inner value of a single-field tuple struct (`struct Meters(pub f32)`).
+    //! * `#[imgui(borrow, ...)]` makes the rest of the widgets target the value
+    //!   inside of a `RefCell` (`RefCell<f32>`).
+    //! * `#[imgui(lock, ...)]` makes the rest of the widgets target the value
+    //!   inside of a `Mutex` (`Mutex<f32>`, `Arc<Mutex<f32>>`). Panics if the
+    //!   mutex is poisoned.
+    //! * `#[imgui(deref, ...)]` makes the rest of the widgets target the value
+    //!   a field dereferences to (the field must implement `DerefMut`).
+    //! * `#[imgui(cell, ...)]` makes the rest of the widgets target the value
+    //!   inside of a `Cell` (`Cell<f32>`). The widgets edit a copy of the value
+    //!   (read with `get`), which is written back with `set` after they are
+    //!   drawn.
+    //! * `#[imgui(atomic, ...)]` makes the rest of the widgets target the value
+    //!   of an atomic (`AtomicBool`, `AtomicU32`, `AtomicUsize`...). The value
+    //!   is loaded into a copy, and stored back only if the widgets changed it,
+    //!   so writes made by other threads in between are kept. Loads and stores
+    //!   are `Relaxed`. `#[imgui(atomic = "SeqCst", ...)]` makes them `SeqCst`,
+    //!   and `atomic = "AcqRel"` makes the load `Acquire` and the store
+    //!   `Release`.
+    //! * Layout annotations that don't use the field (`separator`, `new_line`,
+    //!   `button`, `text("...")` and `bullet(text = "...")`) can be hosted by
+    //!   a `PhantomData<()>` field, which is never read nor modified. Handy for
+    //!   headers and toolbars. So can `slider`, `drag` and `input` annotations
+    //!   with `get` and `set` accessors, which don't use the field either. Any
+    //!   other annotation is a compile error.
+    //! * `#[imgui(skip)]` draws nothing for the field, same as leaving it
+    //!   without annotations. Attributes of other derives (such as
+    //!   `#[serde(skip)]`) are ignored, so both can be placed on the same field.
+    //! * `#[imgui(order = N, ...)]` draws the widgets of the field in the
+    //!   position given by the (non-negative) integer `N`, instead of the
+    //!   declaration order. Ordered fields are drawn first, sorted by `N`, then
+    //!   the remaining fields in declaration order.
+    //! * `same_line` can be added to any widget annotation (`button(same_line,
+    //!   ...)`, `slider(same_line, ...)`, ...) to place it on the same line as
+    //!   the previous widget.
+    //! * `id_offset = N` can also be added to any widget annotation. It pushes
+    //!   an extra integer into the ID stack around the widget, to tell apart
+    //!   widgets that would otherwise end up with the same ID.
+    //! * `disabled_if = "field"` can also be added to the widget annotations
+    //!   (all of them but `text(...)`, `display` and `tree(...)`). While the
+    //!   sibling `bool` field is `true`, the widget is faded out, its events
+    //!   (clicks, edits, ...) are not reported, and its callbacks (`on_click`,
+    //!   `on_select`, the `set` accessor) are not called. `"!field"` disables
+    //!   it while the field is `false` instead. Widgets that edit their field
+    //!   (checkboxes, sliders, inputs, ...) are drawn with the value of the
+    //!   field, and any edit made through them while they are disabled is
+    //!   discarded: the field is restored from a copy, so its type must
+    //!   implement `Clone`.
+    //! * `hidden_fn = "path::fn"` is accepted by the same annotations as
+    //!   `disabled_if`. It references a function `fn(&Self) -> bool`, called
+    //!   every frame before drawing the widget. While it returns `true`, the
+    //!   widget (and its `same_line`) is skipped. Unlike `disabled_if`, the
+    //!   predicate can be any runtime state, not only a sibling field.
+    //! * `frame_padding = "[x, y]"` is accepted by the same annotations as
+    //!   well (not by `text(...)`, `display` or `tree(...)`). The
+    //!   `FramePadding` style is set to `[x, y]` (two non-negative numbers)
+    //!   while the widget is drawn, and restored right after it, so a single
+    //!   widget can look different without changing the global style.
+    //!
+    //! Fields holding a mutable reference (`&'a mut f32`) are dereferenced, so
+    //! structs that borrow the data they display can derive `Gui` too.
+    //!
+    //! ## Widget count
+    //!
+    //! Deriving `Gui` on a struct also defines an associated
+    //! `IMGUI_WIDGET_COUNT: usize` constant, with the number of widget
+    //! annotations of its fields. Every annotation counts as one widget
+    //! (layout ones such as `separator` included), except for the modifiers
+    //! (`newtype`, `borrow`, `lock`, `deref` and `order`). `nested`, `group`
+    //! and tab annotations count as a single widget, regardless of the widgets
+    //! of the inner type. Fields without annotations don't count.
+    //!
+    //! The widgets themselves are described by the associated
+    //! `IMGUI_WIDGETS: &'static [WidgetInfo]` constant (one
+    //! [`WidgetInfo`](../struct.WidgetInfo.html) per counted widget, in
+    //! declaration order), which generic editors can read at runtime:
+    //!
+    //! ```
+    //! #[derive(imgui_ext::Gui)]
+    //! struct Light {
+    //!     #[imgui(slider(min = 0.0, max = 10.0, label = "Intensity"))]
+    //!     intensity: f32,
+    //! }
+    //!
+    //! let slider = &Light::IMGUI_WIDGETS[0];
+    //! assert_eq!("intensity", slider.field);
+    //! assert_eq!("slider", slider.kind);
+    //! assert_eq!(Some("Intensity"), slider.label);
+    //! assert_eq!((Some(0.0), Some(10.0)), (slider.min, slider.max));
+    //! ```
 }
 /// `display(...)` docs.
 pub mod display {
@@ -306,6 +545,11 @@ pub mod nested {
     //! # Optional fields
     //!
     //! * `catch`
+    //! * `id` string or integer pushed to the ID stack while the nested UI is
+    //!   drawn. Useful when the same type is nested more than once, so the
+    //!   widgets of each field don't clash.
+    //! * `columns` integer. Lays the fields of the nested UI into columns (see
+    //!   [columns](#columns)).
     //!
     //! # Example
     //!
@@ -340,9 +584,51 @@ pub mod nested {
     //!
     //! [result]: https://i.imgur.com/l6omyf4.png
     //!
+    //! # Optional nested UIs
+    //!
+    //! `Option<T>` can also be nested, as long as `T` implements `Default`. An
+    //! "Enabled" checkbox is rendered before the nested UI, which switches the
+    //! field between `None` and `Some(T::default())`. The nested events (an
+    //! [`OptionEvents`](../struct.OptionEvents.html)) report when the checkbox
+    //! is toggled, and hold the events of `T`, which are `None` while the field
+    //! is disabled.
+    //!
+    //! ```
+    //! #[derive(imgui_ext::Gui, Default)]
+    //! struct Shadows {
+    //!     #[imgui(slider(min = 0.0, max = 1.0))]
+    //!     bias: f32,
+    //! }
+    //!
+    //! #[derive(imgui_ext::Gui)]
+    //! struct Example {
+    //!     #[imgui(nested)]
+    //!     shadows: Option<Shadows>,
+    //! }
+    //! ```
+    //!
+    //! # Generic nested UIs
+    //!
+    //! The nested type can be a type parameter. The derive adds the
+    //! `T: Gui` bound (for every nested type that depends on a type
+    //! parameter), and the events type gets the same generic parameters, so
+    //! the nested events are still typed:
+    //!
+    //! ```
+    //! #[derive(imgui_ext::Gui)]
+    //! struct Named<T> {
+    //!     #[imgui(display)]
+    //!     name: String,
+    //!     #[imgui(nested)]
+    //!     inner: T,
+    //! }
+    //! ```
+    //!
     //! # Nested input events
     //!
-    //! You can access input events from nested UIs:
+    //! You can access input events from nested UIs. Accessors of nested events
+    //! return a reference (`&<T as Gui>::Events`), so they are never copied nor
+    //! cloned:
     //!
     //! ```ignore
     //! // initialize imgui (ui) ...
@@ -357,6 +643,80 @@ pub mod nested {
     //!     )
     //! }
     //! ```
+    //!
+    //! # Columns
+    //!
+    //! `nested(columns = N)` lays the fields of the nested UI into `N` columns,
+    //! one field per column (wrapping to a new row after the last one). The
+    //! layout is reset to a single column after the nested UI.
+    //!
+    //! Only the fields of the nested type itself are laid out, the UIs nested
+    //! inside of it are drawn within a single column. Types that implement
+    //! `Gui` by hand are drawn as usual, inside of the first column.
+    //!
+    //! ```
+    //! #[derive(imgui_ext::Gui)]
+    //! struct Size {
+    //!     #[imgui(drag(label = "W"))]
+    //!     width: f32,
+    //!     #[imgui(drag(label = "H"))]
+    //!     height: f32,
+    //! }
+    //!
+    //! #[derive(imgui_ext::Gui)]
+    //! struct Example {
+    //!     #[imgui(nested(columns = 2))]
+    //!     size: Size,
+    //! }
+    //! ```
+    use imgui::{im_str, ImStr, Ui};
+
+    /// Nested UI laid into columns. Must be ended with `end`.
+    pub struct ColumnsToken(());
+
+    impl ColumnsToken {
+        pub fn end(self, ui: &Ui) {
+            ui.columns(1, im_str!(""), false);
+        }
+    }
+
+    /// Begins `count` columns for the next nested UI, which is then drawn
+    /// with [`Gui::draw_gui_columns`](../trait.Gui.html). This is what
+    /// `nested(columns = N)` expands to.
+    pub fn begin_columns(ui: &Ui, id: &ImStr, count: i32) -> ColumnsToken {
+        ui.columns(count, id, false);
+        ColumnsToken(())
+    }
+}
+/// `group(...)` docs (nested UIs inside of a child region).
+pub mod group {
+    //!
+    //! Same as `nested`, but the nested UI is drawn inside of a child region,
+    //! which scrolls when the contents don't fit.
+    //!
+    //! # Optional fields
+    //!
+    //! * `border` flag. Draws a border around the region.
+    //! * `size` size of the region. Either a path to a function that returns
+    //!   the size, or an expression (`"[0.0, 120.0]"`). A size of `0.0` takes
+    //!   the remaining space.
+    //! * `catch`
+    //!
+    //! # Example
+    //!
+    //! ```
+    //! #[derive(imgui_ext::Gui)]
+    //! struct Light {
+    //!     #[imgui(slider(min = 0.0, max = 10.0))]
+    //!     intensity: f32,
+    //! }
+    //!
+    //! #[derive(imgui_ext::Gui)]
+    //! struct Example {
+    //!     #[imgui(group(border, size = "[0.0, 120.0]"))]
+    //!     light: Light,
+    //! }
+    //! ```
 }
 /// `button(...)` docs.
 pub mod button {
@@ -369,8 +729,18 @@ pub mod button {
     //!
     //! # Optional fields
     //!
-    //! - `size` path to a function that returns the button size.
+    //! - `size` path to a function that returns the button size, or an array
+    //!   literal (`size = "[100.0, 20.0]"`). The size is passed to imgui as-is:
+    //!   a component of `0.0` fits the label, and a negative component `-n`
+    //!   stretches the button up to `n` pixels from the edge of the available
+    //!   region (so `-1.0` takes all of the available width or height).
+    //! - `fill` stretch the button to the full available width. Same as
+    //!   `size = "[-1.0, 0.0]"`, so it can't be combined with `size`.
     //! - `catch`
+    //! - `disabled_if` name of a sibling `bool` field. The button is faded out,
+    //!   and its clicks ignored, while the field is `true` (or `false`, with
+    //!   `disabled_if = "!field"`).
+    //! - `same_line` place the button on the same line as the previous widget.
     //!
     //! # Example
     //!
@@ -443,18 +813,146 @@ pub mod bullet {
 pub trait Gui {
     type Events;
     fn draw_gui(ui: &Ui, ext: &mut Self) -> Self::Events;
+
+    /// Same as `draw_gui`, but the widgets are drawn inside of a group, and
+    /// the screen rectangle they take (`[min, max]`) is returned along with
+    /// the events. Useful to place UI next to them.
+    fn draw_gui_rect(ui: &Ui, ext: &mut Self) -> (Self::Events, [[f32; 2]; 2]) {
+        let min = ui.cursor_screen_pos();
+        unsafe { sys::igBeginGroup() };
+        let events = Self::draw_gui(ui, ext);
+        unsafe { sys::igEndGroup() };
+        let [width, height] = ui.get_item_rect_size();
+        (events, [min, [min[0] + width, min[1] + height]])
+    }
+
+    /// Same as `draw_gui`, but the `size` params of the widgets (of nested UIs
+    /// included) are multiplied by `scale`, so fixed sizes follow the DPI of
+    /// the display:
+    ///
+    /// ```ignore
+    /// let [scale, _] = ui.io().display_framebuffer_scale;
+    /// let events = Example::draw_gui_scaled(&ui, &mut example, scale);
+    /// ```
+    ///
+    /// Scales compose: a scaled UI drawn from another one is multiplied by
+    /// both factors. Only sizes are scaled: the `speed` of drags is left as
+    /// is.
+    fn draw_gui_scaled(ui: &Ui, ext: &mut Self, scale: f32) -> Self::Events {
+        with_scale(scale, || Self::draw_gui(ui, ext))
+    }
+
+    /// Same as `draw_gui`, but when `columns` is `true`, the widgets of each
+    /// field move to the next column of the current layout. This is what
+    /// `nested(columns = N)` calls. Types that implement `Gui` by hand draw
+    /// themselves as usual.
+    #[doc(hidden)]
+    fn draw_gui_columns(ui: &Ui, ext: &mut Self, _columns: bool) -> Self::Events {
+        Self::draw_gui(ui, ext)
+    }
+}
+
+/// Description of a widget annotation, as found in the `IMGUI_WIDGETS` table of
+/// derived structs (see [misc](misc/index.html#widget-count)).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WidgetInfo {
+    /// Name of the annotated field.
+    pub field: &'static str,
+    /// Name of the annotation (`"slider"`, `"checkbox"`, `"color(edit)"`...).
+    pub kind: &'static str,
+    /// Label of the widget (the field name, unless overridden). `None` for
+    /// annotations without a label.
+    pub label: Option<&'static str>,
+    /// `min` of `slider` & `drag` annotations, if given as a literal.
+    pub min: Option<f64>,
+    /// `max` of `slider` & `drag` annotations, if given as a literal.
+    pub max: Option<f64>,
 }
 
-impl<T: Gui> Gui for Option<T> {
+/// Default bounds of the values of a type, read by `slider(bounded)` and
+/// `drag(bounded)`. `T` is the type of the value edited by the widget.
+///
+/// Bounds given in the annotation take precedence over the ones of the type:
+///
+/// ```
+/// use imgui_ext::Bounded;
+///
+/// struct Volume(f32);
+///
+/// impl Bounded for Volume {
+///     fn imgui_bounds() -> (f32, f32) {
+///         (0.0, 1.0)
+///     }
+/// }
+///
+/// #[derive(imgui_ext::Gui)]
+/// struct Mixer {
+///     // min = 0.0, max = 1.0
+///     #[imgui(newtype, slider(bounded))]
+///     master: Volume,
+///     // min = 0.0, max = 0.5
+///     #[imgui(newtype, slider(bounded, max = 0.5))]
+///     music: Volume,
+/// }
+/// ```
+pub trait Bounded<T = f32> {
+    /// `(min, max)`
+    fn imgui_bounds() -> (T, T);
+}
+
+/// Trait implemented by the derive macro in previous versions.
+///
+/// It is implemented for every type that implements [`Gui`](trait.Gui.html),
+/// so code written against the old API keeps compiling while it is migrated.
+#[deprecated(since = "0.4.0", note = "Use `Gui` instead.")]
+pub trait ImGuiExt {
+    type Events;
+    fn imgui_ext(ui: &Ui, ext: &mut Self) -> Self::Events;
+}
+
+#[allow(deprecated)]
+impl<T: Gui> ImGuiExt for T {
     type Events = T::Events;
+    #[inline]
+    fn imgui_ext(ui: &Ui, ext: &mut Self) -> Self::Events {
+        T::draw_gui(ui, ext)
+    }
+}
+
+/// Events of an optional UI (see the `Gui` implementation of `Option<T>`).
+pub struct OptionEvents<E> {
+    toggled: bool,
+    events: Option<E>,
+}
+
+impl<E> OptionEvents<E> {
+    /// Whether the "Enabled" checkbox was toggled (the value switched between
+    /// `None` and `Some`).
+    pub fn toggled(&self) -> bool {
+        self.toggled
+    }
+
+    /// Events of the nested UI, `None` while the value is `None`.
+    pub fn events(&self) -> Option<&E> {
+        self.events.as_ref()
+    }
+}
+
+/// Optional UIs are rendered with an extra "Enabled" checkbox, which toggles
+/// between `None` and `Some(T::default())`.
+impl<T: Gui + Default> Gui for Option<T> {
+    type Events = OptionEvents<T::Events>;
 
-    // TODO remove unsafe
     fn draw_gui(ui: &Ui, ext: &mut Self) -> Self::Events {
-        if let Some(ref mut ext) = ext {
-            T::draw_gui(ui, ext)
-        } else {
-            unsafe { std::mem::zeroed() }
+        let id = ui.push_id(ext as *const Self);
+        let mut enabled = ext.is_some();
+        let toggled = ui.checkbox(imgui::im_str!("Enabled"), &mut enabled);
+        if toggled {
+            *ext = if enabled { Some(T::default()) } else { None };
         }
+        let events = ext.as_mut().map(|ext| T::draw_gui(ui, ext));
+        id.pop(ui);
+        OptionEvents { toggled, events }
     }
 }
 
@@ -464,6 +962,10 @@ impl<T: Gui> Gui for Box<T> {
     fn draw_gui(ui: &Ui, ext: &mut Self) -> Self::Events {
         T::draw_gui(ui, ext.as_mut())
     }
+    #[inline]
+    fn draw_gui_columns(ui: &Ui, ext: &mut Self, columns: bool) -> Self::Events {
+        T::draw_gui_columns(ui, ext.as_mut(), columns)
+    }
 }
 
 /// Extension trait for imgui's [`Ui`](https://docs.rs/imgui/*/imgui/struct.Ui.html).
@@ -490,8 +992,27 @@ impl<T: Gui> Gui for Box<T> {
 ///
 /// ui.draw_gui(&mut example);
 /// ```
+///
+/// Types that wrap a `Ui` (such as the frame guard of a framework) don't need
+/// anything special, as long as they implement `Deref<Target = Ui>`: the
+/// method is found through auto-deref (`frame.draw_gui(&mut example)`), and
+/// `&Frame` coerces to `&Ui` when calling [`Gui::draw_gui`](trait.Gui.html)
+/// directly, even from generic code (`U: Deref<Target = Ui<'ui>>`).
 pub trait UiExt {
     fn draw_gui<U: Gui>(&self, ext: &mut U) -> U::Events;
+
+    /// See [`Gui::draw_gui_rect`](trait.Gui.html#method.draw_gui_rect).
+    fn draw_gui_rect<U: Gui>(&self, ext: &mut U) -> (U::Events, [[f32; 2]; 2]);
+
+    /// See [`Gui::draw_gui_scaled`](trait.Gui.html#method.draw_gui_scaled).
+    fn draw_gui_scaled<U: Gui>(&self, ext: &mut U, scale: f32) -> U::Events;
+
+    /// Name of `draw_gui` in previous versions.
+    #[deprecated(since = "0.4.0", note = "Use `draw_gui` instead.")]
+    #[inline]
+    fn imgui_ext<U: Gui>(&self, ext: &mut U) -> U::Events {
+        self.draw_gui(ext)
+    }
 }
 
 impl UiExt for Ui<'_> {
@@ -499,4 +1020,340 @@ impl UiExt for Ui<'_> {
     fn draw_gui<U: Gui>(&self, ext: &mut U) -> U::Events {
         U::draw_gui(self, ext)
     }
+
+    #[inline]
+    fn draw_gui_rect<U: Gui>(&self, ext: &mut U) -> (U::Events, [[f32; 2]; 2]) {
+        U::draw_gui_rect(self, ext)
+    }
+
+    #[inline]
+    fn draw_gui_scaled<U: Gui>(&self, ext: &mut U, scale: f32) -> U::Events {
+        U::draw_gui_scaled(self, ext, scale)
+    }
+}
+
+/// Converts a `&'static str` constant into a label that can be passed to imgui.
+///
+/// This is what `label = CONST` expands to. The NUL-terminated copy of the
+/// string is built at compile time, so nothing is allocated when drawing.
+///
+/// ```
+/// const LABEL: &str = "Label";
+///
+/// assert_eq!("Label", imgui_ext::static_label!(LABEL).to_str());
+/// ```
+#[macro_export]
+macro_rules! static_label {
+    ($label:expr) => {{
+        const __IMGUI_EXT_LABEL: &str = $label;
+        const __IMGUI_EXT_LEN: usize = __IMGUI_EXT_LABEL.len() + 1;
+        static __IMGUI_EXT_BYTES: [u8; __IMGUI_EXT_LEN] = {
+            let label = __IMGUI_EXT_LABEL.as_bytes();
+            let mut bytes = [0; __IMGUI_EXT_LEN];
+            let mut i = 0;
+            while i < label.len() {
+                bytes[i] = label[i];
+                i += 1;
+            }
+            bytes
+        };
+        // the array is the label followed by a single NUL byte.
+        unsafe { ::imgui::ImStr::from_utf8_with_nul_unchecked(&__IMGUI_EXT_BYTES) }
+    }};
+}
+
+thread_local! {
+    static SCALE: Cell<f32> = Cell::new(1.0);
+}
+
+/// Multiplies `size` by the scale of the UI being drawn (see
+/// [`Gui::draw_gui_scaled`](trait.Gui.html#method.draw_gui_scaled)). This is
+/// what the `size` params expand to.
+///
+/// The scale is `1.0` (sizes are left untouched) outside of `draw_gui_scaled`.
+pub fn scaled([width, height]: [f32; 2]) -> [f32; 2] {
+    let scale = SCALE.with(Cell::get);
+    [width * scale, height * scale]
+}
+
+/// Calls `draw` with the scale multiplied by `scale`, and restores it after
+/// (even if `draw` panics).
+fn with_scale<R, F: FnOnce() -> R>(scale: f32, draw: F) -> R {
+    /// Restores the parent scale when dropped.
+    struct ParentScale(f32);
+
+    impl Drop for ParentScale {
+        fn drop(&mut self) {
+            SCALE.with(|s| s.set(self.0));
+        }
+    }
+
+    let _parent = ParentScale(SCALE.with(|s| s.replace(s.get() * scale)));
+    draw()
+}
+
+/// Whether the last item was activated this frame (the mouse was pressed on
+/// it), and whether it was deactivated after its value was edited. This is
+/// what the `started` and `finished` events of drags and sliders read.
+pub fn item_edit(_: &Ui) -> (bool, bool) {
+    unsafe {
+        (
+            sys::igIsItemActivated(),
+            sys::igIsItemDeactivatedAfterEdit(),
+        )
+    }
+}
+
+/// Widgets drawn by a `disabled_if` annotation. Must be ended with `end`.
+pub struct DisabledToken(bool);
+
+impl DisabledToken {
+    /// Whether the widgets are disabled (their events are discarded).
+    pub fn is_disabled(&self) -> bool {
+        self.0
+    }
+
+    pub fn end(self, _: &Ui) {
+        if self.0 {
+            unsafe { sys::igPopStyleVar(1) }
+        }
+    }
+}
+
+/// Fades out the widgets drawn until the returned token is ended, if
+/// `disabled` is `true`. This is what `disabled_if` expands to.
+pub fn begin_disabled(_: &Ui, disabled: bool) -> DisabledToken {
+    if disabled {
+        unsafe {
+            let alpha = (*sys::igGetStyle()).Alpha;
+            sys::igPushStyleVarFloat(sys::ImGuiStyleVar_Alpha as _, alpha * 0.5);
+        }
+    }
+    DisabledToken(disabled)
+}
+
+/// Draws a vertical line as tall as a framed widget, and moves the cursor past
+/// it. This is what `separator(vertical)` expands to, after a
+/// `ui.same_line(0.0)`.
+///
+/// Vertical separators only make sense between widgets on the same line. Use
+/// `same_line` on the widget that follows it.
+pub fn vertical_separator(ui: &Ui) {
+    let [x, y] = ui.cursor_screen_pos();
+    unsafe {
+        let height = sys::igGetFrameHeight();
+        let color = sys::igGetColorU32(sys::ImGuiCol_Separator as _, 1.0);
+        let draw_list = sys::igGetWindowDrawList();
+        sys::ImDrawList_AddLine(draw_list, [x, y].into(), [x, y + height].into(), color, 1.0);
+        sys::igDummy([1.0, height].into());
+    }
+}
+
+/// Draws the `Reset` button of the `reset_to` option of drags and sliders, on
+/// the same line as the widget. `id` (the name of the field) and `id_offset`
+/// (the one of the widget, `0` without one) tell apart the buttons of
+/// different fields. Returns `true` if the button was clicked.
+pub fn reset_button(ui: &Ui, id: &str, id_offset: i32) -> bool {
+    ui.same_line(0.0);
+    let id = ui.push_id(id);
+    let offset = ui.push_id(id_offset);
+    let clicked = ui.small_button(imgui::im_str!("Reset"));
+    offset.pop(ui);
+    id.pop(ui);
+    clicked
+}
+
+/// Sets `elem` to `value()` if `reset` is `true`. This is what the `reset_to`
+/// option of drags and sliders expands to. Returns `reset`.
+pub fn reset_to<T, F: FnOnce() -> T>(reset: bool, elem: &mut T, value: F) -> bool {
+    if reset {
+        *elem = value();
+    }
+    reset
+}
+
+/// Scalar types that can be sanitized (see the `sanitize` flag of `drag(...)`
+/// and `slider(...)`) and wrapped (see the `wrap` flag of `slider(...)`).
+pub(crate) trait Finite: Copy {
+    fn is_finite(self) -> bool;
+    /// Wraps `self` into `[min, max)`.
+    fn wrap(self, min: Self, max: Self) -> Self;
+}
+
+impl Finite for f32 {
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+    fn wrap(self, min: Self, max: Self) -> Self {
+        min + (self - min).rem_euclid(max - min)
+    }
+}
+
+impl Finite for f64 {
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+    fn wrap(self, min: Self, max: Self) -> Self {
+        min + (self - min).rem_euclid(max - min)
+    }
+}
+
+impl Finite for i32 {
+    fn is_finite(self) -> bool {
+        true
+    }
+    fn wrap(self, min: Self, max: Self) -> Self {
+        let (value, min, max) = (i64::from(self), i64::from(min), i64::from(max));
+        (min + (value - min).rem_euclid(max - min)) as i32
+    }
+}
+
+impl Finite for u32 {
+    fn is_finite(self) -> bool {
+        true
+    }
+    fn wrap(self, min: Self, max: Self) -> Self {
+        let (value, min, max) = (i64::from(self), i64::from(min), i64::from(max));
+        (min + (value - min).rem_euclid(max - min)) as u32
+    }
+}
+
+impl Finite for i64 {
+    fn is_finite(self) -> bool {
+        true
+    }
+    fn wrap(self, min: Self, max: Self) -> Self {
+        let (value, min, max) = (i128::from(self), i128::from(min), i128::from(max));
+        (min + (value - min).rem_euclid(max - min)) as i64
+    }
+}
+
+/// Unsigned integers without a native imgui data type. They are edited through
+/// a signed `Proxy` type, and clamped back into their own range (so negative
+/// values become zero).
+pub(crate) trait Unsigned: Copy {
+    type Proxy: Copy;
+    fn to_proxy(self) -> Self::Proxy;
+    fn from_proxy(value: Self::Proxy) -> Self;
+}
+
+macro_rules! impl_unsigned {
+    ( $( $unsigned:ty => $proxy:ty ),* ) => {
+        $(
+            impl Unsigned for $unsigned {
+                type Proxy = $proxy;
+                fn to_proxy(self) -> $proxy {
+                    <$proxy as TryFrom<$unsigned>>::try_from(self).unwrap_or(<$proxy>::max_value())
+                }
+                fn from_proxy(value: $proxy) -> Self {
+                    if value < 0 {
+                        0
+                    } else {
+                        <$unsigned as TryFrom<$proxy>>::try_from(value)
+                            .unwrap_or(<$unsigned>::max_value())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned! { u8 => i32, u16 => i32, u64 => i64, usize => i64 }
+
+/// Replaces non-finite values (`NaN` & infinities) with `fallback`.
+pub(crate) fn sanitize<T: Finite>(values: &mut [T], fallback: T) {
+    for value in values.iter_mut().filter(|v| !v.is_finite()) {
+        *value = fallback;
+    }
+}
+
+/// Whether `new` is a different value than `old`, for the widgets that can't
+/// rely on the value returned by imgui. Unlike `!=`, two `NaN`s are the same
+/// value (and so are `-0.0` and `0.0`, as with `==`), so neither produces
+/// spurious change events.
+#[allow(clippy::eq_op)]
+pub(crate) fn differs<T: PartialEq>(old: &T, new: &T) -> bool {
+    // `NaN` is the only value that is different from itself
+    old != new && (old == old || new == new)
+}
+
+/// Wraps `values` into `[min, max)`. Does nothing if the range is empty.
+pub(crate) fn wrap<T: Finite + PartialOrd>(values: &mut [T], min: T, max: T) {
+    if min < max {
+        for value in values.iter_mut() {
+            *value = value.wrap(min, max);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn differs() {
+        use super::differs;
+
+        assert!(!differs(&std::f32::NAN, &std::f32::NAN));
+        assert!(!differs(&-0.0f64, &0.0));
+        assert!(!differs(&1.5f32, &1.5));
+        assert!(differs(&std::f32::NAN, &0.0));
+        assert!(differs(&0.0, &std::f64::NAN));
+        assert!(differs(&1, &2));
+    }
+
+    #[test]
+    fn reset_to() {
+        let mut gain = 3.0f32;
+        assert!(!super::reset_to(false, &mut gain, || 100.0));
+        assert_eq!(3.0, gain);
+        assert!(super::reset_to(true, &mut gain, || 100.0));
+        assert_eq!(100.0, gain);
+    }
+
+    #[test]
+    fn wrap() {
+        let mut angles = [370.0f32, 360.0, -90.0, 180.0];
+        super::wrap(&mut angles, 0.0, 360.0);
+        assert_eq!([10.0, 0.0, 270.0, 180.0], angles);
+
+        let mut ints = [370u32, 5, 360];
+        super::wrap(&mut ints, 10, 360);
+        assert_eq!([20, 355, 10], ints);
+
+        // empty range
+        let mut value = [42.0f64];
+        super::wrap(&mut value, 1.0, 1.0);
+        assert_eq!([42.0], value);
+    }
+
+    #[test]
+    fn unsigned() {
+        use super::Unsigned;
+
+        assert_eq!(255, 255u8.to_proxy());
+        assert_eq!(0u8, u8::from_proxy(-4));
+        assert_eq!(255u8, u8::from_proxy(1000));
+        assert_eq!(12u16, u16::from_proxy(12));
+        assert_eq!(std::i64::MAX, std::u64::MAX.to_proxy());
+        assert_eq!(0usize, usize::from_proxy(-1));
+        assert_eq!(7usize, usize::from_proxy(7));
+    }
+
+    #[test]
+    fn scale() {
+        use super::{scaled, with_scale};
+
+        assert_eq!([100.0, 20.0], scaled([100.0, 20.0]));
+        with_scale(2.0, || {
+            assert_eq!([200.0, -2.0], scaled([100.0, -1.0]));
+            // nested scales compose
+            with_scale(1.5, || assert_eq!([300.0, 0.0], scaled([100.0, 0.0])));
+            assert_eq!([200.0, 40.0], scaled([100.0, 20.0]));
+        });
+        assert_eq!([100.0, 20.0], scaled([100.0, 20.0]));
+
+        // restored when the UI panics
+        let result = std::panic::catch_unwind(|| with_scale(2.0, || panic!("draw")));
+        assert!(result.is_err());
+        assert_eq!([100.0, 20.0], scaled([100.0, 20.0]));
+    }
 }
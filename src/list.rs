@@ -0,0 +1,107 @@
+//! `list` edits a `Vec<T>` of types that implement `Gui` and `Default`.
+//!
+//! Each element is drawn with its own UI, under a tree node labeled with its
+//! index, next to a button that removes it. A button after the last element
+//! appends a `T::default()`.
+//!
+//! Elements are added and removed after the whole list is drawn, so the
+//! indices stay valid while iterating.
+//!
+//! ## Optional params
+//!
+//! * `label` prefix of the tree node labels (the name of the field by
+//!   default). The nodes are labeled `label[index]`.
+//! * `catch` name of the event, triggered when an element is added or
+//!   removed. Edits made to the elements themselves don't trigger it.
+//!
+//! ## Example
+//!
+//! ```
+//! #[derive(Default, imgui_ext::Gui)]
+//! struct Item {
+//!     #[imgui(input)]
+//!     name: String,
+//!     #[imgui(drag(min = 0))]
+//!     count: i32,
+//! }
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Inventory {
+//!     // `events.items()` is true when an item is added or removed.
+//!     #[imgui(list(label = "Item"))]
+//!     items: Vec<Item>,
+//! }
+//! ```
+use imgui::sys;
+use imgui::{im_str, ImStr, ImString, Ui};
+
+use crate::Gui;
+
+/// Structure generated by the `list` annotation.
+pub struct ListParams<'a> {
+    pub label: &'a ImStr,
+}
+
+/// Structural change requested while drawing the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change {
+    Add,
+    Remove(usize),
+}
+
+/// Applies `change` to `list`, once it's no longer being iterated.
+fn apply<T: Default>(list: &mut Vec<T>, change: Option<Change>) -> bool {
+    match change {
+        Some(Change::Add) => list.push(T::default()),
+        Some(Change::Remove(index)) if index < list.len() => {
+            list.remove(index);
+        }
+        Some(Change::Remove(_)) | None => return false,
+    }
+    true
+}
+
+/// Draws every element of `list`, and the buttons that add and remove them.
+/// Returns `true` if an element was added or removed.
+pub fn build<T: Gui + Default>(ui: &Ui, list: &mut Vec<T>, params: ListParams) -> bool {
+    let mut change = None;
+
+    for (index, elem) in list.iter_mut().enumerate() {
+        let id = ui.push_id(index as i32);
+        let label = ImString::new(format!("{}[{}]", params.label.to_str(), index));
+        let open = unsafe { sys::igTreeNodeStr(label.as_ptr()) };
+        ui.same_line(0.0);
+        if ui.small_button(im_str!("Remove")) {
+            change = Some(Change::Remove(index));
+        }
+        if open {
+            T::draw_gui(ui, elem);
+            unsafe { sys::igTreePop() };
+        }
+        id.pop(ui);
+    }
+
+    if ui.small_button(im_str!("Add")) {
+        change = Some(Change::Add);
+    }
+
+    apply(list, change)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply, Change};
+
+    #[test]
+    fn changes() {
+        let mut list = vec![1, 2, 3];
+        assert!(apply(&mut list, Some(Change::Remove(1))));
+        assert_eq!(vec![1, 3], list);
+        assert!(apply(&mut list, Some(Change::Add)));
+        assert_eq!(vec![1, 3, 0], list);
+
+        assert!(!apply(&mut list, Some(Change::Remove(3))));
+        assert!(!apply(&mut list, None));
+        assert_eq!(vec![1, 3, 0], list);
+    }
+}
@@ -4,6 +4,10 @@ macro_rules! imgui_drag_scalar {
             fn build(_: &Ui, elem: &mut Self, params: DragParams<$scalar>) -> bool {
                 use std::{mem, ptr};
 
+                if params.sanitize {
+                    crate::sanitize(std::slice::from_mut(elem), params.min.unwrap_or_default());
+                }
+
                 let label = params.label.as_ptr();
                 let min = params.min.as_ref();
                 let max = params.min.as_ref();
@@ -29,7 +33,12 @@ macro_rules! imgui_drag_scalar {
     ( ( $head:ty, $($scalar:ty ,)* ), $len:expr, $variant:expr ) => {
         impl Drag<$head> for ( $head, $($scalar),* ) {
             fn build(_: &Ui, elem: &mut Self, params: DragParams<$head>) -> bool {
-                use std::{mem, ptr};
+                use std::{mem, ptr, slice};
+
+                if params.sanitize {
+                    let values = unsafe { slice::from_raw_parts_mut(elem as *mut Self as *mut $head, $len) };
+                    crate::sanitize(values, params.min.unwrap_or_default());
+                }
 
                 let label = params.label.as_ptr();
                 let min = params.min.as_ref();
@@ -72,14 +81,31 @@ macro_rules! imgui_slider_scalar {
             fn build(_: &Ui, elem: &mut Self, params: SliderParams<$scalar>) -> bool {
                 use std::{mem, ptr};
 
+                if params.sanitize {
+                    crate::sanitize(std::slice::from_mut(elem), params.min);
+                }
+
+                if params.logarithmic {
+                    if let Some(changed) = crate::slider::build_logarithmic(elem, &params) {
+                        return crate::slider::keyboard_step(elem, &params) || changed;
+                    }
+                }
+
                 let label = params.label.as_ptr();
                 let min = &params.min;
                 let max = &params.max;
-                let format = ptr::null();
+                let display = params
+                    .format_value
+                    .map(|format_value| crate::slider::display(*elem, format_value));
+                let format = display
+                    .as_ref()
+                    .map(|display| &**display)
+                    .or(params.format)
+                    .map_or(ptr::null(), |format| format.as_ptr());
                 let power = params.power.unwrap_or(1.0);
                 let data_type = $variant as _;
 
-                unsafe {
+                let changed = unsafe {
                     sys::igSliderScalar(label,
                                         data_type,
                                         elem as *const Self as _,
@@ -87,7 +113,13 @@ macro_rules! imgui_slider_scalar {
                                         mem::transmute(max),
                                         format,
                                         power)
+                };
+
+                if params.wrap && changed {
+                    crate::wrap(std::slice::from_mut(elem), params.min, params.max);
                 }
+
+                crate::slider::keyboard_step(elem, &params) || changed
             }
         }
     };
@@ -95,7 +127,12 @@ macro_rules! imgui_slider_scalar {
     ( ( $head:ty $(, $scalar:ty)+ ), $len:expr, $variant:expr ) => {
         impl Slider<$head> for ( $head, $($scalar),+ ) {
             fn build(_: &Ui, elem: &mut Self, params: SliderParams<$head>) -> bool {
-                use std::{mem, ptr};
+                use std::{mem, ptr, slice};
+
+                if params.sanitize {
+                    let values = unsafe { slice::from_raw_parts_mut(elem as *mut Self as *mut $head, $len) };
+                    crate::sanitize(values, params.min);
+                }
 
                 let label = params.label.as_ptr();
                 let min = &params.min;
@@ -104,7 +141,7 @@ macro_rules! imgui_slider_scalar {
                 let power = params.power.unwrap_or(1.0);
                 let data_type = $variant as i32;
 
-                unsafe {
+                let changed = unsafe {
                     sys::igSliderScalarN(label,
                                          data_type,
                                          elem as *const Self as _,
@@ -113,7 +150,14 @@ macro_rules! imgui_slider_scalar {
                                          mem::transmute(max),
                                          format,
                                          power)
+                };
+
+                if params.wrap && changed {
+                    let values = unsafe { slice::from_raw_parts_mut(elem as *mut Self as *mut $head, $len) };
+                    crate::wrap(values, params.min, params.max);
                 }
+
+                changed
             }
         }
 
@@ -134,15 +178,16 @@ macro_rules! imgui_input_scalar {
     ( ($scalar:ty ), $len:expr, $variant: expr ) => {
         impl Input<$scalar> for $scalar {
             fn build(_: &Ui, elem: &mut Self, params: InputParams<$scalar>) -> bool {
-                use std::{mem, ptr};
+                use std::{mem, ptr, slice};
                 let label = params.label.as_ptr();
                 let step = params.step.as_ref();
                 let step_fast = params.step_fast.as_ref();
-                let format = ptr::null();
+                let format = params.format.map_or(ptr::null(), |format| format.as_ptr());
                 let flags = params.flags.unwrap_or(imgui::ImGuiInputTextFlags::empty());
                 let data_type = $variant as i32;
+                let prev = *elem;
 
-                unsafe {
+                let changed = unsafe {
                     sys::igInputScalar(label,
                                        data_type,
                                        elem as *const Self as _,
@@ -150,7 +195,9 @@ macro_rules! imgui_input_scalar {
                                        mem::transmute(step_fast),
                                        format,
                                        flags.bits())
-                }
+                };
+
+                changed && crate::input::revert_non_finite(slice::from_ref(&prev), slice::from_mut(elem))
             }
         }
     };
@@ -158,16 +205,17 @@ macro_rules! imgui_input_scalar {
     ( ( $head:ty $(, $scalar:ty)+ ), $len:expr, $variant:expr ) => {
         impl Input<$head> for ( $head, $($scalar),* ) {
             fn build(_: &Ui, elem: &mut Self, params: InputParams<$head>) -> bool {
-                use std::{mem, ptr};
+                use std::{mem, ptr, slice};
 
                 let label = params.label.as_ptr();
                 let step = params.step.as_ref();
                 let step_fast = params.step_fast.as_ref();
-                let format = ptr::null();
+                let format = params.format.map_or(ptr::null(), |format| format.as_ptr());
                 let flags = params.flags.unwrap_or(imgui::ImGuiInputTextFlags::empty());
                 let data_type = $variant as i32;
+                let prev = *elem;
 
-                unsafe {
+                let changed = unsafe {
                     sys::igInputScalarN(label,
                                         data_type,
                                         elem as *const Self as _,
@@ -176,6 +224,12 @@ macro_rules! imgui_input_scalar {
                                         mem::transmute(step_fast),
                                         format,
                                         flags.bits())
+                };
+
+                changed && unsafe {
+                    let prev = slice::from_raw_parts(&prev as *const Self as *const $head, $len);
+                    let values = slice::from_raw_parts_mut(elem as *mut Self as *mut $head, $len);
+                    crate::input::revert_non_finite(prev, values)
                 }
             }
         }
@@ -212,7 +266,7 @@ macro_rules! imgui_input_matrix {
 
                     let step = params.step.as_ref();
                     let step_fast = params.step_fast.as_ref();
-                    let format = std::ptr::null();
+                    let format = params.format.map_or(std::ptr::null(), |format| format.as_ptr());
                     let flags = params.flags.unwrap_or(imgui::ImGuiInputTextFlags::empty());
 
                     trigger |= sys::igInputScalarN(params.label.as_ptr(),
@@ -237,7 +291,7 @@ macro_rules! imgui_input_matrix {
 
                         let step = params.step.as_ref();
                         let step_fast = params.step_fast.as_ref();
-                        let format = std::ptr::null();
+                        let format = params.format.map_or(std::ptr::null(), |format| format.as_ptr());
                         let flags = params.flags.unwrap_or(imgui::ImGuiInputTextFlags::empty());
 
                         trigger |= sys::igInputScalarN(imgui::im_str!("##").as_ptr(),
@@ -274,6 +328,12 @@ macro_rules! imgui_drag_matrix {
             fn build(ui: &Ui, elem: &mut Self, params: DragParams<$head>) -> bool {
                 let mut trigger = false;
 
+                if params.sanitize {
+                    for row in elem.iter_mut() {
+                        crate::sanitize(row, params.min.unwrap_or_default());
+                    }
+                }
+
                 #[allow(unused_mut, unused_variables)]
                 let mut index = 0;
 
@@ -350,6 +410,12 @@ macro_rules! imgui_slider_matrix {
             fn build(ui: &Ui, elem: &mut Self, params: SliderParams<$head>) -> bool {
                 let mut trigger = false;
 
+                if params.sanitize {
+                    for row in elem.iter_mut() {
+                        crate::sanitize(row, params.min);
+                    }
+                }
+
                 #[allow(unused_mut)]
                 let mut index = 0;
 
@@ -401,6 +467,12 @@ macro_rules! imgui_slider_matrix {
                     }
                 )*
 
+                if params.wrap && trigger {
+                    for row in elem.iter_mut() {
+                        crate::wrap(row, params.min, params.max);
+                    }
+                }
+
                 trigger
             }
         }
@@ -409,3 +481,117 @@ macro_rules! imgui_slider_matrix {
         imgui_slider_matrix! { ($($tail),*), ($size-1), $size_2, $kind }
     }
 }
+
+// Impls for the unsigned types without a native imgui data type (see
+// `crate::Unsigned`).
+
+macro_rules! imgui_slider_unsigned {
+    ( $( $scalar:ty ),* ) => {
+        $(
+            impl Slider<$scalar> for $scalar {
+                fn build(ui: &Ui, elem: &mut Self, params: SliderParams<$scalar>) -> bool {
+                    use crate::Unsigned;
+
+                    let mut value = (*elem).to_proxy();
+                    // `format_value` takes the unsigned type, so the text is
+                    // formatted here and shown as the (escaped) format.
+                    let display = params
+                        .format_value
+                        .map(|format_value| crate::slider::display(*elem, format_value));
+                    let params = SliderParams {
+                        min: params.min.to_proxy(),
+                        max: params.max.to_proxy(),
+                        label: params.label,
+                        format: display.as_ref().map(|display| &**display).or(params.format),
+                        power: params.power,
+                        sanitize: params.sanitize,
+                        wrap: params.wrap,
+                        logarithmic: params.logarithmic,
+                        keyboard_step: params.keyboard_step,
+                        format_value: None,
+                    };
+                    let changed = Slider::build(ui, &mut value, params);
+                    if changed {
+                        *elem = <$scalar>::from_proxy(value);
+                    }
+                    changed
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! imgui_drag_unsigned {
+    ( $( $scalar:ty ),* ) => {
+        $(
+            impl Drag<$scalar> for $scalar {
+                fn build(ui: &Ui, elem: &mut Self, params: DragParams<$scalar>) -> bool {
+                    use crate::Unsigned;
+
+                    let mut value = (*elem).to_proxy();
+                    let params = DragParams {
+                        label: params.label,
+                        format: params.format,
+                        min: params.min.map(Unsigned::to_proxy),
+                        max: params.max.map(Unsigned::to_proxy),
+                        speed: params.speed,
+                        power: params.power,
+                        sanitize: params.sanitize,
+                        unit: params.unit,
+                    };
+                    let changed = Drag::build(ui, &mut value, params);
+                    if changed {
+                        *elem = <$scalar>::from_proxy(value);
+                    }
+                    changed
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! imgui_input_unsigned {
+    ( $( $scalar:ty ),* ) => {
+        $(
+            impl Input<$scalar> for $scalar {
+                fn build(ui: &Ui, elem: &mut Self, params: InputParams<$scalar>) -> bool {
+                    use crate::Unsigned;
+
+                    let mut value = (*elem).to_proxy();
+                    let params = InputParams {
+                        label: params.label,
+                        step: params.step.map(Unsigned::to_proxy),
+                        step_fast: params.step_fast.map(Unsigned::to_proxy),
+                        flags: params.flags,
+                        size: params.size,
+                        buffer_size: params.buffer_size,
+                        max_len: params.max_len,
+                        unit: params.unit,
+                        completion: params.completion,
+                        sanitize: params.sanitize,
+                        format: params.format,
+                    };
+                    let changed = Input::build(ui, &mut value, params);
+                    if changed {
+                        *elem = <$scalar>::from_proxy(value);
+                    }
+                    changed
+                }
+            }
+        )*
+    };
+}
+
+// C string buffers (`[u8; N]`), edited through the `[u8]` impl.
+macro_rules! imgui_input_cstr {
+    ( $( $len:expr ),* ) => {
+        $(
+            impl Input<()> for [u8; $len] {
+                #[inline]
+                fn build(ui: &Ui, elem: &mut Self, params: InputParams<()>) -> bool {
+                    Input::build(ui, &mut elem[..], params)
+                }
+            }
+        )*
+    };
+}
@@ -0,0 +1,119 @@
+//! `drag(...)`, `slider(...)` and `input(...)` can annotate the numeric
+//! wrappers of the standard library:
+//!
+//! * `Wrapping<T>` edits the inner value, for any `T` supported by the widget.
+//! * `NonZeroU8`, `NonZeroU16`, `NonZeroU32`, `NonZeroU64` and
+//!   `NonZeroUsize` are edited as their integer type. Zero is rejected when the
+//!   value is written back, and the field keeps its previous value.
+//!
+//! ## Example
+//!
+//! ```
+//! use std::num::{NonZeroU32, Wrapping};
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Generator {
+//!     #[imgui(drag)]
+//!     seed: Wrapping<u32>,
+//!     #[imgui(slider(min = 1, max = 64))]
+//!     samples: NonZeroU32,
+//! }
+//! ```
+use imgui::Ui;
+
+use std::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping};
+
+use crate::drag::{Drag, DragParams};
+use crate::input::{Input, InputParams};
+use crate::slider::{Slider, SliderParams};
+
+impl<T, D: Drag<T>> Drag<T> for Wrapping<D> {
+    #[inline]
+    fn build(ui: &Ui, elem: &mut Self, params: DragParams<T>) -> bool {
+        D::build(ui, &mut elem.0, params)
+    }
+}
+
+impl<T, S: Slider<T>> Slider<T> for Wrapping<S> {
+    #[inline]
+    fn build(ui: &Ui, elem: &mut Self, params: SliderParams<T>) -> bool {
+        S::build(ui, &mut elem.0, params)
+    }
+}
+
+impl<T, I: Input<T>> Input<T> for Wrapping<I> {
+    #[inline]
+    fn build(ui: &Ui, elem: &mut Self, params: InputParams<T>) -> bool {
+        I::build(ui, &mut elem.0, params)
+    }
+}
+
+/// Writes the edited `value` into `elem`. `None` (an invalid value) leaves
+/// `elem` untouched. Returns `true` if `elem` changed.
+fn write_back<N: Copy + PartialEq>(elem: &mut N, value: Option<N>) -> bool {
+    match value {
+        Some(value) if crate::differs(elem, &value) => {
+            *elem = value;
+            true
+        }
+        _ => false,
+    }
+}
+
+macro_rules! impl_non_zero {
+    ( $( $non_zero:ty => $int:ty ),* ) => {
+        $(
+            impl Drag<$int> for $non_zero {
+                fn build(ui: &Ui, elem: &mut Self, params: DragParams<$int>) -> bool {
+                    let mut value = elem.get();
+                    <$int as Drag<$int>>::build(ui, &mut value, params)
+                        && write_back(elem, <$non_zero>::new(value))
+                }
+            }
+
+            impl Slider<$int> for $non_zero {
+                fn build(ui: &Ui, elem: &mut Self, params: SliderParams<$int>) -> bool {
+                    let mut value = elem.get();
+                    <$int as Slider<$int>>::build(ui, &mut value, params)
+                        && write_back(elem, <$non_zero>::new(value))
+                }
+            }
+
+            impl Input<$int> for $non_zero {
+                fn build(ui: &Ui, elem: &mut Self, params: InputParams<$int>) -> bool {
+                    let mut value = elem.get();
+                    <$int as Input<$int>>::build(ui, &mut value, params)
+                        && write_back(elem, <$non_zero>::new(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero! {
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroUsize => usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::write_back;
+
+    use std::num::NonZeroU32;
+
+    #[test]
+    fn non_zero() {
+        let mut elem = NonZeroU32::new(4).unwrap();
+        assert!(write_back(&mut elem, NonZeroU32::new(8)));
+        assert_eq!(8, elem.get());
+
+        // zero reverts to the previous value
+        assert!(!write_back(&mut elem, NonZeroU32::new(0)));
+        assert_eq!(8, elem.get());
+
+        assert!(!write_back(&mut elem, NonZeroU32::new(8)));
+    }
+}
@@ -0,0 +1,119 @@
+//!
+//! It has two variants:
+//!
+//! * `plot(lines(...))` line plot.
+//! * `plot(histogram(...))` histogram.
+//!
+//! Works on any type that implements `AsRef<[f32]>` (`Vec<f32>`, `[f32; N]`,
+//! ...).
+//!
+//! ## Optional params
+//!
+//! * `label` override widget label (a string literal, or the path of a
+//!   `&'static str` constant).
+//! * `label_inline` render the label before the widget, instead of after it.
+//! * `overlay` overlay text.
+//! * `size` path to a function that returns the size of the graph.
+//! * `min` & `max` scale of the graph. Computed from the values when omitted.
+//! * `offset` index of the first value to plot. Either an integer, or the name
+//!   of a sibling field holding the offset (for example, the head of a ring
+//!   buffer).
+//! * `stride` distance between consecutive values, in bytes. Must be a
+//!   multiple of `size_of::<f32>()` (so the values stay aligned).
+//!
+//! ## Example
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! struct Telemetry {
+//!     // Ring buffer. The oldest value is stored at `head`.
+//!     #[imgui(plot(lines(offset = "head", min = 0.0, max = 1.0)))]
+//!     samples: [f32; 32],
+//!     head: usize,
+//!
+//!     // Plot every other value.
+//!     #[imgui(plot(histogram(stride = 8)))]
+//!     bins: Vec<f32>,
+//! }
+//! ```
+use imgui::sys;
+use imgui::{ImStr, Ui};
+
+use std::{mem, ptr};
+
+pub struct PlotParams<'a> {
+    pub label: &'a ImStr,
+    pub overlay: Option<&'a ImStr>,
+    pub size: Option<[f32; 2]>,
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    pub offset: Option<usize>,
+    pub stride: Option<usize>,
+}
+
+impl PlotParams<'_> {
+    /// Number of values that can be reached with the configured stride.
+    fn count(&self, values: &[f32]) -> usize {
+        let stride = self.stride.unwrap_or(mem::size_of::<f32>());
+        assert!(stride >= mem::size_of::<f32>() && stride % mem::size_of::<f32>() == 0);
+        if values.is_empty() {
+            0
+        } else {
+            (values.len() - 1) * mem::size_of::<f32>() / stride + 1
+        }
+    }
+}
+
+pub trait PlotLines {
+    fn build(ui: &Ui, elem: &Self, params: PlotParams);
+}
+
+pub trait PlotHistogram {
+    fn build(ui: &Ui, elem: &Self, params: PlotParams);
+}
+
+impl<T: AsRef<[f32]>> PlotLines for T {
+    fn build(_: &Ui, elem: &Self, params: PlotParams) {
+        let values = elem.as_ref();
+        let count = params.count(values);
+        let offset = params.offset.unwrap_or(0);
+        let overlay = params.overlay.map(|o| o.as_ptr()).unwrap_or(ptr::null());
+        let stride = params.stride.unwrap_or(mem::size_of::<f32>());
+        unsafe {
+            sys::igPlotLines(
+                params.label.as_ptr(),
+                values.as_ptr(),
+                count as _,
+                offset as _,
+                overlay,
+                params.min.unwrap_or(std::f32::MAX),
+                params.max.unwrap_or(std::f32::MAX),
+                params.size.unwrap_or([0.0, 0.0]).into(),
+                stride as _,
+            );
+        }
+    }
+}
+
+impl<T: AsRef<[f32]>> PlotHistogram for T {
+    fn build(_: &Ui, elem: &Self, params: PlotParams) {
+        let values = elem.as_ref();
+        let count = params.count(values);
+        let offset = params.offset.unwrap_or(0);
+        let overlay = params.overlay.map(|o| o.as_ptr()).unwrap_or(ptr::null());
+        let stride = params.stride.unwrap_or(mem::size_of::<f32>());
+        unsafe {
+            sys::igPlotHistogramFloatPtr(
+                params.label.as_ptr(),
+                values.as_ptr(),
+                count as _,
+                offset as _,
+                overlay,
+                params.min.unwrap_or(std::f32::MAX),
+                params.max.unwrap_or(std::f32::MAX),
+                params.size.unwrap_or([0.0, 0.0]).into(),
+                stride as _,
+            );
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! `Range<T>` and `RangeInclusive<T>` fields can be annotated with `drag(...)`
+//! and `slider(...)`. Both bounds are edited together, in the same widget (as
+//! a `[T; 2]` array), for any `T` supported by the widget (`f32` included).
+//!
+//! The range is kept ordered: a bound dragged past the other one stops at it,
+//! so `start <= end` always holds after editing.
+//!
+//! ## Example
+//!
+//! ```
+//! use std::ops::{Range, RangeInclusive};
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Spawner {
+//!     #[imgui(drag(speed = 0.1, min = 0.0))]
+//!     delay: Range<f32>,
+//!     #[imgui(slider(min = 1, max = 16))]
+//!     count: RangeInclusive<i32>,
+//! }
+//! ```
+use imgui::Ui;
+
+use std::ops::{Range, RangeInclusive};
+
+use crate::drag::{Drag, DragParams};
+use crate::slider::{Slider, SliderParams};
+
+/// Keeps the edited `bounds` ordered. If a bound crossed the other one, it is
+/// moved back onto it (`prev` are the bounds before editing).
+fn order<T: Copy + PartialOrd>(prev: [T; 2], bounds: &mut [T; 2]) {
+    if bounds[0] > bounds[1] {
+        if crate::differs(&prev[0], &bounds[0]) {
+            bounds[0] = bounds[1];
+        } else {
+            bounds[1] = bounds[0];
+        }
+    }
+}
+
+/// Edits `start` and `end` through a `[T; 2]`. Returns the ordered bounds if
+/// `build` returns `true` (the value changed).
+fn with_bounds<T, F>(start: T, end: T, build: F) -> Option<[T; 2]>
+where
+    T: Copy + PartialOrd,
+    F: FnOnce(&mut [T; 2]) -> bool,
+{
+    let prev = [start, end];
+    let mut bounds = prev;
+    if build(&mut bounds) {
+        order(prev, &mut bounds);
+        Some(bounds)
+    } else {
+        None
+    }
+}
+
+impl<T> Drag<T> for Range<T>
+where
+    T: Copy + PartialOrd,
+    [T; 2]: Drag<T>,
+{
+    fn build(ui: &Ui, elem: &mut Self, params: DragParams<T>) -> bool {
+        match with_bounds(elem.start, elem.end, |b| Drag::build(ui, b, params)) {
+            Some([start, end]) => {
+                *elem = start..end;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<T> Drag<T> for RangeInclusive<T>
+where
+    T: Copy + PartialOrd,
+    [T; 2]: Drag<T>,
+{
+    fn build(ui: &Ui, elem: &mut Self, params: DragParams<T>) -> bool {
+        match with_bounds(*elem.start(), *elem.end(), |b| Drag::build(ui, b, params)) {
+            Some([start, end]) => {
+                *elem = start..=end;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<T> Slider<T> for Range<T>
+where
+    T: Copy + PartialOrd,
+    [T; 2]: Slider<T>,
+{
+    fn build(ui: &Ui, elem: &mut Self, params: SliderParams<T>) -> bool {
+        match with_bounds(elem.start, elem.end, |b| Slider::build(ui, b, params)) {
+            Some([start, end]) => {
+                *elem = start..end;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<T> Slider<T> for RangeInclusive<T>
+where
+    T: Copy + PartialOrd,
+    [T; 2]: Slider<T>,
+{
+    fn build(ui: &Ui, elem: &mut Self, params: SliderParams<T>) -> bool {
+        match with_bounds(*elem.start(), *elem.end(), |b| Slider::build(ui, b, params)) {
+            Some([start, end]) => {
+                *elem = start..=end;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::with_bounds;
+
+    #[test]
+    fn ordered() {
+        // start dragged past the end
+        let bounds = with_bounds(0.0, 1.0, |b| {
+            b[0] = 2.0;
+            true
+        });
+        assert_eq!(Some([1.0, 1.0]), bounds);
+
+        // end dragged past the start
+        let bounds = with_bounds(0.0, 1.0, |b| {
+            b[1] = -1.0;
+            true
+        });
+        assert_eq!(Some([0.0, 0.0]), bounds);
+
+        let bounds = with_bounds(0.0, 1.0, |b| {
+            b[1] = 4.0;
+            true
+        });
+        assert_eq!(Some([0.0, 4.0]), bounds);
+
+        assert_eq!(None, with_bounds(0.0, 1.0, |_| false));
+    }
+}
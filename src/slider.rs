@@ -3,14 +3,58 @@
 //! * `min` maximum value.
 //! * `max` minimum value.
 //!
+//! Both can also be given as a range, in the first position:
+//! `slider(0.0..=1.0, ...)` is the same as `slider(min = 0.0, max = 1.0, ...)`.
+//! Exclusive ranges are only allowed on integers, and `max` is the last
+//! value of the range (`slider(0..10)` is `slider(min = 0, max = 9)`).
+//!
+//! Both are optional with the `bounded` flag (see below), which is also how
+//! field-less [enums](../variants/index.html) are edited.
+//!
 //! ## Optional fields
 //!
-//! * `label`
-//! * `format` format string (in `printf` format)
-//! * `power`
+//! * `label` (a string literal, or the path of a `&'static str` constant)
+//! * `label_inline` render the label before the widget, instead of after it.
+//! * `align` align the text of the row (such as the `label_inline` label, or
+//!   a `text` on the same line) vertically with the frame of the widget.
+//! * `format` format string (in `printf` format). It is checked at compile
+//!   time: it must contain exactly one conversion, matching the type of the
+//!   field (`%d` for integers, `%lld` for 64-bit integers such as `i64`, `u64`
+//!   and `usize`, `%f` for floats, ...).
+//! * `format_value` path to a function that formats the value shown on the
+//!   slider, for text that can't be expressed with a `printf` format (such as
+//!   `"1.2 MiB"`). The function takes the value of the field
+//!   (`fn(f32) -> String` for an `f32` field, `fn(u64) -> String` for a
+//!   `u64` field). Only applies to scalar fields (not arrays), and can't be
+//!   combined with `format`.
+//! * `power` exponent of the curve of float values. Integer fields are
+//!   rejected at compile time, as are `min`/`max` literals that don't match
+//!   the kind of number of the field (`0` on a float, `0.5` on an integer).
+//! * `sanitize` replace `NaN` and infinite values with `min` before drawing
+//!   the widget, so it doesn't get stuck.
+//! * `wrap` wrap edited values into `[min, max)` instead of clamping them to
+//!   the bounds (useful for angles).
+//! * `logarithmic` use a logarithmic scale, so every order of magnitude
+//!   between `min` and `max` takes the same width (useful for frequencies,
+//!   volumes...). Only applies to scalar fields, and `min` must be positive
+//!   (otherwise the slider is linear). Can't be combined with `power`. The
+//!   `format` applies to the value, not to its logarithm.
+//! * `keyboard_step` amount added (or subtracted) to the value when the right
+//!   or up (left or down) arrow key is pressed while the slider is hovered or
+//!   focused. The result is clamped to `[min, max]` (or wrapped, with `wrap`).
+//!   Only applies to scalar fields (integer fields round the step).
+//! * `bounded` read the bounds that are not given from the type of the field,
+//!   which must implement [`Bounded`](../trait.Bounded.html). Explicit `min`
+//!   and `max` take precedence over the bounds of the type.
+//! * `reset_to` expression to reset the field to, drawn as a `Reset` button
+//!   next to the slider (same as the [drag option](../drag/index.html)).
 //! * `catch` override widget label.
+//! * `started` and `finished` events triggered at the start and at the end of
+//!   an edit (same as the [drag events](../drag/index.html)).
 //! * `map` applies a mapping function to `&mut Self` (works the same as in the
 //!   [input example](../input/index.html#mapping))
+//! * `get` and `set` paths to accessor functions, for values that aren't
+//!   stored in a field (see [accessors](#accessors)).
 //!
 //! ## Example
 //!
@@ -19,8 +63,44 @@
 //! struct Sliders {
 //!     #[imgui(slider(min = 0.0, max = 1.0))]
 //!     foo: f32,
-//!     #[imgui(slider(min = 0, max = 16, format = "bar = %.02f"))]
+//!     #[imgui(slider(min = 0, max = 16, format = "bar = %d"))]
 //!     bar: [i32; 2],
+//!     #[imgui(slider(min = 20.0, max = 20000.0, logarithmic))]
+//!     frequency: f32,
+//!     #[imgui(slider(-1.0..=1.0))]
+//!     pan: f32,
+//! }
+//! ```
+//!
+//! Sliders are implemented for `f32`, `f64`, `i32`, `i64` and `u32` (and
+//! their arrays and tuples), as well as for `u8`, `u16`, `u64` and `usize`
+//! scalars. The last ones are edited as signed integers and clamped to their
+//! range when written back, so they never become negative.
+//!
+//! ## Accessors
+//!
+//! `get` and `set` bind the slider to a computed value instead of the field:
+//! the value is read with `get(&Self) -> T` before the slider is drawn, and
+//! written with `set(&mut Self, T)` when the slider changes it. The field only
+//! hosts the annotation (a `PhantomData<()>` will do). Both options must be
+//! given. They work the same way on `drag(...)` and `input(...)`.
+//!
+//! ```
+//! use std::marker::PhantomData;
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Temperature {
+//!     kelvin: f32,
+//!     #[imgui(slider(min = 0.0, max = 100.0, get = "celsius", set = "set_celsius"))]
+//!     celsius: PhantomData<()>,
+//! }
+//!
+//! fn celsius(t: &Temperature) -> f32 {
+//!     t.kelvin - 273.15
+//! }
+//!
+//! fn set_celsius(t: &mut Temperature, celsius: f32) {
+//!     t.kelvin = celsius + 273.15;
 //! }
 //! ```
 //!
@@ -30,7 +110,9 @@
 //!
 //! [result]: https://i.imgur.com/X2ue0dS.png
 use imgui::sys;
-use imgui::{ImStr, Ui};
+use imgui::{ImStr, ImString, Ui};
+
+use std::ffi::CStr;
 
 pub struct SliderParams<'a, T> {
     pub min: T,
@@ -38,6 +120,11 @@ pub struct SliderParams<'a, T> {
     pub label: &'a ImStr,
     pub format: Option<&'a ImStr>,
     pub power: Option<f32>,
+    pub sanitize: bool,
+    pub wrap: bool,
+    pub logarithmic: bool,
+    pub keyboard_step: Option<f32>,
+    pub format_value: Option<fn(T) -> String>,
 }
 
 pub trait Slider<T> {
@@ -61,10 +148,171 @@ impl<T, S: Slider<T>> Slider<T> for Box<S> {
     }
 }
 
+/// Scalars that can be edited with a `logarithmic` slider (or stepped with
+/// the arrow keys).
+pub(crate) trait LogScale: Copy {
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+    fn display(self) -> String;
+    /// Appends the value, formatted by `format` (a `printf` format with a
+    /// single conversion of this type, checked by the derive), to `buf`.
+    unsafe fn appendf(self, buf: *mut sys::ImGuiTextBuffer, format: &ImStr);
+}
+
+macro_rules! impl_log_scale {
+    ($($float:ty),+ ; $($int:ty),+) => {
+        $(impl LogScale for $float {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            fn from_f64(value: f64) -> Self {
+                value as Self
+            }
+            fn display(self) -> String {
+                format!("{:.3}", self)
+            }
+            unsafe fn appendf(self, buf: *mut sys::ImGuiTextBuffer, format: &ImStr) {
+                // floats are passed as doubles to variadic functions
+                sys::ImGuiTextBuffer_appendf(buf, format.as_ptr(), self as f64);
+            }
+        })+
+        $(impl LogScale for $int {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            fn from_f64(value: f64) -> Self {
+                value.round() as Self
+            }
+            fn display(self) -> String {
+                self.to_string()
+            }
+            unsafe fn appendf(self, buf: *mut sys::ImGuiTextBuffer, format: &ImStr) {
+                sys::ImGuiTextBuffer_appendf(buf, format.as_ptr(), self);
+            }
+        })+
+    };
+}
+
+impl_log_scale! { f32, f64 ; i32, i64, u32 }
+
+/// Bounds of the logarithmic slider, or `None` if `[min, max]` can't be
+/// represented in a logarithmic scale.
+fn log_bounds(min: f64, max: f64) -> Option<(f64, f64)> {
+    if min > 0.0 && max > min && max.is_finite() {
+        Some((min.ln(), max.ln()))
+    } else {
+        None
+    }
+}
+
+/// Draws a slider over the logarithm of `elem`, displaying the actual value.
+/// Returns `None` (drawing nothing) if the bounds are not positive.
+pub(crate) fn build_logarithmic<T: LogScale>(
+    elem: &mut T,
+    params: &SliderParams<T>,
+) -> Option<bool> {
+    let (min, max) = log_bounds(params.min.to_f64(), params.max.to_f64())?;
+
+    let mut exp = elem.to_f64().ln().max(min).min(max);
+    // imgui would format the logarithm, so the text is formatted here
+    let display = match (params.format_value, params.format) {
+        (Some(format_value), _) => display(*elem, format_value),
+        (None, Some(format)) => printf(*elem, format),
+        (None, None) => display(*elem, T::display),
+    };
+
+    let changed = unsafe {
+        sys::igSliderScalar(
+            params.label.as_ptr(),
+            sys::ImGuiDataType_Double as _,
+            &mut exp as *mut f64 as _,
+            &min as *const f64 as _,
+            &max as *const f64 as _,
+            display.as_ptr(),
+            1.0,
+        )
+    };
+    if changed {
+        *elem = T::from_f64(exp.exp());
+    }
+    Some(changed)
+}
+
+/// Text shown by a slider of `value`, formatted by `format_value`.
+pub(crate) fn display<T>(value: T, format_value: fn(T) -> String) -> ImString {
+    // `%` would be interpreted by imgui
+    ImString::new(format_value(value).replace('%', "%%"))
+}
+
+/// Text shown by a slider of `value`, formatted by the `printf` `format`.
+fn printf<T: LogScale>(value: T, format: &ImStr) -> ImString {
+    let text = unsafe {
+        let buf = sys::ImGuiTextBuffer_ImGuiTextBuffer();
+        value.appendf(buf, format);
+        let text = CStr::from_ptr(sys::ImGuiTextBuffer_c_str(buf))
+            .to_string_lossy()
+            .into_owned();
+        sys::ImGuiTextBuffer_destroy(buf);
+        text
+    };
+    display(text, |text| text)
+}
+
+/// Moves `value` one `step` towards `max` (`increase`) or `min` (`decrease`),
+/// clamped to `[min, max]`, or wrapped into `[min, max)` if `wrap` is set.
+/// Returns `None` if neither or both keys are pressed.
+fn step_value(
+    value: f64,
+    step: f64,
+    [decrease, increase]: [bool; 2],
+    min: f64,
+    max: f64,
+    wrap: bool,
+) -> Option<f64> {
+    let value = match (decrease, increase) {
+        (true, false) => value - step,
+        (false, true) => value + step,
+        _ => return None,
+    };
+    if wrap && max > min {
+        Some(crate::Finite::wrap(value, min, max))
+    } else {
+        Some(value.max(min).min(max))
+    }
+}
+
+/// Applies the `keyboard_step` of the slider drawn last, if it's hovered (or
+/// focused) and an arrow key was pressed. Returns `true` if `elem` changed.
+pub(crate) fn keyboard_step<T: LogScale + PartialEq>(
+    elem: &mut T,
+    params: &SliderParams<T>,
+) -> bool {
+    let step = match params.keyboard_step {
+        Some(step) if unsafe { sys::igIsItemHovered(0) || sys::igIsItemFocused() } => step,
+        _ => return false,
+    };
+    let pressed = |key| unsafe { sys::igIsKeyPressed(sys::igGetKeyIndex(key as _), true) };
+    let keys = [
+        pressed(sys::ImGuiKey_LeftArrow) || pressed(sys::ImGuiKey_DownArrow),
+        pressed(sys::ImGuiKey_RightArrow) || pressed(sys::ImGuiKey_UpArrow),
+    ];
+    let (min, max) = (params.min.to_f64(), params.max.to_f64());
+    match step_value(elem.to_f64(), f64::from(step), keys, min, max, params.wrap) {
+        Some(value) if crate::differs(elem, &T::from_f64(value)) => {
+            *elem = T::from_f64(value);
+            true
+        }
+        _ => false,
+    }
+}
+
 imgui_slider_scalar! { (f32, f32, f32, f32, f32, f32, f32, f32), 8, sys::ImGuiDataType_Float }
 imgui_slider_scalar! { (f64, f64, f64, f64, f64, f64, f64, f64), 8, sys::ImGuiDataType_Double }
 imgui_slider_scalar! { (u32, u32, u32, u32, u32, u32, u32, u32), 8, sys::ImGuiDataType_U32 }
 imgui_slider_scalar! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, sys::ImGuiDataType_S32 }
+imgui_slider_scalar! { (i64, i64, i64, i64, i64, i64, i64, i64), 8, sys::ImGuiDataType_S64 }
+
+imgui_slider_unsigned! { u8, u16, u64, usize }
 
 // matrix types
 // TODO macro Y expansion
@@ -104,3 +352,72 @@ imgui_slider_matrix! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, 4, sys::ImGu
 imgui_slider_matrix! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, 3, sys::ImGuiDataType_S32 }
 imgui_slider_matrix! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, 2, sys::ImGuiDataType_S32 }
 imgui_slider_matrix! { (i32, i32, i32, i32, i32, i32, i32, i32), 8, 1, sys::ImGuiDataType_S32 }
+
+#[cfg(test)]
+mod tests {
+    use super::{display, log_bounds, printf, step_value, LogScale};
+
+    #[test]
+    fn logarithmic() {
+        let (min, max) = log_bounds(20.0, 20000.0).unwrap();
+        // three orders of magnitude, each one a third of the slider
+        let third = (max - min) / 3.0;
+        assert!((f64::from_f64((min + third).exp()) - 200.0).abs() < 1e-6);
+        assert!((f64::from_f64((min + 2.0 * third).exp()) - 2000.0).abs() < 1e-6);
+        assert_eq!(1000, i32::from_f64(1000.0f64.ln().exp()));
+
+        assert_eq!(None, log_bounds(0.0, 1.0));
+        assert_eq!(None, log_bounds(-1.0, 1.0));
+        assert_eq!(None, log_bounds(1.0, 1.0));
+        assert_eq!("0.500", 0.5f32.display());
+    }
+    #[test]
+    fn step() {
+        let left = [true, false];
+        let right = [false, true];
+        assert_eq!(Some(0.25), step_value(0.5, 0.25, left, 0.0, 1.0, false));
+        assert_eq!(Some(0.75), step_value(0.5, 0.25, right, 0.0, 1.0, false));
+
+        // clamped to the bounds
+        assert_eq!(Some(1.0), step_value(0.9, 0.25, right, 0.0, 1.0, false));
+        assert_eq!(Some(0.0), step_value(0.1, 0.25, left, 0.0, 1.0, false));
+
+        // or wrapped around them
+        assert_eq!(Some(0.0), step_value(9.0, 1.0, right, 0.0, 10.0, true));
+        assert_eq!(Some(9.0), step_value(0.0, 1.0, left, 0.0, 10.0, true));
+        assert_eq!(Some(0.75), step_value(0.5, 0.25, right, 0.0, 1.0, true));
+
+        assert_eq!(None, step_value(0.5, 0.25, [false, false], 0.0, 1.0, false));
+        assert_eq!(None, step_value(0.5, 0.25, [true, true], 0.0, 1.0, false));
+    }
+
+    #[test]
+    fn format_value() {
+        fn bytes(value: f32) -> String {
+            match value {
+                v if v >= 1024.0 * 1024.0 => format!("{:.1} MiB", v / (1024.0 * 1024.0)),
+                v if v >= 1024.0 => format!("{:.1} KiB", v / 1024.0),
+                v => format!("{} B", v),
+            }
+        }
+
+        assert_eq!("512 B", display(512.0, bytes).to_str());
+        assert_eq!("1.5 KiB", display(1536.0, bytes).to_str());
+        assert_eq!("1.2 MiB", display(1.25 * 1024.0 * 1024.0, bytes).to_str());
+        assert_eq!("50%%", display(50, |v: i32| format!("{}%", v)).to_str());
+    }
+
+    #[test]
+    fn format_logarithmic() {
+        use imgui::im_str;
+
+        assert_eq!("x = 2.50", printf(2.5f32, im_str!("x = %.2f")).to_str());
+        assert_eq!("1.0e+03 Hz", printf(1000.0f64, im_str!("%.1e Hz")).to_str());
+        assert_eq!("50%%", printf(50i32, im_str!("%d%%")).to_str());
+        assert_eq!("0x00ff", printf(255u32, im_str!("0x%04x")).to_str());
+        assert_eq!(
+            "-4294967296",
+            printf(-(1i64 << 32), im_str!("%lld")).to_str()
+        );
+    }
+}
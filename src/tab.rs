@@ -0,0 +1,153 @@
+//! Tabs are built from two annotations:
+//!
+//! * `tab_bar(...)` draws a nested UI inside of a tab bar.
+//! * `tab_item(...)` draws a nested UI inside of a tab. It must be used in a
+//!   type that is drawn with `tab_bar`.
+//!
+//! ## Optional fields (`tab_bar`)
+//!
+//! * `catch`
+//!
+//! ## Optional fields (`tab_item`)
+//!
+//! * `label` override the tab label.
+//! * `flags` tab flags, separated by `|`. Allowed values: `"SetSelected"`,
+//!   `"UnsavedDocument"` and `"NoCloseWithMiddleMouseButton"`.
+//! * `open` name of a sibling `bool` field bound to the close button of the
+//!   tab. The tab is hidden while the field is `false`.
+//! * `closable` name of an event triggered when the close button of the tab
+//!   is clicked. Adds a close button even if the tab isn't bound to an `open`
+//!   field.
+//! * `catch`
+//!
+//! ## Example
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! struct Document {
+//!     #[imgui(input)]
+//!     title: String,
+//! }
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Documents {
+//!     #[imgui(tab_item(label = "main.rs", open = "main_open"))]
+//!     main: Document,
+//!     main_open: bool,
+//!
+//!     // `events.lib_closed()` is true when the tab is closed.
+//!     #[imgui(tab_item(label = "lib.rs", flags = "UnsavedDocument", closable = "lib_closed"))]
+//!     lib: Document,
+//! }
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Editor {
+//!     #[imgui(tab_bar)]
+//!     documents: Documents,
+//! }
+//! ```
+use imgui::sys;
+use imgui::{ImStr, Ui};
+use std::ops::BitOr;
+use std::os::raw::c_int;
+use std::ptr;
+
+/// Flags of a `tab_item`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TabItemFlags(c_int);
+
+impl TabItemFlags {
+    /// Select the tab the next time it is drawn.
+    pub const SET_SELECTED: Self = TabItemFlags(sys::ImGuiTabItemFlags_SetSelected as c_int);
+    /// Show a dot next to the label, and don't close the tab with the middle
+    /// mouse button.
+    pub const UNSAVED_DOCUMENT: Self =
+        TabItemFlags(sys::ImGuiTabItemFlags_UnsavedDocument as c_int);
+    pub const NO_CLOSE_WITH_MIDDLE_MOUSE_BUTTON: Self =
+        TabItemFlags(sys::ImGuiTabItemFlags_NoCloseWithMiddleMouseButton as c_int);
+
+    pub fn empty() -> Self {
+        TabItemFlags(0)
+    }
+
+    pub fn bits(self) -> c_int {
+        self.0
+    }
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for TabItemFlags {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        TabItemFlags(self.0 | rhs.0)
+    }
+}
+
+/// Structure generated by the `tab_item` annotation.
+pub struct TabItemParams<'a> {
+    pub label: &'a ImStr,
+    pub flags: TabItemFlags,
+}
+
+/// Tab bar being drawn. Must be ended with `end`.
+pub struct TabBarToken(());
+
+impl TabBarToken {
+    pub fn end(self, _: &Ui) {
+        unsafe { sys::igEndTabBar() }
+    }
+}
+
+/// Tab being drawn. Must be ended with `end`.
+pub struct TabItemToken(());
+
+impl TabItemToken {
+    pub fn end(self, _: &Ui) {
+        unsafe { sys::igEndTabItem() }
+    }
+}
+
+/// Begins a tab bar. Returns `None` if the bar is not visible.
+pub fn begin_tab_bar(_: &Ui, id: &ImStr) -> Option<TabBarToken> {
+    if unsafe { sys::igBeginTabBar(id.as_ptr(), 0) } {
+        Some(TabBarToken(()))
+    } else {
+        None
+    }
+}
+
+/// Begins a tab. Returns `None` if the contents of the tab are not visible.
+///
+/// When `open` is `Some`, the tab has a close button that sets it to `false`
+/// (and the tab is not drawn while it is `false`).
+pub fn begin_tab_item(
+    _: &Ui,
+    params: TabItemParams,
+    open: Option<&mut bool>,
+) -> Option<TabItemToken> {
+    let open = open
+        .map(|open| open as *mut bool)
+        .unwrap_or(ptr::null_mut());
+    if unsafe { sys::igBeginTabItem(params.label.as_ptr(), open, params.flags.bits()) } {
+        Some(TabItemToken(()))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TabItemFlags as Flags;
+
+    #[test]
+    fn flags() {
+        let flags = Flags::empty() | Flags::UNSAVED_DOCUMENT | Flags::SET_SELECTED;
+        assert!(flags.contains(Flags::UNSAVED_DOCUMENT));
+        assert!(flags.contains(Flags::SET_SELECTED));
+        assert!(!flags.contains(Flags::NO_CLOSE_WITH_MIDDLE_MOUSE_BUTTON));
+        assert_eq!(0, Flags::empty().bits());
+    }
+}
@@ -0,0 +1,138 @@
+//! Field-less enums (such as a quality setting) can be edited with
+//! `slider(...)`, over the discriminants of their variants. The name of the
+//! variant is shown on the slider, and the value is written back as the
+//! variant with the nearest discriminant.
+//!
+//! `#[derive(Gui)]` on a field-less enum implements [`Variants`], along with
+//! [`Bounded`], so the slider is annotated with `bounded` instead of a `min`
+//! and a `max`: the bounds are the smallest and largest discriminants. The
+//! enum itself draws no widgets.
+//!
+//! Discriminants are edited as `i32`, so the enum must be `#[repr(i32)]`
+//! (any other representation is rejected at compile time). The enum can't
+//! take struct options (`#[imgui(tree)]`, `#[imgui(patch)]`, ...).
+//!
+//! ## Example
+//!
+//! ```
+//! #[derive(imgui_ext::Gui)]
+//! #[repr(i32)]
+//! enum Quality {
+//!     Low,
+//!     Medium = 4,
+//!     High = 8,
+//! }
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Settings {
+//!     #[imgui(slider(bounded))]
+//!     quality: Quality,
+//! }
+//! ```
+//!
+//! [`Variants`]: trait.Variants.html
+//! [`Bounded`]: ../trait.Bounded.html
+use imgui::Ui;
+
+use crate::slider::{Slider, SliderParams};
+
+/// Field-less enums. Implemented by `#[derive(Gui)]`.
+pub trait Variants: Sized {
+    /// Name and discriminant of every variant, in declaration order.
+    const VARIANTS: &'static [(&'static str, i32)];
+
+    /// Index of `self` in `VARIANTS`.
+    fn index(&self) -> usize;
+
+    /// Variant at `index` of `VARIANTS`.
+    fn from_index(index: usize) -> Self;
+}
+
+/// Smallest and largest discriminants of `E`.
+pub fn bounds<E: Variants>() -> (i32, i32) {
+    let discriminants = E::VARIANTS.iter().map(|&(_, discriminant)| discriminant);
+    let min = discriminants.clone().min().unwrap_or(0);
+    let max = discriminants.max().unwrap_or(0);
+    (min, max)
+}
+
+/// Index of the variant with the discriminant nearest to `value` (the first
+/// one on a tie).
+fn nearest(variants: &[(&str, i32)], value: i32) -> usize {
+    variants
+        .iter()
+        .enumerate()
+        .min_by_key(|&(_, &(_, discriminant))| (i64::from(discriminant) - i64::from(value)).abs())
+        .map_or(0, |(index, _)| index)
+}
+
+/// Name of the variant nearest to the value of the slider.
+fn name<E: Variants>(value: i32) -> String {
+    E::VARIANTS[nearest(E::VARIANTS, value)].0.to_string()
+}
+
+/// Draws an `i32` slider over the discriminant of `elem`. Returns `true` if
+/// `elem` changed to another variant.
+pub fn build<E: Variants>(ui: &Ui, elem: &mut E, params: SliderParams<i32>) -> bool {
+    let mut value = E::VARIANTS[elem.index()].1;
+    let params = SliderParams {
+        format_value: Some(name::<E>),
+        ..params
+    };
+    if !Slider::build(ui, &mut value, params) {
+        return false;
+    }
+    let index = nearest(E::VARIANTS, value);
+    if index == elem.index() {
+        return false;
+    }
+    *elem = E::from_index(index);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bounds, nearest, Variants};
+
+    #[derive(Debug, PartialEq)]
+    enum Quality {
+        Low,
+        Medium,
+        High,
+    }
+
+    impl Variants for Quality {
+        const VARIANTS: &'static [(&'static str, i32)] = &[("Low", 0), ("Medium", 4), ("High", 8)];
+
+        fn index(&self) -> usize {
+            match self {
+                Quality::Low => 0,
+                Quality::Medium => 1,
+                Quality::High => 2,
+            }
+        }
+
+        fn from_index(index: usize) -> Self {
+            match index {
+                0 => Quality::Low,
+                1 => Quality::Medium,
+                _ => Quality::High,
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_variant() {
+        assert_eq!((0, 8), bounds::<Quality>());
+
+        let nearest = |value| Quality::from_index(nearest(Quality::VARIANTS, value));
+        assert_eq!(Quality::Low, nearest(1));
+        // ties go to the first variant
+        assert_eq!(Quality::Low, nearest(2));
+        assert_eq!(Quality::Medium, nearest(3));
+        assert_eq!(Quality::Medium, nearest(6));
+        assert_eq!(Quality::High, nearest(7));
+        assert_eq!(Quality::High, nearest(100));
+        assert_eq!(Quality::Low, nearest(-100));
+    }
+}
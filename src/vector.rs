@@ -0,0 +1,153 @@
+//! Vector types of math libraries can be annotated with `drag`, `slider` and
+//! `input`, the same way as `[f32; N]` arrays.
+//!
+//! Implementations are provided behind optional features:
+//!
+//! * `glam`: `glam::Vec2`, `glam::Vec3` and `glam::Vec4`.
+//! * `mint`: `mint::Vector2<f32>`, `mint::Vector3<f32>` and
+//!   `mint::Vector4<f32>` (`mint` is supported by most math libraries,
+//!   `nalgebra` and `cgmath` included).
+//!
+//! Other vector types can opt in with the [`vector_widgets!`] macro, as long
+//! as they convert from and into a `[f32; N]` (`From<[f32; N]>` and
+//! `Into<[f32; N]>`):
+//!
+//! ```
+//! #[derive(Clone, Copy)]
+//! struct Position {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! impl From<[f32; 2]> for Position {
+//!     fn from([x, y]: [f32; 2]) -> Self {
+//!         Position { x, y }
+//!     }
+//! }
+//!
+//! impl From<Position> for [f32; 2] {
+//!     fn from(p: Position) -> Self {
+//!         [p.x, p.y]
+//!     }
+//! }
+//!
+//! imgui_ext::vector_widgets! { Position: 2 }
+//!
+//! #[derive(imgui_ext::Gui)]
+//! struct Player {
+//!     #[imgui(drag(speed = 0.1))]
+//!     position: Position,
+//! }
+//! ```
+//!
+//! [`vector_widgets!`]: ../macro.vector_widgets.html
+
+/// Edits `elem` through its array representation: `elem` is converted into an
+/// array, which is passed to `build`, and converted back if `build` returns
+/// `true` (the value changed).
+pub fn with_array<V, A, F>(elem: &mut V, build: F) -> bool
+where
+    V: Copy + Into<A>,
+    A: Into<V>,
+    F: FnOnce(&mut A) -> bool,
+{
+    let mut array = (*elem).into();
+    let changed = build(&mut array);
+    if changed {
+        *elem = array.into();
+    }
+    changed
+}
+
+/// Implements the `Drag`, `Slider` and `Input` traits for vector types that
+/// convert from and into a `[f32; N]`. See the [vector](vector/index.html)
+/// docs.
+///
+/// ```ignore
+/// imgui_ext::vector_widgets! { Vec2: 2, Vec3: 3 }
+/// ```
+#[macro_export]
+macro_rules! vector_widgets {
+    ( $( $ty:ty : $len:tt ),+ $(,)? ) => {
+        $(
+            impl $crate::drag::Drag<f32> for $ty {
+                fn build(
+                    ui: &::imgui::Ui,
+                    elem: &mut Self,
+                    params: $crate::drag::DragParams<f32>,
+                ) -> bool {
+                    $crate::vector::with_array(elem, |array: &mut [f32; $len]| {
+                        $crate::drag::Drag::build(ui, array, params)
+                    })
+                }
+            }
+
+            impl $crate::slider::Slider<f32> for $ty {
+                fn build(
+                    ui: &::imgui::Ui,
+                    elem: &mut Self,
+                    params: $crate::slider::SliderParams<f32>,
+                ) -> bool {
+                    $crate::vector::with_array(elem, |array: &mut [f32; $len]| {
+                        $crate::slider::Slider::build(ui, array, params)
+                    })
+                }
+            }
+
+            impl $crate::input::Input<f32> for $ty {
+                fn build(
+                    ui: &::imgui::Ui,
+                    elem: &mut Self,
+                    params: $crate::input::InputParams<f32>,
+                ) -> bool {
+                    $crate::vector::with_array(elem, |array: &mut [f32; $len]| {
+                        $crate::input::Input::build(ui, array, params)
+                    })
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "glam")]
+vector_widgets! { glam::Vec2: 2, glam::Vec3: 3, glam::Vec4: 4 }
+
+#[cfg(feature = "mint")]
+vector_widgets! { mint::Vector2<f32>: 2, mint::Vector3<f32>: 3, mint::Vector4<f32>: 4 }
+
+#[cfg(test)]
+mod tests {
+    use super::with_array;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Vec2(f32, f32);
+
+    impl From<[f32; 2]> for Vec2 {
+        fn from([x, y]: [f32; 2]) -> Self {
+            Vec2(x, y)
+        }
+    }
+
+    impl From<Vec2> for [f32; 2] {
+        fn from(v: Vec2) -> Self {
+            [v.0, v.1]
+        }
+    }
+
+    #[test]
+    fn array() {
+        let mut v = Vec2(1.0, 2.0);
+        assert!(with_array(&mut v, |a: &mut [f32; 2]| {
+            a[1] = 4.0;
+            true
+        }));
+        assert_eq!(Vec2(1.0, 4.0), v);
+
+        // unchanged values are not written back
+        assert!(!with_array(&mut v, |a: &mut [f32; 2]| {
+            a[0] = 8.0;
+            false
+        }));
+        assert_eq!(Vec2(1.0, 4.0), v);
+    }
+}
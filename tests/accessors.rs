@@ -0,0 +1,42 @@
+use imgui_ext::UiExt;
+use std::marker::PhantomData;
+
+mod common;
+
+#[derive(imgui_ext::Gui)]
+struct Temperature {
+    kelvin: f32,
+    writes: usize,
+    #[imgui(slider(min = 0.0, max = 100.0, get = "celsius", set = "set_celsius"))]
+    celsius: PhantomData<()>,
+}
+
+fn celsius(t: &Temperature) -> f32 {
+    t.kelvin - 273.15
+}
+
+fn set_celsius(t: &mut Temperature, celsius: f32) {
+    t.kelvin = celsius + 273.15;
+    t.writes += 1;
+}
+
+#[test]
+fn accessors() {
+    let mut ctx = common::headless();
+
+    let mut temp = Temperature {
+        kelvin: 323.15,
+        writes: 0,
+        celsius: PhantomData,
+    };
+
+    // Click the left end of the slider.
+    common::click_first_item(&mut ctx, &[false, true, false], |ui, frame| {
+        let events = ui.draw_gui(&mut temp);
+        // the value is only written back when the slider changes it
+        assert_eq!(frame == 1, events.celsius());
+        assert_eq!(if frame == 0 { 0 } else { 1 }, temp.writes);
+    });
+
+    assert!(celsius(&temp) < 10.0);
+}
@@ -0,0 +1,33 @@
+use imgui_ext::UiExt;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+
+mod common;
+
+#[derive(imgui_ext::Gui)]
+struct Shared {
+    #[imgui(atomic, checkbox)]
+    paused: AtomicBool,
+    #[imgui(atomic = "SeqCst", slider(min = 0, max = 100))]
+    volume: Arc<AtomicU32>,
+}
+
+#[test]
+fn atomic_checkbox() {
+    let mut ctx = common::headless();
+
+    let mut shared = Shared {
+        paused: AtomicBool::new(false),
+        volume: Arc::new(AtomicU32::new(50)),
+    };
+    let mut paused = false;
+
+    // Click the checkbox.
+    common::click_first_item(&mut ctx, &[false, true, false], |ui, _| {
+        paused |= ui.draw_gui(&mut shared).paused();
+    });
+
+    assert!(paused);
+    assert!(shared.paused.load(Ordering::Relaxed));
+    assert_eq!(50, shared.volume.load(Ordering::SeqCst));
+}
@@ -0,0 +1,26 @@
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn borrow() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(borrow, drag(speed = 0.1))]
+        a: RefCell<f32>,
+        #[imgui(borrow, drag, display)]
+        b: RefCell<f32>,
+    }
+}
+
+#[test]
+fn lock() {
+    struct Meters(pub f32);
+
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(lock, slider(min = 0.0, max = 1.0))]
+        a: Mutex<f32>,
+        #[imgui(lock, newtype, drag)]
+        b: Arc<Mutex<Meters>>,
+    }
+}
@@ -0,0 +1,197 @@
+mod common;
+
+#[test]
+fn button() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(
+            button(label = "a"),
+            button(label = "b", size = "size"),
+            button(label = "c", size = "[-1.0, 20.0]"),
+            button(label = "d", fill)
+        )]
+        a: (),
+    }
+
+    fn size() -> [f32; 2] {
+        [100.0, 20.0]
+    }
+}
+
+#[derive(imgui_ext::Gui)]
+struct Fill {
+    #[imgui(button(label = "Fill", fill, catch = "fill"))]
+    fill: (),
+    #[imgui(button(label = "Small", catch = "small"))]
+    small: (),
+}
+
+#[test]
+fn button_fill() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut buttons = Fill {
+        fill: (),
+        small: (),
+    };
+    let mut clicks = Vec::new();
+
+    // Click far to the right of the labels, where only a full-width button
+    // reaches.
+    common::drive(
+        &mut ctx,
+        4,
+        |io, frame, [x, y]| {
+            io.mouse_pos = [x + 200.0, y + 4.0];
+            io.mouse_down[0] = frame == 1;
+        },
+        |ui, _| {
+            let events = ui.draw_gui(&mut buttons);
+            assert!(!events.small());
+            clicks.push(events.fill());
+        },
+    );
+
+    assert_eq!(vec![false, false, true, false], clicks);
+}
+
+#[derive(imgui_ext::Gui)]
+struct Toolbar {
+    #[imgui(button(label = "Save", disabled_if = "saved", catch = "save"))]
+    save: (),
+    #[imgui(button(label = "Undo", same_line, disabled_if = "!can_undo", catch = "undo"))]
+    undo: (),
+    saved: bool,
+    can_undo: bool,
+}
+
+#[test]
+fn button_disabled() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut toolbar = Toolbar {
+        save: (),
+        undo: (),
+        saved: true,
+        can_undo: false,
+    };
+    let mut clicks = Vec::new();
+
+    // click on the (disabled) save button, then enable it and click again
+    let frames = [false, true, false, true, false];
+    common::click_first_item(&mut ctx, &frames, |ui, frame| {
+        if frame == 3 {
+            toolbar.saved = false;
+        }
+        let events = ui.draw_gui(&mut toolbar);
+        assert!(!events.undo());
+        clicks.push(events.save());
+    });
+
+    assert_eq!(vec![false, false, false, false, true], clicks);
+}
+
+#[derive(imgui_ext::Gui)]
+struct Keypad {
+    #[imgui(buttons(cols = 3, size = "[32.0, 32.0]", on_click = "Keypad::press"))]
+    keys: Vec<imgui::ImString>,
+    pressed: Vec<usize>,
+}
+
+impl Keypad {
+    fn press(index: usize, keypad: &mut Keypad) {
+        keypad.pressed.push(index);
+    }
+}
+
+#[test]
+fn buttons_grid() {
+    use imgui::ImString;
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    // the last row only has the "0" key
+    let mut keypad = Keypad {
+        keys: "1234567890"
+            .chars()
+            .map(|key| ImString::new(key.to_string()))
+            .collect(),
+        pressed: Vec::new(),
+    };
+    let mut clicks = Vec::new();
+    let mut indices = Vec::new();
+
+    // Click the center of the second button of the second row ("5"), then
+    // the only button of the last row ("0"). Buttons are 32x32, with the
+    // default spacing of 8x4 pixels.
+    common::drive(
+        &mut ctx,
+        5,
+        |io, frame, [x, y]| {
+            io.mouse_pos = match frame {
+                1 | 2 => [x + 40.0 + 16.0, y + 36.0 + 16.0],
+                _ => [x + 16.0, y + 3.0 * 36.0 + 16.0],
+            };
+            io.mouse_down[0] = frame == 1 || frame == 3;
+        },
+        |ui, _| {
+            let events = ui.draw_gui(&mut keypad);
+            clicks.push(events.keys());
+            indices.push(events.keys_index());
+        },
+    );
+
+    assert_eq!(vec![false, false, true, false, true], clicks);
+    assert_eq!(vec![None, None, Some(4), None, Some(9)], indices);
+    assert_eq!(vec![4, 9], keypad.pressed);
+}
+
+#[derive(imgui_ext::Gui)]
+struct LockedKeypad {
+    #[imgui(buttons(cols = 3, on_click = "LockedKeypad::press", disabled_if = "locked"))]
+    keys: Vec<imgui::ImString>,
+    pressed: Vec<usize>,
+    locked: bool,
+}
+
+impl LockedKeypad {
+    fn press(index: usize, keypad: &mut LockedKeypad) {
+        keypad.pressed.push(index);
+    }
+}
+
+#[test]
+fn buttons_disabled() {
+    use imgui::ImString;
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut keypad = LockedKeypad {
+        keys: "123"
+            .chars()
+            .map(|key| ImString::new(key.to_string()))
+            .collect(),
+        pressed: Vec::new(),
+        locked: true,
+    };
+    let mut clicks = Vec::new();
+
+    // click on the first (disabled) key, then unlock the keypad and click again
+    let frames = [false, true, false, true, false];
+    common::click_first_item(&mut ctx, &frames, |ui, frame| {
+        if frame == 3 {
+            assert!(keypad.pressed.is_empty());
+            keypad.locked = false;
+        }
+        clicks.push(ui.draw_gui(&mut keypad).keys());
+    });
+
+    assert_eq!(vec![false, false, false, false, true], clicks);
+    assert_eq!(vec![0], keypad.pressed);
+}
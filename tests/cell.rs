@@ -0,0 +1,48 @@
+use imgui_ext::UiExt;
+use std::cell::{Cell, RefCell};
+
+mod common;
+
+#[derive(Clone, Copy)]
+struct Meters(pub f32);
+
+#[derive(imgui_ext::Gui)]
+struct Camera {
+    #[imgui(cell, drag(catch = "fov"))]
+    fov: Cell<f32>,
+    #[imgui(cell, newtype, slider(min = 0.0, max = 100.0), display)]
+    distance: Cell<Meters>,
+    #[imgui(borrow, cell, checkbox)]
+    ortho: RefCell<Cell<bool>>,
+}
+
+#[test]
+fn cell_drag() {
+    let mut ctx = common::headless();
+
+    let mut camera = Camera {
+        fov: Cell::new(60.0),
+        distance: Cell::new(Meters(4.0)),
+        ortho: RefCell::new(Cell::new(false)),
+    };
+    let mut fov = false;
+
+    // Press the mouse over the drag, then move it to the right.
+    common::drive(
+        &mut ctx,
+        5,
+        |io, frame, [x, y]| {
+            io.mouse_pos = match frame {
+                1 | 2 => [x + 4.0, y + 4.0],
+                _ => [x + 44.0, y + 4.0],
+            };
+            io.mouse_down[0] = frame < 4;
+        },
+        |ui, _| fov |= ui.draw_gui(&mut camera).fov(),
+    );
+
+    assert!(fov);
+    assert!(camera.fov.get() > 60.0);
+    assert_eq!(4.0, camera.distance.get().0);
+    assert!(!camera.ortho.borrow().get());
+}
@@ -1,3 +1,5 @@
+mod common;
+
 #[test]
 fn checkbox() {
     #[derive(imgui_ext::Gui)]
@@ -10,3 +12,87 @@ fn checkbox() {
         c: bool,
     }
 }
+
+#[derive(imgui_ext::Gui, Default)]
+struct Toggle {
+    #[imgui(checkbox(toggled = "flipped"))]
+    value: bool,
+}
+
+#[test]
+fn checkbox_toggled() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut toggle = Toggle::default();
+    let mut flipped = Vec::new();
+
+    // Move away, press over the checkbox, release, then idle for a frame.
+    common::click_first_item(&mut ctx, &[false, true, false, false], |ui, _| {
+        let events = ui.draw_gui(&mut toggle);
+        flipped.push(events.flipped());
+    });
+
+    assert!(toggle.value);
+    assert_eq!(vec![false, false, true, false], flipped);
+}
+
+#[derive(imgui_ext::Gui, Default)]
+struct Inherit {
+    #[imgui(checkbox(catch = "clicked"))]
+    vsync: Option<bool>,
+}
+
+#[test]
+fn checkbox_tri_state() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut inherit = Inherit::default();
+    let mut states = Vec::new();
+
+    // Move away, then click over the checkbox three times.
+    let frames = [false, true, false, true, false, true, false];
+    common::click_first_item(&mut ctx, &frames, |ui, _| {
+        if ui.draw_gui(&mut inherit).clicked() {
+            states.push(inherit.vsync);
+        }
+    });
+
+    assert_eq!(vec![Some(true), Some(false), None], states);
+}
+
+#[derive(imgui_ext::Gui, Default)]
+struct Locked {
+    #[imgui(checkbox(disabled_if = "locked", catch = "clicked"))]
+    vsync: bool,
+    locked: bool,
+}
+
+#[test]
+fn checkbox_disabled() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut locked = Locked {
+        vsync: false,
+        locked: true,
+    };
+    let mut clicks = Vec::new();
+
+    // click on the (disabled) checkbox, then unlock it and click again
+    let frames = [false, true, false, true, false];
+    common::click_first_item(&mut ctx, &frames, |ui, frame| {
+        if frame == 3 {
+            assert!(!locked.vsync);
+            locked.locked = false;
+        }
+        clicks.push(ui.draw_gui(&mut locked).clicked());
+    });
+
+    assert!(locked.vsync);
+    assert_eq!(vec![false, false, false, false, true], clicks);
+}
@@ -0,0 +1,31 @@
+mod common;
+
+#[derive(imgui_ext::Gui)]
+struct Theme {
+    #[imgui(color(edit(format = "u32", alpha_bar)))]
+    background: u32,
+    #[imgui(color(edit(format = "u32", catch = "accent")))]
+    accent: u32,
+}
+
+#[test]
+fn color_packed() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut theme = Theme {
+        background: 0x1234_5678,
+        accent: 0xff80_00ff,
+    };
+
+    // the color goes through `[f32; 4]` every frame, and comes back intact
+    for _ in 0..3 {
+        let ui = ctx.frame();
+        let events = ui.draw_gui(&mut theme);
+        assert!(!events.accent());
+    }
+
+    assert_eq!(0x1234_5678, theme.background);
+    assert_eq!(0xff80_00ff, theme.accent);
+}
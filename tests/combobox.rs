@@ -0,0 +1,199 @@
+use imgui::ImString;
+
+mod common;
+
+#[test]
+fn combobox_typeahead() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(combobox(typeahead))]
+        a: [ImString; 3],
+        #[imgui(combobox(label = "Fruit", typeahead, catch = "fruit"))]
+        b: [ImString; 3],
+    }
+}
+
+/// Reports a new selection every time it is drawn.
+#[derive(Default)]
+struct Always;
+
+impl imgui_ext::combobox::Combobox for Always {
+    fn build(_: &imgui::Ui, _: &mut Self, _: imgui_ext::combobox::ComboboxParams) -> bool {
+        true
+    }
+}
+
+#[derive(imgui_ext::Gui, Default)]
+struct Select {
+    #[imgui(combobox(selected = "2", on_select = "Select::select", catch = "changed"))]
+    always: Always,
+    #[imgui(combobox(on_select = "Select::select"))]
+    never: Option<Always>,
+    calls: Vec<usize>,
+}
+
+impl Select {
+    fn select(new_index: usize, ext: &mut Self) {
+        ext.calls.push(new_index);
+    }
+}
+
+#[test]
+fn combobox_on_select() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut select = Select::default();
+    let ui = ctx.frame();
+    let events = ui.draw_gui(&mut select);
+
+    assert!(events.changed());
+    assert_eq!(vec![2], select.calls);
+}
+
+#[derive(imgui_ext::Gui)]
+struct Dynamic {
+    #[imgui(combobox(catch = "empty"))]
+    empty: Vec<ImString>,
+    #[imgui(combobox(selected = "8", typeahead, catch = "items"))]
+    items: Vec<ImString>,
+}
+
+#[test]
+fn combobox_empty() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut dynamic = Dynamic {
+        empty: Vec::new(),
+        items: vec![ImString::new("Apple"), ImString::new("Banana")],
+    };
+
+    // Click the empty combobox: it doesn't open nor change.
+    common::click_first_item(&mut ctx, &[false, true, false], |ui, _| {
+        let events = ui.draw_gui(&mut dynamic);
+        assert!(!events.empty());
+        assert!(!events.items());
+    });
+
+    assert!(dynamic.empty.is_empty());
+    assert_eq!(2, dynamic.items.len());
+}
+
+#[derive(imgui_ext::Gui)]
+struct Tool {
+    #[imgui(combobox(icons = "icons", selected = "1"))]
+    tools: Vec<ImString>,
+    icons: Vec<ImString>,
+}
+
+/// Clicks the combobox of `tool` to open it, and draws a few more frames.
+fn open_tool(tool: &mut Tool) {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+    common::click_first_item(&mut ctx, &[false, true, false, false], |ui, _| {
+        assert!(!ui.draw_gui(tool).tools());
+    });
+}
+
+#[test]
+fn combobox_icons() {
+    let mut tool = Tool {
+        tools: vec![ImString::new("Pen"), ImString::new("Eraser")],
+        icons: vec![ImString::new("P"), ImString::new("E")],
+    };
+    open_tool(&mut tool);
+}
+
+#[test]
+#[should_panic(expected = "has 1 icons for 2 items")]
+fn combobox_icons_mismatch() {
+    let mut tool = Tool {
+        tools: vec![ImString::new("Pen"), ImString::new("Eraser")],
+        icons: vec![ImString::new("P")],
+    };
+    open_tool(&mut tool);
+}
+
+/// Opens the first combobox of `ext`, and types `text` while it is open.
+#[cfg(any(feature = "smallvec", feature = "arrayvec"))]
+fn type_ahead<T: imgui_ext::Gui>(ext: &mut T, text: &str) {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+    common::drive(
+        &mut ctx,
+        5,
+        |io, frame, [x, y]| {
+            io.mouse_pos = [x + 4.0, y + 4.0];
+            io.mouse_down[0] = frame == 1;
+            if frame == 3 {
+                text.chars().for_each(|c| io.add_input_character(c));
+            }
+        },
+        |ui, _| {
+            ui.draw_gui(ext);
+        },
+    );
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn combobox_smallvec() {
+    use smallvec::SmallVec;
+
+    #[derive(imgui_ext::Gui)]
+    struct Fruits {
+        #[imgui(combobox(typeahead, on_select = "Fruits::select"))]
+        fruits: SmallVec<[ImString; 4]>,
+        selected: Option<usize>,
+    }
+
+    impl Fruits {
+        fn select(index: usize, ext: &mut Self) {
+            ext.selected = Some(index);
+        }
+    }
+
+    let mut fruits = Fruits {
+        fruits: ["Apple", "Banana", "Cherry"]
+            .iter()
+            .map(|&fruit| ImString::new(fruit))
+            .collect(),
+        selected: None,
+    };
+    type_ahead(&mut fruits, "ch");
+    assert_eq!(Some(2), fruits.selected);
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn combobox_arrayvec() {
+    use arrayvec::ArrayVec;
+
+    #[derive(imgui_ext::Gui)]
+    struct Fruits {
+        #[imgui(combobox(typeahead, on_select = "Fruits::select"))]
+        fruits: ArrayVec<[ImString; 4]>,
+        selected: Option<usize>,
+    }
+
+    impl Fruits {
+        fn select(index: usize, ext: &mut Self) {
+            ext.selected = Some(index);
+        }
+    }
+
+    let mut fruits = Fruits {
+        fruits: ["Apple", "Banana", "Cherry"]
+            .iter()
+            .map(|&fruit| ImString::new(fruit))
+            .collect(),
+        selected: None,
+    };
+    type_ahead(&mut fruits, "ba");
+    assert_eq!(Some(1), fruits.selected);
+}
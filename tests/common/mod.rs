@@ -0,0 +1,82 @@
+//! Helpers shared by the integration tests, to draw frames without a
+//! renderer and to drive the mouse over the widgets.
+#![allow(dead_code)]
+
+use imgui::{Context, Io, Ui};
+
+/// Context that can draw frames without a renderer (and without saving the
+/// layout to an `.ini` file).
+pub fn headless() -> Context {
+    let mut ctx = Context::create();
+    ctx.set_ini_filename(None);
+    ctx.io_mut().display_size = [640.0, 480.0];
+    ctx.fonts().build_rgba32_texture();
+    ctx
+}
+
+/// Draws `frames` frames. The first one is drawn with the mouse away from the
+/// window, and records the position of the first item (the cursor before
+/// `draw`). `input` sets the input of every other frame, given the frame
+/// number and that position.
+pub fn drive<I, F>(ctx: &mut Context, frames: usize, mut input: I, mut draw: F)
+where
+    I: FnMut(&mut Io, usize, [f32; 2]),
+    F: FnMut(&Ui, usize),
+{
+    let mut pos = [0.0, 0.0];
+    for frame in 0..frames {
+        {
+            let io = ctx.io_mut();
+            if frame == 0 {
+                io.mouse_pos = [-1.0, -1.0];
+                io.mouse_down[0] = false;
+            } else {
+                input(io, frame, pos);
+            }
+        }
+        let ui = ctx.frame();
+        if frame == 0 {
+            pos = ui.cursor_screen_pos();
+        }
+        draw(&ui, frame);
+    }
+}
+
+/// Draws a frame per entry of `down`, with the mouse over the first item of
+/// the window (4 pixels past its top left corner), and the left button held
+/// on the frames where `down` is `true`. The mouse is away from the window on
+/// the first frame.
+pub fn click_first_item<F>(ctx: &mut Context, down: &[bool], draw: F)
+where
+    F: FnMut(&Ui, usize),
+{
+    drive(
+        ctx,
+        down.len(),
+        |io, frame, [x, y]| {
+            io.mouse_pos = [x + 4.0, y + 4.0];
+            io.mouse_down[0] = down[frame];
+        },
+        draw,
+    );
+}
+
+/// Clicks the first item of the window to focus it (such as a text input),
+/// and types `text` on the next frame. Draws `frames` frames in total.
+pub fn type_into_first_item<F>(ctx: &mut Context, frames: usize, text: &str, draw: F)
+where
+    F: FnMut(&Ui, usize),
+{
+    drive(
+        ctx,
+        frames,
+        |io, frame, [x, y]| {
+            io.mouse_pos = [x + 4.0, y + 4.0];
+            io.mouse_down[0] = frame == 1;
+            if frame == 2 {
+                text.chars().for_each(|c| io.add_input_character(c));
+            }
+        },
+        draw,
+    );
+}
@@ -0,0 +1,33 @@
+#![allow(deprecated)]
+
+use imgui_ext::{ImGuiExt, UiExt};
+
+mod common;
+
+#[derive(imgui_ext::Gui, Default)]
+struct New {
+    #[imgui(checkbox)]
+    check: bool,
+}
+
+#[derive(imgui_ext::ImGuiExt, Default)]
+struct Old {
+    #[imgui(slider(min = 0.0, max = 1.0))]
+    value: f32,
+}
+
+#[test]
+fn imgui_ext() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut new = New::default();
+    let events = ui.imgui_ext(&mut new);
+    assert!(!events.check());
+    let events = ImGuiExt::imgui_ext(&ui, &mut new);
+    assert!(!events.check());
+
+    let mut old = Old::default();
+    let events = ui.imgui_ext(&mut old);
+    assert!(!events.value());
+}
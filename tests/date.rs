@@ -0,0 +1,62 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{NaiveDate, NaiveDateTime};
+use imgui_ext::date::with_date;
+
+mod common;
+
+#[derive(imgui_ext::Gui)]
+struct Release {
+    #[imgui(drag(speed = 0.1))]
+    date: NaiveDate,
+    #[imgui(input)]
+    built: NaiveDateTime,
+}
+
+#[test]
+fn date_edit() {
+    let mut date = NaiveDate::from_ymd(2020, 1, 31);
+
+    // next month
+    assert!(with_date(&mut date, |ymd| {
+        ymd[1] += 2;
+        true
+    }));
+    assert_eq!(NaiveDate::from_ymd(2020, 3, 31), date);
+
+    // invalid dates are reverted
+    assert!(!with_date(&mut date, |ymd| {
+        ymd[1] = 13;
+        true
+    }));
+    assert!(!with_date(&mut date, |ymd| {
+        ymd[1] = 2;
+        true
+    }));
+    assert_eq!(NaiveDate::from_ymd(2020, 3, 31), date);
+
+    // the time is kept
+    let mut built = NaiveDate::from_ymd(2020, 2, 28).and_hms(12, 30, 0);
+    assert!(with_date(&mut built, |ymd| {
+        ymd[2] = 29;
+        true
+    }));
+    assert_eq!(NaiveDate::from_ymd(2020, 2, 29).and_hms(12, 30, 0), built);
+}
+
+#[test]
+fn date_widgets() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut release = Release {
+        date: NaiveDate::from_ymd(2020, 6, 1),
+        built: NaiveDate::from_ymd(2020, 5, 30).and_hms(8, 0, 0),
+    };
+    let ui = ctx.frame();
+    let events = ui.draw_gui(&mut release);
+
+    assert!(events.widgets_changed().is_empty());
+    assert_eq!(NaiveDate::from_ymd(2020, 6, 1), release.date);
+}
@@ -0,0 +1,44 @@
+use imgui_ext::UiExt;
+use std::ops::{Deref, DerefMut};
+
+mod common;
+
+#[derive(Default)]
+struct Scaled {
+    value: f32,
+}
+
+impl Deref for Scaled {
+    type Target = f32;
+    fn deref(&self) -> &f32 {
+        &self.value
+    }
+}
+
+impl DerefMut for Scaled {
+    fn deref_mut(&mut self) -> &mut f32 {
+        &mut self.value
+    }
+}
+
+#[derive(imgui_ext::Gui, Default)]
+struct Config {
+    #[imgui(deref, drag(speed = 0.1))]
+    scale: Scaled,
+    #[imgui(deref, slider(min = 0.0, max = 1.0), display)]
+    alpha: Box<f32>,
+}
+
+#[test]
+fn deref() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut config = Config::default();
+    *config.scale = 2.0;
+    let events = ui.draw_gui(&mut config);
+
+    assert!(!events.scale());
+    assert!(!events.alpha());
+    assert_eq!(2.0, *config.scale);
+}
@@ -0,0 +1,34 @@
+use imgui_ext::UiExt;
+
+mod common;
+
+#[derive(Default, imgui_ext::Gui)]
+#[imgui(doc_tooltips)]
+struct Test {
+    /// Toggles the thing.
+    ///
+    /// Spans multiple lines.
+    #[imgui(checkbox)]
+    a: bool,
+    /// Several widgets share one tooltip.
+    #[imgui(separator, slider(min = 0.0, max = 1.0))]
+    b: f32,
+    // not documented
+    #[imgui(checkbox)]
+    c: bool,
+    /// Not drawn.
+    d: bool,
+}
+
+#[test]
+fn doc_tooltips() {
+    let mut ctx = common::headless();
+
+    let mut test = Test::default();
+
+    // Find the first checkbox, then hover it so its tooltip is drawn.
+    common::click_first_item(&mut ctx, &[false, false, false], |ui, _| {
+        let events = ui.draw_gui(&mut test);
+        assert!(events.widgets_changed().is_empty());
+    });
+}
@@ -0,0 +1,180 @@
+mod common;
+
+#[test]
+fn drag_speed_field() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(slider(min = 0.0, max = 1.0))]
+        sensitivity: f32,
+        #[imgui(drag(speed_field = "sensitivity"))]
+        a: f32,
+        #[imgui(drag(min = 0, max = 10, speed_field = "sensitivity"))]
+        b: [i32; 2],
+    }
+}
+
+#[test]
+fn drag_speed_fast() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(drag(speed = 0.01, speed_fast = 1.0))]
+        a: f32,
+        #[imgui(drag(speed_fast = "10.0"))]
+        b: [f32; 2],
+        #[imgui(slider(min = 0.0, max = 1.0))]
+        sensitivity: f32,
+        #[imgui(drag(speed_field = "sensitivity", speed_fast = 5.0))]
+        c: f32,
+    }
+}
+
+#[test]
+fn drag_labels() {
+    use imgui_ext::UiExt;
+
+    #[derive(imgui_ext::Gui)]
+    struct Transform {
+        #[imgui(drag(speed = 0.1, labels = "[\"X\", \"Y\", \"Z\"]"))]
+        position: [f32; 3],
+        #[imgui(drag(min = 0, labels = "[\"W\", \"H\"]", catch = "resize"))]
+        size: [i32; 2],
+    }
+
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut transform = Transform {
+        position: [1.0, 2.0, 3.0],
+        size: [640, 480],
+    };
+    let events = ui.draw_gui(&mut transform);
+
+    assert!(!events.position());
+    assert!(!events.resize());
+    assert_eq!([1.0, 2.0, 3.0], transform.position);
+    assert_eq!([640, 480], transform.size);
+}
+
+#[derive(imgui_ext::Gui)]
+struct Reset {
+    #[imgui(drag(reset_on_dclick, catch = "reset"))]
+    value: f32,
+    #[imgui(drag(reset_on_dclick, labels = "[\"X\", \"Y\"]"))]
+    axes: [f32; 2],
+}
+
+#[test]
+fn drag_reset_on_dclick() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut reset = Reset {
+        value: 4.0,
+        axes: [1.0, 2.0],
+    };
+    let mut events = Vec::new();
+
+    // Move away, then click twice over the first drag (a double click).
+    common::click_first_item(&mut ctx, &[false, true, false, true, false], |ui, frame| {
+        events.push(ui.draw_gui(&mut reset).reset());
+
+        if frame < 3 {
+            assert_eq!(4.0, reset.value);
+        }
+    });
+
+    assert_eq!(0.0, reset.value);
+    assert_eq!(vec![false, false, false, true, false], events);
+    assert_eq!([1.0, 2.0], reset.axes);
+}
+
+#[derive(imgui_ext::Gui)]
+struct Mixer {
+    #[imgui(drag(reset_to = "100.0", catch = "gain"))]
+    gain: f32,
+}
+
+#[test]
+fn drag_reset_to() {
+    use imgui_ext::UiExt;
+    use std::cell::Cell;
+
+    let mut ctx = common::headless();
+
+    let mut mixer = Mixer { gain: 3.0 };
+    let button = Cell::new([0.0, 0.0]);
+    let mut events = Vec::new();
+
+    // Move away, then click the `Reset` button (the last item drawn).
+    common::drive(
+        &mut ctx,
+        3,
+        |io, frame, _| {
+            let [x, y] = button.get();
+            io.mouse_pos = [x + 4.0, y + 4.0];
+            io.mouse_down[0] = frame == 1;
+        },
+        |ui, frame| {
+            events.push(ui.draw_gui(&mut mixer).gain());
+            if frame == 0 {
+                button.set(ui.get_item_rect_min());
+            }
+
+            if frame < 2 {
+                assert_eq!(3.0, mixer.gain);
+            }
+        },
+    );
+
+    assert_eq!(100.0, mixer.gain);
+    assert_eq!(vec![false, false, true], events);
+}
+
+#[derive(imgui_ext::Gui)]
+struct Scrub {
+    #[imgui(drag(speed = 1.0, started = "grab", finished = "release"))]
+    value: f32,
+}
+
+#[test]
+fn drag_started_finished() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut scrub = Scrub { value: 0.0 };
+    let mut events = Vec::new();
+
+    // Press the drag, move the mouse to the right while it is held down, and
+    // release it.
+    common::drive(
+        &mut ctx,
+        6,
+        |io, frame, [x, y]| {
+            io.mouse_pos = match frame {
+                1 | 2 => [x + 4.0, y + 4.0],
+                _ => [x + 24.0, y + 4.0],
+            };
+            io.mouse_down[0] = frame <= 3;
+        },
+        |ui, _| {
+            let ev = ui.draw_gui(&mut scrub);
+            events.push((ev.grab(), ev.release()));
+        },
+    );
+
+    assert!(scrub.value > 0.0);
+    // a single edit: started on press, finished on release
+    assert_eq!(
+        vec![
+            (false, false),
+            (true, false),
+            (false, false),
+            (false, false),
+            (false, true),
+            (false, false),
+        ],
+        events
+    );
+}
@@ -0,0 +1,59 @@
+use imgui_ext::UiExt;
+use std::time::Duration;
+
+mod common;
+
+#[derive(imgui_ext::Gui)]
+struct Config {
+    #[imgui(drag(speed = 0.1))]
+    timeout: Duration,
+    #[imgui(input(step = 10.0, unit = "ms"))]
+    debounce: Duration,
+    #[imgui(drag(unit = "ms"))]
+    delay: Option<Duration>,
+}
+
+#[test]
+fn duration_untouched() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut config = Config {
+        timeout: Duration::from_secs(30),
+        debounce: Duration::from_millis(250),
+        delay: Some(Duration::from_millis(5)),
+    };
+    let events = ui.draw_gui(&mut config);
+
+    assert!(!events.timeout());
+    assert!(!events.debounce());
+    assert!(!events.delay());
+    assert_eq!(Duration::from_secs(30), config.timeout);
+    assert_eq!(Duration::from_millis(250), config.debounce);
+    assert_eq!(Some(Duration::from_millis(5)), config.delay);
+}
+
+#[derive(imgui_ext::Gui)]
+struct Debounce {
+    #[imgui(input(unit = "ms", auto_select_all))]
+    debounce: Duration,
+}
+
+#[test]
+fn duration_ms() {
+    let mut ctx = common::headless();
+
+    let mut debounce = Debounce {
+        debounce: Duration::from_millis(250),
+    };
+    let mut edited = false;
+
+    // Click the input (selecting all of its text), and type a new value in
+    // milliseconds.
+    common::type_into_first_item(&mut ctx, 4, "125", |ui, _| {
+        edited |= ui.draw_gui(&mut debounce).debounce();
+    });
+
+    assert!(edited);
+    assert_eq!(Duration::from_millis(125), debounce.debounce);
+}
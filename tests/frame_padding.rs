@@ -0,0 +1,35 @@
+use imgui_ext::UiExt;
+
+mod common;
+
+#[derive(imgui_ext::Gui)]
+struct Padded {
+    #[imgui(checkbox(frame_padding = "[10.0, 8.0]"))]
+    padded: bool,
+}
+
+#[derive(imgui_ext::Gui)]
+struct Plain {
+    #[imgui(checkbox)]
+    plain: bool,
+}
+
+#[test]
+fn frame_padding() {
+    let mut ctx = common::headless();
+
+    let mut padded = Padded { padded: false };
+    let mut plain = Plain { plain: false };
+
+    let ui = ctx.frame();
+    ui.draw_gui(&mut plain);
+    let [_, before] = ui.get_item_rect_size();
+    ui.draw_gui(&mut padded);
+    let [_, height] = ui.get_item_rect_size();
+    ui.draw_gui(&mut plain);
+    let [_, after] = ui.get_item_rect_size();
+
+    // only the padded checkbox is taller, the style is restored after it
+    assert!(height > before);
+    assert_eq!(before, after);
+}
@@ -0,0 +1,54 @@
+use imgui_ext::UiExt;
+
+mod common;
+
+#[derive(Default, imgui_ext::Gui)]
+struct Vec3 {
+    #[imgui(drag)]
+    x: f32,
+    #[imgui(drag)]
+    y: f32,
+    #[imgui(drag)]
+    z: f32,
+}
+
+// No `T: Gui` bound needed, it is added by the derive.
+#[derive(Default, imgui_ext::Gui)]
+struct Named<T> {
+    #[imgui(display)]
+    name: String,
+    #[imgui(nested(catch = "inner"))]
+    inner: T,
+}
+
+#[derive(Default, imgui_ext::Gui)]
+struct Scene<'a, T: Default> {
+    #[imgui(nested)]
+    position: Named<Vec3>,
+    #[imgui(tab_bar)]
+    tabs: Named<T>,
+    #[imgui(checkbox)]
+    visible: bool,
+    #[imgui(display)]
+    label: &'a str,
+}
+
+#[test]
+fn generic_nested() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut named = Named {
+        name: String::from("position"),
+        inner: Vec3::default(),
+    };
+    let events = ui.draw_gui(&mut named);
+    // events of the inner type are typed
+    let inner: &<Vec3 as imgui_ext::Gui>::Events = events.inner();
+    assert!(!inner.x() && !inner.y() && !inner.z());
+
+    let mut scene: Scene<Named<Vec3>> = Scene::default();
+    let events = ui.draw_gui(&mut scene);
+    assert!(!events.position().inner().x());
+    assert!(!events.visible());
+}
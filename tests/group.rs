@@ -0,0 +1,22 @@
+#[test]
+fn group() {
+    #[derive(imgui_ext::Gui)]
+    struct Inner {
+        #[imgui(checkbox)]
+        a: bool,
+    }
+
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(group)]
+        a: Inner,
+        #[imgui(group(border, size = "[0.0, 120.0]"))]
+        b: Inner,
+        #[imgui(group(size = "size", catch = "c_events"))]
+        c: Inner,
+    }
+
+    fn size() -> [f32; 2] {
+        [200.0, 100.0]
+    }
+}
@@ -0,0 +1,46 @@
+mod common;
+
+#[derive(imgui_ext::Gui)]
+struct Light {
+    #[imgui(checkbox)]
+    enabled: bool,
+    #[imgui(slider(min = 0.0, max = 10.0, hidden_fn = "Light::is_off"))]
+    intensity: f32,
+    #[imgui(drag(same_line, hidden_fn = "Light::is_off"))]
+    range: f32,
+}
+
+impl Light {
+    fn is_off(&self) -> bool {
+        !self.enabled
+    }
+}
+
+#[test]
+fn hidden_fn() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut light = Light {
+        enabled: true,
+        intensity: 1.0,
+        range: 4.0,
+    };
+
+    let start = ui.cursor_screen_pos();
+    ui.draw_gui(&mut light);
+    let on = ui.cursor_screen_pos()[1] - start[1];
+
+    light.enabled = false;
+    let start = ui.cursor_screen_pos();
+    ui.draw_gui(&mut light);
+    let off = ui.cursor_screen_pos()[1] - start[1];
+
+    // only the checkbox is left
+    assert!(off < on);
+    let start = ui.cursor_screen_pos();
+    ui.checkbox(imgui::im_str!("enabled"), &mut light.enabled);
+    assert_eq!(ui.cursor_screen_pos()[1] - start[1], off);
+}
@@ -0,0 +1,35 @@
+mod common;
+
+// The generated code must not depend on the imports of the module deriving
+// `Gui` (`imgui::im_str!` in particular).
+mod no_imports {
+    #[derive(Default, imgui_ext::Gui)]
+    pub struct Test {
+        #[imgui(
+            text("Header"),
+            bullet(text = "Item"),
+            slider(min = 0.0, max = 1.0, format = "%.1f")
+        )]
+        pub slider: f32,
+        #[imgui(drag(format = "%.3f", labels = "[\"X\", \"Y\"]"))]
+        pub drag: [f32; 2],
+        #[imgui(input(label = "Name"))]
+        pub name: String,
+        #[imgui(progress(overlay = "Loading"))]
+        pub progress: f32,
+        #[imgui(display(label = "Count", display = "{} items", 0))]
+        pub count: (u32,),
+    }
+}
+
+#[test]
+fn hygiene() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut test = no_imports::Test::default();
+    let events = ui.draw_gui(&mut test);
+    assert!(events.widgets_changed().is_empty());
+}
@@ -0,0 +1,45 @@
+mod common;
+
+#[derive(imgui_ext::Gui)]
+struct Toolbar {
+    #[imgui(image_button(size = "size"))]
+    a: usize,
+    #[imgui(image_button(size = "[24.0, 24.0]", bg = "bg", same_line))]
+    b: usize,
+    #[imgui(image_button(
+        size = "[24.0, 24.0]",
+        uv0 = "[0.0, 0.0]",
+        uv1 = "[0.5, 0.5]",
+        tint = "[1.0, 0.0, 0.0, 1.0]",
+        image_padding = 2,
+        catch = "close"
+    ))]
+    c: usize,
+}
+
+fn size() -> [f32; 2] {
+    [16.0, 16.0]
+}
+
+fn bg() -> [f32; 4] {
+    [0.0, 0.0, 0.0, 1.0]
+}
+
+#[test]
+fn image_button() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut toolbar = Toolbar { a: 0, b: 1, c: 2 };
+    let mut clicks = Vec::new();
+
+    // Press and release the mouse over the first button.
+    common::click_first_item(&mut ctx, &[false, true, false], |ui, _| {
+        let events = ui.draw_gui(&mut toolbar);
+        clicks.push((events.a(), events.b(), events.close()));
+    });
+
+    let none = (false, false, false);
+    assert_eq!(vec![none, none, (true, false, false)], clicks);
+}
@@ -0,0 +1,360 @@
+use imgui::ImString;
+
+mod common;
+
+#[test]
+fn input_submit() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(input(enter_returns_true, catch = "submit"))]
+        a: ImString,
+        #[imgui(input(auto_select_all))]
+        b: ImString,
+        #[imgui(input(auto_select_all, enter_returns_true, flags = "flags"))]
+        c: ImString,
+    }
+
+    fn flags() -> imgui::ImGuiInputTextFlags {
+        imgui::ImGuiInputTextFlags::CharsUppercase
+    }
+}
+
+#[derive(imgui_ext::Gui)]
+struct Bindings {
+    #[imgui(input(label = "Jump", catch = "jump"))]
+    jump: char,
+    #[imgui(input(auto_select_all))]
+    crouch: char,
+}
+
+#[test]
+fn input_char() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut bindings = Bindings {
+        jump: 'w',
+        crouch: 'c',
+    };
+    let mut jumped = false;
+
+    // Click the input, and type a new key next to the current one.
+    common::type_into_first_item(&mut ctx, 4, "e", |ui, _| {
+        jumped |= ui.draw_gui(&mut bindings).jump();
+    });
+
+    assert!(jumped);
+    assert_eq!('e', bindings.jump);
+    assert_eq!('c', bindings.crouch);
+}
+
+#[test]
+fn input_buffer_size() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(input(buffer_size = 1024))]
+        a: ImString,
+        #[imgui(input(buffer_size = 32, size = "size"))]
+        b: ImString,
+    }
+
+    fn size() -> [f32; 2] {
+        [200.0, 100.0]
+    }
+}
+
+#[test]
+fn input_string() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(input)]
+        name: String,
+        #[imgui(input(size = "size", enter_returns_true))]
+        notes: String,
+    }
+
+    fn size() -> [f32; 2] {
+        [200.0, 100.0]
+    }
+}
+
+#[test]
+fn input_arity() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(input2)]
+        a: [f32; 2],
+        #[imgui(input3(label = "b", step = 0.1))]
+        b: [f32; 3],
+        #[imgui(input4(catch = "d"))]
+        c: [f32; 4],
+    }
+}
+
+#[test]
+fn input_format() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(input(format = "%.1f dB"))]
+        a: f32,
+        #[imgui(input(format = "0x%08X", step = 1))]
+        b: i32,
+        #[imgui(input3(format = "%.3e"))]
+        c: [f32; 3],
+    }
+}
+
+#[derive(Default, imgui_ext::Gui)]
+struct Nickname {
+    #[imgui(input(max_len = 4, catch = "edit"))]
+    name: String,
+}
+
+#[test]
+fn input_max_len() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut nick = Nickname::default();
+    let mut edited = false;
+
+    // Click the input to focus it, then paste multi-byte text into it.
+    common::type_into_first_item(&mut ctx, 4, "ñandú🦀", |ui, _| {
+        edited |= ui.draw_gui(&mut nick).edit();
+    });
+
+    assert!(edited);
+    assert_eq!("ñand", nick.name);
+    assert_eq!(4, nick.name.chars().count());
+}
+
+#[derive(imgui_ext::Gui)]
+struct Search {
+    #[imgui(input(clear_button, catch = "search"))]
+    query: String,
+    #[imgui(input(clear_button))]
+    tag: ImString,
+}
+
+#[test]
+fn input_clear_button() {
+    use imgui_ext::UiExt;
+    use std::cell::Cell;
+
+    let mut ctx = common::headless();
+
+    let mut search = Search {
+        query: String::from("imgui"),
+        tag: ImString::new("rust"),
+    };
+    let pos = Cell::new([0.0, 0.0]);
+    let mut events = Vec::new();
+
+    // The clear button of the query is the right-most widget of the first row.
+    common::drive(
+        &mut ctx,
+        3,
+        |io, frame, _| {
+            io.mouse_pos = pos.get();
+            io.mouse_down[0] = frame == 1;
+        },
+        |ui, frame| {
+            let (ev, [min, max]) = ui.draw_gui_rect(&mut search);
+            if frame == 0 {
+                pos.set([max[0] - 4.0, min[1] + 4.0]);
+            }
+            events.push(ev.search());
+        },
+    );
+
+    assert_eq!(vec![false, false, true], events);
+    assert_eq!("", search.query);
+    assert_eq!("rust", search.tag.to_str());
+}
+
+#[derive(imgui_ext::Gui)]
+struct Note {
+    #[imgui(input(catch = "edit"))]
+    text: String,
+    #[imgui(input)]
+    tag: ImString,
+}
+
+#[test]
+fn input_buffer_stable() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut note = Note {
+        text: String::from("hello"),
+        tag: ImString::new("rust"),
+    };
+    let mut buffers = Vec::new();
+    let mut edited = false;
+
+    // Focus the text input, and keep it active (without typing) for a few
+    // frames. The fields must keep their buffers, or undo would be lost.
+    let frames = [false, true, false, false, false];
+    common::click_first_item(&mut ctx, &frames, |ui, _| {
+        edited |= ui.draw_gui(&mut note).edit();
+        buffers.push((note.text.as_ptr(), note.tag.as_ptr()));
+    });
+
+    assert!(!edited);
+    assert_eq!("hello", note.text);
+    assert_eq!("rust", note.tag.to_str());
+    // the `ImString` buffer is reserved on the first frame
+    assert!(buffers[1..].iter().all(|&b| b == buffers[1]));
+}
+
+static COMPLETIONS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+fn complete_command(text: &str) -> Option<String> {
+    COMPLETIONS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    ["help", "history"]
+        .iter()
+        .find(|command| command.starts_with(text))
+        .map(|command| command.to_string())
+}
+
+#[derive(imgui_ext::Gui)]
+struct CommandBar {
+    #[imgui(input(completion = "complete_command"))]
+    command: String,
+    #[imgui(input(completion = "complete_command"))]
+    history: ImString,
+}
+
+#[test]
+fn input_completion() {
+    use imgui::sys;
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+    ctx.io_mut().key_map[sys::ImGuiKey_Tab as usize] = 9;
+
+    let mut bar = CommandBar {
+        command: String::new(),
+        history: ImString::new("hi"),
+    };
+
+    // Click the command input, type "he", and press TAB.
+    common::drive(
+        &mut ctx,
+        5,
+        |io, frame, [x, y]| {
+            io.mouse_pos = [x + 4.0, y + 4.0];
+            io.mouse_down[0] = frame == 1;
+            if frame == 2 {
+                io.add_input_character('h');
+                io.add_input_character('e');
+            }
+            io.keys_down[9] = frame == 3;
+        },
+        |ui, _| {
+            ui.draw_gui(&mut bar);
+        },
+    );
+
+    assert_eq!(1, COMPLETIONS.load(std::sync::atomic::Ordering::SeqCst));
+    assert_eq!("help", bar.command);
+    // the other input isn't active
+    assert_eq!("hi", bar.history.to_str());
+}
+
+fn single_line(text: &str) -> String {
+    text.chars().filter(|c| !c.is_control()).collect()
+}
+
+fn note_size() -> [f32; 2] {
+    [200.0, 100.0]
+}
+
+#[derive(imgui_ext::Gui)]
+struct Tag {
+    // multiline, so the newlines make it past imgui
+    #[imgui(input(size = "note_size", sanitize = "single_line"))]
+    name: String,
+}
+
+#[test]
+fn input_sanitize() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut tag = Tag {
+        name: String::new(),
+    };
+
+    // Click the input, and paste a couple of lines into it.
+    common::type_into_first_item(&mut ctx, 4, "foo\nbar\n", |ui, _| {
+        ui.draw_gui(&mut tag);
+    });
+
+    assert_eq!("foobar", tag.name);
+}
+
+#[derive(imgui_ext::Gui)]
+struct Device {
+    #[imgui(input)]
+    name: [u8; 32],
+}
+
+#[test]
+fn input_cstr() {
+    use imgui_ext::UiExt;
+    use std::ffi::CStr;
+
+    let mut ctx = common::headless();
+
+    let mut device = Device { name: [0; 32] };
+    device.name[..3].copy_from_slice(b"eth");
+
+    // Click the input, and type "0" at the end of the text.
+    common::drive(
+        &mut ctx,
+        4,
+        |io, frame, [x, y]| {
+            io.mouse_pos = [x + 120.0, y + 4.0];
+            io.mouse_down[0] = frame == 1;
+            if frame == 2 {
+                io.add_input_character('0');
+            }
+        },
+        |ui, frame| {
+            let events = ui.draw_gui(&mut device);
+            assert_eq!(frame == 2, events.name());
+        },
+    );
+
+    let name = CStr::from_bytes_with_nul(&device.name[..5]).unwrap();
+    assert_eq!("eth0", name.to_str().unwrap());
+}
+
+#[derive(imgui_ext::Gui)]
+struct Gain {
+    #[imgui(input(auto_select_all))]
+    gain: f32,
+}
+
+#[test]
+fn input_non_finite() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut gain = Gain { gain: 2.5 };
+
+    // Click the input (selecting all of its text), and replace the text with
+    // a value that doesn't fit in an `f32`.
+    common::type_into_first_item(&mut ctx, 4, "1e99", |ui, _| {
+        assert!(!ui.draw_gui(&mut gain).gain());
+    });
+
+    // the infinity is reverted
+    assert_eq!(2.5, gain.gain);
+}
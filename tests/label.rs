@@ -0,0 +1,80 @@
+mod common;
+
+const LABEL: &str = "Label";
+
+mod labels {
+    pub const SLIDER: &str = "Slider";
+}
+
+#[test]
+fn label_path() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(checkbox(label = LABEL))]
+        a: bool,
+        #[imgui(slider(label = labels::SLIDER, min = 0.0, max = 1.0))]
+        b: f32,
+        #[imgui(drag(label = LABEL), input(label = "literal"))]
+        c: f32,
+    }
+}
+
+#[test]
+fn static_label() {
+    let labels: Vec<_> = (0..2).map(|_| imgui_ext::static_label!(LABEL)).collect();
+
+    assert_eq!("Label", labels[0].to_str());
+    // the label is a static, not a new string every frame.
+    assert!(std::ptr::eq(labels[0], labels[1]));
+}
+
+#[test]
+fn label_inline() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(slider(min = 0.0, max = 1.0, label = "Volume", label_inline))]
+        a: f32,
+        #[imgui(checkbox(label = LABEL, label_inline))]
+        b: bool,
+        #[imgui(input(label_inline, enter_returns_true))]
+        c: f32,
+    }
+}
+
+// U+F0C7 is the "save" icon of Font Awesome (a 3-byte UTF-8 sequence).
+const ICON_SAVE: &str = "\u{f0c7} Save";
+
+#[derive(imgui_ext::Gui)]
+struct Toolbar {
+    #[imgui(button(label = "\u{f0c7} Save", catch = "save"))]
+    save: (),
+    #[imgui(
+        checkbox(label = "\u{f0c7} Autosave ✓"),
+        checkbox(label = ICON_SAVE)
+    )]
+    autosave: bool,
+}
+
+#[test]
+fn label_glyph() {
+    use imgui_ext::UiExt;
+
+    assert_eq!(ICON_SAVE, imgui_ext::static_label!(ICON_SAVE).to_str());
+    assert_eq!(ICON_SAVE, imgui::im_str!("\u{f0c7} Save").to_str());
+
+    let mut ctx = common::headless();
+
+    let mut toolbar = Toolbar {
+        save: (),
+        autosave: false,
+    };
+    let mut saved = Vec::new();
+
+    // The button is still clickable with a label outside of the font.
+    common::click_first_item(&mut ctx, &[false, true, false], |ui, _| {
+        saved.push(ui.draw_gui(&mut toolbar).save());
+    });
+
+    assert_eq!(vec![false, false, true], saved);
+    assert!(!toolbar.autosave);
+}
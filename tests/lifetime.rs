@@ -0,0 +1,35 @@
+use imgui_ext::UiExt;
+
+mod common;
+
+#[derive(imgui_ext::Gui)]
+struct View<'a, 'b> {
+    #[imgui(drag(speed = 0.1))]
+    value: &'a mut f32,
+    #[imgui(slider(min = 0, max = 10))]
+    array: &'a mut [i32; 3],
+    #[imgui(display)]
+    name: &'b str,
+}
+
+#[test]
+fn lifetime() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut value = 4.0;
+    let mut array = [1, 2, 3];
+    let name = String::from("view");
+
+    let mut view = View {
+        value: &mut value,
+        array: &mut array,
+        name: &name,
+    };
+    let events = ui.draw_gui(&mut view);
+
+    assert!(!events.value());
+    assert!(!events.array());
+    assert_eq!(4.0, value);
+    assert_eq!([1, 2, 3], array);
+}
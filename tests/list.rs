@@ -0,0 +1,35 @@
+mod common;
+
+#[derive(Default, imgui_ext::Gui)]
+struct Item {
+    #[imgui(input)]
+    name: String,
+    #[imgui(drag(min = 0))]
+    count: i32,
+}
+
+#[derive(Default, imgui_ext::Gui)]
+struct Inventory {
+    #[imgui(list(label = "Item", catch = "resized"))]
+    items: Vec<Item>,
+}
+
+#[test]
+fn list_add() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut inventory = Inventory::default();
+    let mut events = Vec::new();
+
+    // the "Add" button is the only widget of an empty list
+    common::click_first_item(&mut ctx, &[false, true, false, false], |ui, _| {
+        events.push(ui.draw_gui(&mut inventory).resized());
+    });
+
+    assert_eq!(vec![false, false, true, false], events);
+    assert_eq!(1, inventory.items.len());
+    assert_eq!("", inventory.items[0].name);
+    assert_eq!(0, inventory.items[0].count);
+}
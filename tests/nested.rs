@@ -0,0 +1,94 @@
+mod common;
+
+#[test]
+fn nested_id() {
+    #[derive(imgui_ext::Gui)]
+    struct Vec3 {
+        #[imgui(drag)]
+        x: f32,
+        #[imgui(drag)]
+        y: f32,
+        #[imgui(drag)]
+        z: f32,
+    }
+
+    #[derive(imgui_ext::Gui)]
+    struct Transform {
+        #[imgui(nested(id = "position"))]
+        position: Vec3,
+        #[imgui(nested(id = "scale", catch = "scale"))]
+        scale: Vec3,
+        #[imgui(nested(id = 42))]
+        rotation: Vec3,
+    }
+}
+
+#[test]
+fn nested_events_by_ref() {
+    #[derive(imgui_ext::Gui)]
+    struct Form {
+        #[imgui(checkbox)]
+        remember: bool,
+        #[imgui(button(label = "Login", catch = "login"))]
+        _login: (),
+    }
+
+    #[derive(imgui_ext::Gui)]
+    struct Example {
+        #[imgui(nested(catch = "form_events"))]
+        form: Form,
+    }
+
+    type FormEvents = <Form as imgui_ext::Gui>::Events;
+    type Events = <Example as imgui_ext::Gui>::Events;
+
+    let events = Events {
+        form_events: FormEvents {
+            remember: false,
+            login: true,
+        },
+    };
+
+    let form: &FormEvents = events.form_events();
+    assert!(std::ptr::eq(form, &events.form_events));
+    assert!(form.login());
+    assert!(!form.remember());
+}
+
+#[test]
+fn nested_columns() {
+    use imgui_ext::UiExt;
+
+    #[derive(Default, imgui_ext::Gui)]
+    struct Size {
+        #[imgui(drag(label = "W"))]
+        width: f32,
+        #[imgui(drag(label = "H"))]
+        height: f32,
+    }
+
+    #[derive(Default, imgui_ext::Gui)]
+    struct Rows {
+        #[imgui(nested)]
+        size: Size,
+    }
+
+    #[derive(Default, imgui_ext::Gui)]
+    struct Columns {
+        #[imgui(nested(columns = 2))]
+        size: Size,
+    }
+
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let (_, [rows_min, rows_max]) = ui.draw_gui_rect(&mut Rows::default());
+    let (_, [cols_min, cols_max]) = ui.draw_gui_rect(&mut Columns::default());
+
+    // both fields fit in a single row
+    assert!(cols_max[1] - cols_min[1] < rows_max[1] - rows_min[1]);
+
+    // the layout doesn't leak out of the nested UI
+    let (_, [min, max]) = ui.draw_gui_rect(&mut Rows::default());
+    assert_eq!(rows_max[1] - rows_min[1], max[1] - min[1]);
+}
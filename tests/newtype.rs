@@ -0,0 +1,12 @@
+#[test]
+fn newtype() {
+    struct Meters(pub f32);
+
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(newtype, drag(speed = 0.1))]
+        a: Meters,
+        #[imgui(newtype, drag(label = "foo", min = 0.0, max = 100.0), display)]
+        b: Meters,
+    }
+}
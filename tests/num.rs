@@ -0,0 +1,42 @@
+use imgui_ext::UiExt;
+use std::num::{NonZeroU32, NonZeroU8, NonZeroUsize, Wrapping};
+
+mod common;
+
+#[derive(imgui_ext::Gui)]
+struct Generator {
+    #[imgui(drag, input)]
+    seed: Wrapping<u32>,
+    #[imgui(slider(min = -1.0, max = 1.0))]
+    bias: Wrapping<f32>,
+    #[imgui(slider(min = 1, max = 64), input(catch = "samples_input"))]
+    samples: NonZeroU32,
+    #[imgui(drag(min = 1, max = 8))]
+    octaves: NonZeroU8,
+    #[imgui(input(step = 1))]
+    threads: Option<NonZeroUsize>,
+}
+
+#[test]
+fn num_wrappers() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut gen = Generator {
+        seed: Wrapping(u32::max_value()),
+        bias: Wrapping(0.5),
+        samples: NonZeroU32::new(16).unwrap(),
+        octaves: NonZeroU8::new(4).unwrap(),
+        threads: NonZeroUsize::new(2),
+    };
+    let events = ui.draw_gui(&mut gen);
+
+    assert!(!events.seed());
+    assert!(!events.samples());
+    assert!(!events.samples_input());
+    assert_eq!(Wrapping(u32::max_value()), gen.seed);
+    assert_eq!(Wrapping(0.5), gen.bias);
+    assert_eq!(16, gen.samples.get());
+    assert_eq!(4, gen.octaves.get());
+    assert_eq!(NonZeroUsize::new(2), gen.threads);
+}
@@ -0,0 +1,41 @@
+use imgui_ext::{Gui, OptionEvents};
+
+mod common;
+
+#[derive(imgui_ext::Gui, Default)]
+struct Sub {
+    #[imgui(checkbox)]
+    a: bool,
+    #[imgui(slider(min = 0.0, max = 1.0))]
+    b: f32,
+}
+
+#[derive(imgui_ext::Gui, Default)]
+struct Test {
+    #[imgui(nested)]
+    sub: Option<Sub>,
+}
+
+#[test]
+fn option_nested() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+    let mut test = Test::default();
+    let mut states = Vec::new();
+    let mut toggled = Vec::new();
+
+    // the "Enabled" checkbox is the first item, and toggles on release.
+    let down = [false, true, false, true, false];
+    common::click_first_item(&mut ctx, &down, |ui, _| {
+        let events = ui.draw_gui(&mut test);
+        let sub: &OptionEvents<<Sub as Gui>::Events> = events.sub();
+        assert_eq!(test.sub.is_some(), sub.events().is_some());
+        toggled.push(sub.toggled());
+        states.push(test.sub.as_ref().map(|sub| (sub.a, sub.b)));
+    });
+
+    let default = Some((false, 0.0));
+    assert_eq!(vec![None, None, default, default, None], states);
+    assert_eq!(vec![false, false, true, false, true], toggled);
+}
@@ -0,0 +1,51 @@
+use imgui::Ui;
+use imgui_ext::{Gui, UiExt};
+use std::cell::RefCell;
+
+mod common;
+
+thread_local! {
+    static DRAWN: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+/// Records the order in which it is drawn.
+struct Log(&'static str);
+
+impl Gui for Log {
+    type Events = ();
+    fn draw_gui(_: &Ui, ext: &mut Self) -> Self::Events {
+        DRAWN.with(|drawn| drawn.borrow_mut().push(ext.0));
+    }
+}
+
+#[derive(imgui_ext::Gui)]
+struct Test {
+    #[imgui(nested)]
+    a: Log,
+    #[imgui(order = 2, nested)]
+    b: Log,
+    #[imgui(nested)]
+    c: Log,
+    #[imgui(order = 0, separator, nested)]
+    d: Log,
+    #[imgui(order = 1, nested)]
+    e: Log,
+}
+
+#[test]
+fn order() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut test = Test {
+        a: Log("a"),
+        b: Log("b"),
+        c: Log("c"),
+        d: Log("d"),
+        e: Log("e"),
+    };
+    ui.draw_gui(&mut test);
+
+    let drawn = DRAWN.with(|drawn| drawn.borrow().clone());
+    assert_eq!(vec!["d", "e", "b", "a", "c"], drawn);
+}
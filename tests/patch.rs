@@ -0,0 +1,23 @@
+mod common;
+
+#[derive(Clone, Debug, PartialEq, imgui_ext::Gui)]
+#[imgui(patch)]
+struct Test {
+    #[imgui(slider(min = 0.0, max = 1.0))]
+    a: f32,
+    #[imgui(checkbox)]
+    b: bool,
+}
+
+#[test]
+fn patch() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let original = Test { a: 0.5, b: true };
+    let (patch, events) = Test::draw_gui_patch(&ui, &original);
+
+    assert_eq!(Test { a: 0.5, b: true }, original);
+    assert_eq!(original, patch);
+    assert!(events.widgets_changed().is_empty());
+}
@@ -0,0 +1,31 @@
+use imgui_ext::UiExt;
+use std::marker::PhantomData;
+
+mod common;
+
+#[derive(Default, imgui_ext::Gui)]
+struct Settings {
+    #[imgui(text("Settings"), separator)]
+    header: PhantomData<()>,
+    #[imgui(checkbox)]
+    vsync: bool,
+    #[imgui(
+        new_line,
+        button(label = "Save", catch = "save"),
+        button(label = "Reset", same_line, catch = "reset")
+    )]
+    toolbar: PhantomData<()>,
+}
+
+#[test]
+fn phantom() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut settings = Settings::default();
+    let events = ui.draw_gui(&mut settings);
+
+    assert!(!events.save());
+    assert!(!events.reset());
+    assert!(!settings.vsync);
+}
@@ -0,0 +1,66 @@
+use imgui::StyleColor;
+use imgui_ext::UiExt;
+
+mod common;
+
+#[test]
+fn plot() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(plot(lines))]
+        a: Vec<f32>,
+        #[imgui(plot(lines(offset = 3), histogram(label = "foo", stride = 8)))]
+        b: [f32; 8],
+    }
+}
+
+#[derive(imgui_ext::Gui)]
+struct Ring {
+    // the oldest value is stored at `head`
+    #[imgui(plot(lines(offset = "head", min = 0.0, max = 1.0)))]
+    samples: [f32; 8],
+    head: usize,
+}
+
+/// Draws the plot of `ring`, and returns the points of its line (the vertices
+/// drawn with the `PlotLines` color), from left to right.
+fn plot_points(ring: &mut Ring) -> Vec<[f32; 2]> {
+    let mut ctx = common::headless();
+    ctx.style_mut().colors[StyleColor::PlotLines as usize] = [1.0, 0.0, 0.0, 1.0];
+    let ui = ctx.frame();
+    ui.draw_gui(ring);
+
+    let mut points: Vec<_> = ui
+        .render()
+        .draw_lists()
+        .flat_map(|list| list.vtx_buffer().iter())
+        .filter(|vertex| vertex.col == [255, 0, 0, 255])
+        .map(|vertex| vertex.pos)
+        .collect();
+    points.sort_by(|a, b| a[0].partial_cmp(&b[0]).unwrap());
+    points
+}
+
+/// Top of the plotted line (the point of the largest value).
+fn top(points: &[[f32; 2]]) -> f32 {
+    points
+        .iter()
+        .map(|point| point[1])
+        .fold(std::f32::INFINITY, f32::min)
+}
+
+#[test]
+fn plot_ring_buffer() {
+    // a single peak, at the oldest value of the ring buffer
+    let mut samples = [0.0; 8];
+    samples[5] = 1.0;
+
+    // the plot starts at the oldest value
+    let points = plot_points(&mut Ring { samples, head: 5 });
+    assert!(!points.is_empty());
+    assert_eq!(top(&points), points[0][1]);
+
+    // without the offset, it starts at the first value of the array
+    let points = plot_points(&mut Ring { samples, head: 0 });
+    assert!(points[0][1] > top(&points));
+}
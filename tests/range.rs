@@ -0,0 +1,42 @@
+use imgui_ext::UiExt;
+use std::ops::{Range, RangeInclusive};
+
+mod common;
+
+#[derive(imgui_ext::Gui)]
+struct Spawner {
+    #[imgui(drag(speed = 1.0, catch = "delay"))]
+    delay: Range<f32>,
+    #[imgui(slider(min = 1, max = 16))]
+    count: RangeInclusive<i32>,
+}
+
+#[test]
+fn drag_range() {
+    let mut ctx = common::headless();
+
+    let mut spawner = Spawner {
+        delay: 0.0..1.0,
+        count: 2..=4,
+    };
+    let mut delay = false;
+
+    // Press the mouse over the start of the range, then drag it past the end.
+    common::drive(
+        &mut ctx,
+        5,
+        |io, frame, [x, y]| {
+            io.mouse_pos = match frame {
+                1 | 2 => [x + 4.0, y + 4.0],
+                _ => [x + 44.0, y + 4.0],
+            };
+            io.mouse_down[0] = frame < 4;
+        },
+        |ui, _| delay |= ui.draw_gui(&mut spawner).delay(),
+    );
+
+    // the start stops at the end of the range
+    assert!(delay);
+    assert_eq!(1.0..1.0, spawner.delay);
+    assert_eq!(2..=4, spawner.count);
+}
@@ -0,0 +1,30 @@
+use imgui_ext::UiExt;
+
+mod common;
+
+#[derive(Default, imgui_ext::Gui)]
+struct Form {
+    #[imgui(input)]
+    name: String,
+    #[imgui(slider(min = 0, max = 100))]
+    age: i32,
+    #[imgui(checkbox, button(label = "Submit", catch = "submit"))]
+    subscribe: bool,
+}
+
+#[test]
+fn draw_gui_rect() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let start = ui.cursor_screen_pos();
+    let (events, [min, max]) = ui.draw_gui_rect(&mut Form::default());
+
+    assert!(!events.submit());
+    assert_eq!(start, min);
+    assert!(max[0] > min[0]);
+    assert!(max[1] > min[1]);
+
+    // the next widget goes below the rect
+    assert!(ui.cursor_screen_pos()[1] >= max[1]);
+}
@@ -0,0 +1,68 @@
+use std::mem::{align_of, size_of};
+
+// Deriving `Gui` doesn't touch the struct (the events go to a separate type),
+// so structs shared with C keep their layout.
+#[derive(imgui_ext::Gui)]
+#[repr(C)]
+struct Derived {
+    #[imgui(checkbox)]
+    enabled: bool,
+    #[imgui(slider(min = 0.0, max = 1.0))]
+    gain: f32,
+    #[imgui(input)]
+    name: [u8; 16],
+    #[imgui(drag)]
+    offset: [f64; 2],
+}
+
+#[repr(C)]
+struct Plain {
+    enabled: bool,
+    gain: f32,
+    name: [u8; 16],
+    offset: [f64; 2],
+}
+
+#[test]
+fn repr_c() {
+    assert_eq!(size_of::<Plain>(), size_of::<Derived>());
+    assert_eq!(align_of::<Plain>(), align_of::<Derived>());
+
+    let derived = Derived {
+        enabled: true,
+        gain: 0.5,
+        name: [0; 16],
+        offset: [0.0; 2],
+    };
+    let plain = Plain {
+        enabled: true,
+        gain: 0.5,
+        name: [0; 16],
+        offset: [0.0; 2],
+    };
+    let offsets = |base: usize, fields: [usize; 4]| {
+        let mut offsets = fields;
+        offsets.iter_mut().for_each(|field| *field -= base);
+        offsets
+    };
+    assert_eq!(
+        offsets(
+            &plain as *const _ as usize,
+            [
+                &plain.enabled as *const _ as usize,
+                &plain.gain as *const _ as usize,
+                plain.name.as_ptr() as usize,
+                plain.offset.as_ptr() as usize,
+            ]
+        ),
+        offsets(
+            &derived as *const _ as usize,
+            [
+                &derived.enabled as *const _ as usize,
+                &derived.gain as *const _ as usize,
+                derived.name.as_ptr() as usize,
+                derived.offset.as_ptr() as usize,
+            ]
+        ),
+    );
+}
@@ -0,0 +1,53 @@
+mod common;
+
+#[test]
+fn same_line() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(
+            slider(min = 0.0, max = 1.0),
+            button(label = "Reset", same_line, catch = "reset")
+        )]
+        a: f32,
+        #[imgui(checkbox(same_line))]
+        b: bool,
+        #[imgui(text("Text"), text(lit = "Inline", same_line))]
+        c: (),
+    }
+}
+
+#[derive(imgui_ext::Gui)]
+struct Toolbar {
+    #[imgui(
+        button(label = "Open"),
+        separator(vertical),
+        button(label = "Save", same_line, catch = "save")
+    )]
+    _buttons: (),
+}
+
+#[derive(imgui_ext::Gui)]
+struct Single {
+    #[imgui(button(label = "Open"))]
+    _button: (),
+}
+
+#[test]
+fn vertical_separator() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    // the separator doesn't start a new row
+    let start = ui.cursor_screen_pos();
+    let events = ui.draw_gui(&mut Toolbar { _buttons: () });
+    let toolbar = ui.cursor_screen_pos()[1] - start[1];
+
+    let start = ui.cursor_screen_pos();
+    ui.draw_gui(&mut Single { _button: () });
+    let single = ui.cursor_screen_pos()[1] - start[1];
+
+    assert!(!events.save());
+    assert_eq!(single, toolbar);
+}
@@ -0,0 +1,54 @@
+use imgui::im_str;
+use imgui_ext::drag::{Drag, DragParams};
+use imgui_ext::slider::{Slider, SliderParams};
+
+mod common;
+
+#[test]
+fn sanitize_derive() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(drag(sanitize))]
+        a: f32,
+        #[imgui(slider(min = 0.0, max = 1.0, sanitize))]
+        b: [f64; 3],
+        #[imgui(drag(min = 0, max = 4, sanitize))]
+        c: i32,
+    }
+}
+
+#[test]
+fn sanitize() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut drag = std::f32::NAN;
+    let params = DragParams {
+        label: im_str!("drag"),
+        format: None,
+        min: None,
+        max: None,
+        speed: None,
+        power: None,
+        sanitize: true,
+        unit: None,
+    };
+    Drag::build(&ui, &mut drag, params);
+    assert_eq!(0.0, drag);
+
+    let mut slider = [0.5, std::f32::NAN, std::f32::INFINITY];
+    let params = SliderParams {
+        label: im_str!("slider"),
+        format: None,
+        min: -1.0,
+        max: 1.0,
+        power: None,
+        sanitize: true,
+        wrap: false,
+        logarithmic: false,
+        keyboard_step: None,
+        format_value: None,
+    };
+    Slider::build(&ui, &mut slider, params);
+    assert_eq!([0.5, -1.0, -1.0], slider);
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+mod common;
+
+#[derive(Default, Serialize, Deserialize, imgui_ext::Gui)]
+#[serde(rename_all = "camelCase")]
+struct Settings {
+    #[imgui(checkbox)]
+    #[serde(default)]
+    vsync: bool,
+    #[imgui(slider(min = 0.5, max = 2.0, label = "UI scale"))]
+    ui_scale: f32,
+    // skipped by both derives
+    #[imgui(skip)]
+    #[serde(skip)]
+    cache: Vec<u8>,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+fn serializable<T: Serialize + for<'de> Deserialize<'de>>() {}
+
+#[test]
+fn serde_attributes() {
+    use imgui_ext::UiExt;
+
+    serializable::<Settings>();
+    assert_eq!(2, Settings::IMGUI_WIDGET_COUNT);
+
+    let mut ctx = common::headless();
+
+    let mut settings = Settings::default();
+    let ui = ctx.frame();
+    let events = ui.draw_gui(&mut settings);
+    assert!(events.widgets_changed().is_empty());
+    assert!(settings.cache.is_empty() && !settings.dirty);
+}
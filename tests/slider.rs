@@ -0,0 +1,319 @@
+use std::cell::RefCell;
+
+mod common;
+
+#[test]
+fn slider_wrap() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(slider(min = 0.0, max = 360.0, wrap))]
+        heading: f32,
+        #[imgui(slider(min = "-180", max = 180, wrap, sanitize))]
+        angles: [i32; 3],
+    }
+}
+
+#[test]
+fn slider_range() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(slider(0.0..=1.0))]
+        volume: f32,
+        #[imgui(slider(-1.0..=1.0, format = "%.2f"))]
+        balance: [f64; 2],
+        #[imgui(slider(0..10, catch = "digit"))]
+        digit: i32,
+        #[imgui(slider(-8..=8))]
+        offset: i64,
+    }
+}
+
+#[test]
+fn slider_usize() {
+    use imgui_ext::UiExt;
+
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(slider(min = 0, max = 10))]
+        index: usize,
+        #[imgui(slider(min = 0, max = 255), drag(speed = 1.0), input(step = 1))]
+        byte: u8,
+        #[imgui(drag(min = 0))]
+        count: u64,
+    }
+
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut test = Test {
+        index: 3,
+        byte: 200,
+        count: 1 << 40,
+    };
+    let events = ui.draw_gui(&mut test);
+
+    assert!(!events.index());
+    assert!(!events.byte());
+    assert!(!events.count());
+    assert_eq!(3, test.index);
+    assert_eq!(200, test.byte);
+    assert_eq!(1 << 40, test.count);
+}
+
+#[derive(imgui_ext::Gui)]
+struct Logarithmic {
+    #[imgui(slider(min = 20.0, max = 20000.0, logarithmic))]
+    frequency: f32,
+    #[imgui(slider(min = 0.001, max = 1.0, logarithmic))]
+    volume: f64,
+    #[imgui(slider(min = 1, max = 100000, logarithmic))]
+    samples: i32,
+}
+
+#[test]
+fn slider_logarithmic() {
+    use imgui_ext::UiExt;
+
+    let mut ctx = common::headless();
+
+    let mut test = Logarithmic {
+        frequency: 1000.0,
+        volume: 0.5,
+        samples: 44100,
+    };
+
+    // Draw once without touching, then press the middle of the first slider.
+    common::drive(
+        &mut ctx,
+        2,
+        |io, _, [x, y]| {
+            let width = 400.0 * 0.65;
+            io.mouse_pos = [x + width / 2.0, y + 4.0];
+            io.mouse_down[0] = true;
+        },
+        |ui, frame| {
+            let events = ui.draw_gui(&mut test);
+
+            if frame == 0 {
+                assert!(events.widgets_changed().is_empty());
+                assert_eq!(1000.0, test.frequency);
+                assert_eq!(0.5, test.volume);
+                assert_eq!(44100, test.samples);
+            } else {
+                assert!(events.frequency());
+            }
+        },
+    );
+
+    // The middle of a logarithmic [20, 20000] is ~632 (sqrt(20 * 20000)),
+    // far from the ~10000 of a linear slider.
+    assert!(test.frequency > 300.0 && test.frequency < 1300.0);
+}
+
+/// Hovers the first slider drawn by `draw`, and presses the arrow keys given
+/// by `keys`, one character per frame (`<` left, `>` right, anything else
+/// none). Keys are released in between so every press counts.
+fn press_arrows<F: FnMut(&imgui::Ui)>(keys: &str, mut draw: F) {
+    use imgui::sys;
+
+    let mut ctx = common::headless();
+    ctx.io_mut().key_map[sys::ImGuiKey_LeftArrow as usize] = 1;
+    ctx.io_mut().key_map[sys::ImGuiKey_RightArrow as usize] = 2;
+
+    let keys: Vec<char> = keys.chars().collect();
+    common::drive(
+        &mut ctx,
+        keys.len(),
+        |io, frame, [x, y]| {
+            io.mouse_pos = [x + 4.0, y + 4.0];
+            io.keys_down[1] = keys[frame] == '<';
+            io.keys_down[2] = keys[frame] == '>';
+        },
+        |ui, _| draw(ui),
+    );
+}
+
+#[test]
+fn slider_keyboard_step() {
+    use imgui_ext::UiExt;
+
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(slider(min = 0.0, max = 1.0, keyboard_step = 0.25))]
+        volume: f32,
+        #[imgui(slider(min = 20.0, max = 20000.0, logarithmic, keyboard_step = 10.0))]
+        frequency: f64,
+    }
+
+    let mut test = Test {
+        volume: 0.5,
+        frequency: 440.0,
+    };
+    let mut volumes = Vec::new();
+    press_arrows(" .>.>.>.<", |ui| {
+        ui.draw_gui(&mut test);
+        volumes.push(test.volume);
+    });
+
+    // the last step is clamped to the max
+    let expected = vec![0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0, 1.0, 0.75];
+    assert_eq!(expected, volumes);
+    // only the hovered slider is stepped
+    assert_eq!(440.0, test.frequency);
+}
+
+#[test]
+fn slider_keyboard_step_wrap() {
+    use imgui_ext::UiExt;
+
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(slider(min = 0, max = 10, keyboard_step = 1, wrap))]
+        octave: i32,
+    }
+
+    let mut test = Test { octave: 9 };
+    let mut octaves = Vec::new();
+    press_arrows(" .>.<.<", |ui| {
+        let events = ui.draw_gui(&mut test);
+        octaves.push((test.octave, events.octave()));
+    });
+
+    let expected = vec![
+        (9, false),
+        (9, false),
+        (0, true),
+        (0, false),
+        (9, true),
+        (9, false),
+        (8, true),
+    ];
+    assert_eq!(expected, octaves);
+}
+
+struct Volume(f32);
+
+impl imgui_ext::Bounded for Volume {
+    fn imgui_bounds() -> (f32, f32) {
+        (0.25, 1.0)
+    }
+}
+
+#[test]
+fn slider_bounded() {
+    use imgui_ext::UiExt;
+
+    #[derive(imgui_ext::Gui)]
+    struct Mixer {
+        #[imgui(newtype, slider(bounded, sanitize))]
+        master: Volume,
+        // explicit bounds take precedence
+        #[imgui(newtype, slider(bounded, min = 0.5, sanitize))]
+        music: Volume,
+        #[imgui(newtype, drag(bounded, sanitize))]
+        effects: Volume,
+    }
+
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    // non-finite values are replaced with the lower bound
+    let mut mixer = Mixer {
+        master: Volume(std::f32::NAN),
+        music: Volume(std::f32::NAN),
+        effects: Volume(std::f32::INFINITY),
+    };
+    ui.draw_gui(&mut mixer);
+
+    assert_eq!(0.25, mixer.master.0);
+    assert_eq!(0.5, mixer.music.0);
+    assert_eq!(0.25, mixer.effects.0);
+}
+
+thread_local! {
+    static FORMATTED: RefCell<Vec<String>> = RefCell::new(Vec::new());
+}
+
+fn human_bytes(bytes: f32) -> String {
+    let text = match bytes {
+        b if b >= 1024.0 * 1024.0 => format!("{:.1} MiB", b / (1024.0 * 1024.0)),
+        b if b >= 1024.0 => format!("{:.1} KiB", b / 1024.0),
+        b => format!("{} B", b),
+    };
+    FORMATTED.with(|formatted| formatted.borrow_mut().push(text.clone()));
+    text
+}
+
+fn human_blocks(blocks: u64) -> String {
+    let text = format!("{} blocks", blocks);
+    FORMATTED.with(|formatted| formatted.borrow_mut().push(text.clone()));
+    text
+}
+
+#[test]
+fn slider_format_value() {
+    use imgui_ext::UiExt;
+
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(slider(min = 0.0, max = 16777216.0, format_value = "human_bytes"))]
+        cache: f32,
+        #[imgui(slider(min = 1.0, max = 16777216.0, logarithmic, format_value = "human_bytes"))]
+        budget: f32,
+        #[imgui(slider(min = 0, max = 4096, format_value = "human_blocks"))]
+        blocks: u64,
+    }
+
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut test = Test {
+        cache: 1.25 * 1024.0 * 1024.0,
+        budget: 512.0,
+        blocks: 16,
+    };
+    let events = ui.draw_gui(&mut test);
+
+    assert!(!events.cache());
+    assert!(!events.budget());
+    assert!(!events.blocks());
+    FORMATTED.with(|formatted| {
+        assert_eq!(&["1.2 MiB", "512 B", "16 blocks"], &formatted.borrow()[..]);
+    });
+}
+
+#[test]
+fn slider_nan() {
+    use imgui_ext::UiExt;
+
+    // values that are different from (or equal to) themselves with `!=`
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(slider(min = 0.0, max = 1.0, keyboard_step = 0.1))]
+        nan: f32,
+        #[imgui(slider(min = "-1.0", max = 1.0))]
+        zero: f64,
+        #[imgui(drag(speed = 0.1))]
+        drag: [f32; 2],
+        #[imgui(slider(min = 1.0, max = 100.0, logarithmic))]
+        log: f32,
+    }
+
+    let mut ctx = common::headless();
+
+    let mut test = Test {
+        nan: std::f32::NAN,
+        zero: -0.0,
+        drag: [std::f32::NAN, -0.0],
+        log: std::f32::NAN,
+    };
+    for _ in 0..2 {
+        let ui = ctx.frame();
+        let events = ui.draw_gui(&mut test);
+        assert!(events.widgets_changed().is_empty());
+    }
+
+    assert!(test.nan.is_nan() && test.log.is_nan() && test.drag[0].is_nan());
+    assert!(test.zero == 0.0 && test.zero.is_sign_negative());
+    assert!(test.drag[1] == 0.0 && test.drag[1].is_sign_negative());
+}
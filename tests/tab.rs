@@ -0,0 +1,45 @@
+use imgui_ext::UiExt;
+
+mod common;
+
+#[derive(imgui_ext::Gui, Default)]
+struct Document {
+    #[imgui(checkbox)]
+    saved: bool,
+}
+
+#[derive(imgui_ext::Gui, Default)]
+struct Documents {
+    #[imgui(tab_item(label = "main.rs", open = "main_open", closable = "main_closed"))]
+    main: Document,
+    main_open: bool,
+    #[imgui(tab_item(
+        label = "lib.rs",
+        flags = "UnsavedDocument | NoCloseWithMiddleMouseButton",
+        closable = "lib_closed"
+    ))]
+    lib: Document,
+    #[imgui(tab_item(flags = "SetSelected", catch = "readme_events"))]
+    readme: Document,
+}
+
+#[derive(imgui_ext::Gui, Default)]
+struct Editor {
+    #[imgui(tab_bar(catch = "tabs"))]
+    documents: Documents,
+}
+
+#[test]
+fn closable_tab() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut editor = Editor::default();
+    editor.documents.main_open = true;
+    let events = ui.draw_gui(&mut editor);
+
+    assert!(editor.documents.main_open);
+    assert!(!events.tabs().main_closed());
+    assert!(!events.tabs().lib_closed());
+    assert!(!events.tabs().readme_events().saved());
+}
@@ -0,0 +1,31 @@
+use imgui_ext::UiExt;
+
+mod common;
+
+#[derive(imgui_ext::Gui)]
+struct Log {
+    #[imgui(text(wrapped, colored(color = "[1,0,0,1]")))]
+    error: String,
+    #[imgui(text("Warnings", bullet, disabled), text_wrap("Lorem ipsum", bullet))]
+    warnings: (),
+    #[imgui(text(colored(color = "green"), same_line))]
+    count: u32,
+}
+
+fn green() -> [f32; 4] {
+    [0.0, 1.0, 0.0, 1.0]
+}
+
+#[test]
+fn text_decorators() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut log = Log {
+        error: String::from("Something went wrong"),
+        warnings: (),
+        count: 4,
+    };
+    // text doesn't trigger events, so there is no events type
+    let () = ui.draw_gui(&mut log);
+}
@@ -0,0 +1,52 @@
+use imgui_ext::{Gui, UiExt};
+use std::cell::Cell;
+
+mod common;
+
+thread_local! {
+    static DRAWN: Cell<usize> = Cell::new(0);
+}
+
+/// Counts how many times it is drawn.
+struct Counter;
+
+impl Gui for Counter {
+    type Events = ();
+    fn draw_gui(ui: &imgui::Ui, _: &mut Self) {
+        DRAWN.with(|drawn| drawn.set(drawn.get() + 1));
+        ui.text("child");
+    }
+}
+
+/// Counts how many times the mapped value is accessed.
+fn counted(value: &mut f32) -> &mut f32 {
+    DRAWN.with(|drawn| drawn.set(drawn.get() + 1));
+    value
+}
+
+#[derive(imgui_ext::Gui)]
+struct Tree {
+    #[imgui(tree(label = "Collapsed", node(nested)))]
+    collapsed: Counter,
+    // the child expressions (`map`) are not evaluated either
+    #[imgui(tree(label = "Mapped", node(drag(map = "counted"))))]
+    mapped: f32,
+    #[imgui(tree(label = "Open", cond = "Always", node(nested)))]
+    open: Counter,
+}
+
+#[test]
+fn tree_collapsed() {
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut tree = Tree {
+        collapsed: Counter,
+        mapped: 0.0,
+        open: Counter,
+    };
+    ui.draw_gui(&mut tree);
+
+    // only the open node drew its child
+    assert_eq!(1, DRAWN.with(Cell::get));
+}
@@ -0,0 +1,42 @@
+use imgui::Ui;
+use imgui_ext::{Gui, UiExt};
+use std::ops::Deref;
+
+mod common;
+
+/// Frame guard of a framework that wraps imgui.
+struct Frame<'ui> {
+    ui: Ui<'ui>,
+}
+
+impl<'ui> Deref for Frame<'ui> {
+    type Target = Ui<'ui>;
+    fn deref(&self) -> &Ui<'ui> {
+        &self.ui
+    }
+}
+
+#[derive(Default, imgui_ext::Gui)]
+struct Example {
+    #[imgui(checkbox)]
+    a: bool,
+    #[imgui(slider(min = 0.0, max = 1.0))]
+    b: f32,
+}
+
+// Works with any type that dereferences to a `Ui`.
+fn draw<'ui, U: Deref<Target = Ui<'ui>>>(ui: &U, example: &mut Example) -> bool {
+    let events = Example::draw_gui(ui, example);
+    events.a() || events.b()
+}
+
+#[test]
+fn ui_wrapper() {
+    let mut ctx = common::headless();
+    let frame = Frame { ui: ctx.frame() };
+
+    let mut example = Example::default();
+    assert!(frame.draw_gui(&mut example).widgets_changed().is_empty());
+    assert!(!Gui::draw_gui(&frame, &mut example).a());
+    assert!(!draw(&frame, &mut example));
+}
@@ -0,0 +1,45 @@
+use imgui_ext::variants::Variants;
+use imgui_ext::UiExt;
+
+mod common;
+
+#[derive(Debug, PartialEq, imgui_ext::Gui)]
+#[repr(i32)]
+enum Quality {
+    Low,
+    Medium = 4,
+    High = 8,
+}
+
+#[derive(imgui_ext::Gui)]
+struct Settings {
+    #[imgui(slider(bounded, catch = "quality"))]
+    quality: Quality,
+}
+
+#[test]
+fn quality_variants() {
+    assert_eq!(&[("Low", 0), ("Medium", 4), ("High", 8)], Quality::VARIANTS);
+    assert_eq!(2, Quality::High.index());
+    assert_eq!(Quality::Medium, Quality::from_index(1));
+    assert_eq!((0, 8), <Quality as imgui_ext::Bounded<i32>>::imgui_bounds());
+}
+
+#[test]
+fn slider_variants() {
+    let mut ctx = common::headless();
+
+    let mut settings = Settings {
+        quality: Quality::High,
+    };
+    let mut quality = false;
+
+    // Click the left end of the slider.
+    common::click_first_item(&mut ctx, &[false, true, true, false], |ui, _| {
+        quality |= ui.draw_gui(&mut settings).quality();
+    });
+
+    // the nearest variant is written back
+    assert!(quality);
+    assert_eq!(Quality::Low, settings.quality);
+}
@@ -0,0 +1,76 @@
+use imgui_ext::UiExt;
+
+mod common;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Vec3 {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+impl From<[f32; 3]> for Vec3 {
+    fn from([x, y, z]: [f32; 3]) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+impl From<Vec3> for [f32; 3] {
+    fn from(v: Vec3) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+imgui_ext::vector_widgets! { Vec3: 3 }
+
+#[test]
+fn vector_widgets() {
+    #[derive(imgui_ext::Gui, Default)]
+    struct Test {
+        #[imgui(drag(speed = 0.1), slider(min = 0.0, max = 1.0), input)]
+        a: Vec3,
+        #[imgui(drag)]
+        b: Option<Vec3>,
+    }
+
+    let mut ctx = common::headless();
+    let ui = ctx.frame();
+
+    let mut test = Test::default();
+    let events = ui.draw_gui(&mut test);
+    assert!(events.widgets_changed().is_empty());
+    assert_eq!(Vec3::default(), test.a);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn drag_glam() {
+    #[derive(imgui_ext::Gui)]
+    struct Transform {
+        #[imgui(drag(catch = "moved"))]
+        position: glam::Vec3,
+    }
+
+    let mut ctx = common::headless();
+
+    let mut transform = Transform {
+        position: glam::Vec3::from([1.0, 2.0, 3.0]),
+    };
+    let mut moved = false;
+
+    // Press over the first component, then drag it to the right.
+    common::drive(
+        &mut ctx,
+        6,
+        |io, frame, [x, y]| {
+            io.mouse_pos = [x + 4.0 + 10.0 * frame as f32, y + 4.0];
+            io.mouse_down[0] = true;
+        },
+        |ui, _| moved |= ui.draw_gui(&mut transform).moved(),
+    );
+
+    let [x, y, z]: [f32; 3] = transform.position.into();
+    assert!(moved);
+    assert!(x > 1.0);
+    assert_eq!([2.0, 3.0], [y, z]);
+}
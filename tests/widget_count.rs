@@ -0,0 +1,91 @@
+use std::marker::PhantomData;
+
+#[derive(imgui_ext::Gui)]
+struct Inner {
+    #[imgui(checkbox, checkbox(label = "again"))]
+    a: bool,
+}
+
+#[derive(imgui_ext::Gui)]
+struct Widgets {
+    #[imgui(text("Header"), separator)]
+    header: PhantomData<()>,
+    #[imgui(slider(min = 0.0, max = 1.0), drag)]
+    a: f32,
+    #[imgui(order = 0, checkbox)]
+    b: bool,
+    #[imgui(nested)]
+    inner: Inner,
+    ignored: usize,
+}
+
+#[derive(imgui_ext::Gui)]
+struct Empty {
+    _ignored: usize,
+}
+
+#[test]
+fn widget_count() {
+    assert_eq!(2, Inner::IMGUI_WIDGET_COUNT);
+    assert_eq!(6, Widgets::IMGUI_WIDGET_COUNT);
+    assert_eq!(0, Empty::IMGUI_WIDGET_COUNT);
+}
+
+const GAIN: &str = "Gain";
+
+#[derive(imgui_ext::Gui)]
+struct Mixer {
+    #[imgui(slider(min = "-1.0", max = 1.0, label = "Balance"))]
+    balance: f32,
+    #[imgui(slider(0..=100, label = GAIN))]
+    gain: i32,
+    #[imgui(drag(min = 0.0), button(label = "Mute"))]
+    volume: f32,
+}
+
+#[test]
+fn widget_info() {
+    use imgui_ext::WidgetInfo;
+
+    assert_eq!(Widgets::IMGUI_WIDGET_COUNT, Widgets::IMGUI_WIDGETS.len());
+    assert!(Empty::IMGUI_WIDGETS.is_empty());
+
+    assert_eq!(
+        &[
+            WidgetInfo {
+                field: "balance",
+                kind: "slider",
+                label: Some("Balance"),
+                min: Some(-1.0),
+                max: Some(1.0),
+            },
+            WidgetInfo {
+                field: "gain",
+                kind: "slider",
+                label: Some("Gain"),
+                min: Some(0.0),
+                max: Some(100.0),
+            },
+            WidgetInfo {
+                field: "volume",
+                kind: "drag",
+                label: Some("volume"),
+                min: Some(0.0),
+                max: None,
+            },
+            WidgetInfo {
+                field: "volume",
+                kind: "button",
+                label: Some("Mute"),
+                min: None,
+                max: None,
+            },
+        ],
+        Mixer::IMGUI_WIDGETS
+    );
+
+    // layout annotations have no label
+    let text = &Widgets::IMGUI_WIDGETS[0];
+    assert_eq!("text", text.kind);
+    assert_eq!(None, text.label);
+}
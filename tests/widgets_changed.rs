@@ -0,0 +1,30 @@
+#[test]
+fn widgets_changed() {
+    #[derive(imgui_ext::Gui)]
+    struct Test {
+        #[imgui(checkbox, button(label = "Reset", catch = "reset"))]
+        a: bool,
+        #[imgui(slider(min = 0.0, max = 1.0))]
+        b: f32,
+        #[imgui(drag(catch = "c_drag"))]
+        c: f32,
+    }
+
+    type Events = <Test as imgui_ext::Gui>::Events;
+
+    let events = Events {
+        a: true,
+        reset: false,
+        b: false,
+        c_drag: true,
+    };
+    assert_eq!(vec!["a", "c_drag"], events.widgets_changed());
+
+    let events = Events {
+        a: false,
+        reset: false,
+        b: false,
+        c_drag: false,
+    };
+    assert!(events.widgets_changed().is_empty());
+}